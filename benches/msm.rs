@@ -0,0 +1,86 @@
+//! Benchmarks of [`JubJubExtended::multiscalar_mul_mixed`] against the naive
+//! sum of scalar multiplications.
+//!
+//! ```text
+//! cargo bench --bench msm
+//! ```
+
+#![feature(test)]
+
+extern crate test;
+
+use dusk_jubjub::{
+    JubJubAffine, JubJubExtended, JubJubScalar, GENERATOR_EXTENDED,
+};
+use rand_core::{RngCore, SeedableRng};
+use rand_xorshift::XorShiftRng;
+use test::{black_box, Bencher};
+
+fn gen(n: usize) -> (Vec<JubJubScalar>, Vec<JubJubAffine>) {
+    let mut rng = XorShiftRng::from_seed([
+        0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32,
+        0x54, 0x06, 0xbc, 0xe5,
+    ]);
+
+    let mut scalar = || {
+        let mut bytes = [0u8; 64];
+        rng.fill_bytes(&mut bytes);
+        JubJubScalar::from_bytes_wide(&bytes)
+    };
+
+    let scalars = (0..n).map(|_| scalar()).collect();
+    let points = (0..n)
+        .map(|_| JubJubAffine::from(GENERATOR_EXTENDED * scalar()))
+        .collect();
+
+    (scalars, points)
+}
+
+fn bench_msm(b: &mut Bencher, n: usize) {
+    let (scalars, points) = gen(n);
+
+    b.iter(|| {
+        JubJubExtended::multiscalar_mul_mixed(
+            black_box(&scalars),
+            black_box(&points),
+        )
+    });
+}
+
+fn bench_naive(b: &mut Bencher, n: usize) {
+    let (scalars, points) = gen(n);
+
+    b.iter(|| {
+        black_box(&scalars)
+            .iter()
+            .zip(black_box(&points).iter())
+            .fold(JubJubExtended::identity(), |acc, (s, p)| {
+                acc + JubJubExtended::from(*p) * s
+            })
+    });
+}
+
+#[bench]
+fn msm_16(b: &mut Bencher) {
+    bench_msm(b, 16);
+}
+
+#[bench]
+fn msm_256(b: &mut Bencher) {
+    bench_msm(b, 256);
+}
+
+#[bench]
+fn msm_1024(b: &mut Bencher) {
+    bench_msm(b, 1024);
+}
+
+#[bench]
+fn naive_16(b: &mut Bencher) {
+    bench_naive(b, 16);
+}
+
+#[bench]
+fn naive_256(b: &mut Bencher) {
+    bench_naive(b, 256);
+}
@@ -0,0 +1,68 @@
+//! Generates the fixed-base table of the generator used by
+//! `mul_by_generator`:
+//!
+//! ```text
+//! cargo run --example gen_generator_table > src/generator_table.rs
+//! cargo fmt
+//! ```
+//!
+//! Entry `[i][j]` of the table is `(j + 1) · 256^i · G`, for the 32 windows
+//! of two radix-16 digits of a scalar.
+
+use dusk_bytes::Serializable;
+use dusk_jubjub::{
+    BlsScalar, JubJubAffine, JubJubExtended, GENERATOR_EXTENDED,
+};
+
+fn limbs(x: &BlsScalar) -> String {
+    let bytes = x.to_bytes();
+    let limbs: Vec<String> = bytes
+        .chunks(8)
+        .map(|c| {
+            let mut limb = [0u8; 8];
+            limb.copy_from_slice(c);
+            format!("0x{:016x}", u64::from_le_bytes(limb))
+        })
+        .collect();
+
+    format!("BlsScalar::from_raw([{}])", limbs.join(", "))
+}
+
+fn main() {
+    println!("//! Fixed-base table of [`GENERATOR_EXTENDED`], generated by");
+    println!("//! `examples/gen_generator_table.rs`.");
+    println!("//!");
+    println!("//! Entry `[i][j]` is `(j + 1) · 256^i · G`.");
+    println!("//!");
+    println!("//! [`GENERATOR_EXTENDED`]: crate::GENERATOR_EXTENDED");
+    println!();
+    println!("use crate::{{AffineNielsPoint, BlsScalar, JubJubAffine}};");
+    println!();
+    println!(
+        "pub(crate) static GENERATOR_TABLE: [[AffineNielsPoint; 8]; 32] = ["
+    );
+
+    let mut base = GENERATOR_EXTENDED;
+    for _ in 0..32 {
+        println!("[");
+
+        let mut p = JubJubExtended::identity();
+        for _ in 0..8 {
+            p += base;
+
+            let affine = JubJubAffine::from(p);
+            println!(
+                "JubJubAffine::from_raw_unchecked({}, {}).to_niels(),",
+                limbs(&affine.get_x()),
+                limbs(&affine.get_y())
+            );
+        }
+        println!("],");
+
+        for _ in 0..8 {
+            base = base.double();
+        }
+    }
+
+    println!("];");
+}
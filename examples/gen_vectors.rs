@@ -0,0 +1,119 @@
+//! Generates deterministic test vectors for cross-implementation testing.
+//!
+//! The vectors are emitted as JSON on stdout, one record per line:
+//!
+//! ```text
+//! cargo run --example gen_vectors > tests/vectors.json
+//! ```
+//!
+//! All the values are hex-encoded with the canonical little-endian
+//! serialization of the crate: 32 bytes for scalars and compressed points,
+//! 64 bytes for ElGamal ciphers.
+
+use dusk_bytes::Serializable;
+use dusk_jubjub::elgamal::ElgamalCipher;
+use dusk_jubjub::{
+    JubJubAffine, JubJubExtended, JubJubScalar, GENERATOR_EXTENDED,
+};
+use rand_core::{RngCore, SeedableRng};
+use rand_xorshift::XorShiftRng;
+
+const SEED: [u8; 16] = [
+    0x64, 0x75, 0x73, 0x6b, 0x2d, 0x6a, 0x75, 0x62, 0x6a, 0x75, 0x62, 0x2d,
+    0x6b, 0x61, 0x74, 0x73,
+];
+
+const VECTORS: usize = 8;
+
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+fn scalar<R: RngCore>(rng: &mut R) -> JubJubScalar {
+    let mut bytes = [0u8; 64];
+    rng.fill_bytes(&mut bytes);
+    JubJubScalar::from_bytes_wide(&bytes)
+}
+
+fn point<R: RngCore>(rng: &mut R) -> JubJubExtended {
+    GENERATOR_EXTENDED * scalar(rng)
+}
+
+fn compressed(p: &JubJubExtended) -> String {
+    hex(&JubJubAffine::from(p).to_bytes())
+}
+
+fn section<F>(name: &str, last: bool, mut record: F)
+where
+    F: FnMut() -> String,
+{
+    println!("  \"{}\": [", name);
+    for i in 0..VECTORS {
+        let sep = if i + 1 < VECTORS { "," } else { "" };
+        println!("    {{ {} }}{}", record(), sep);
+    }
+    println!("  ]{}", if last { "" } else { "," });
+}
+
+fn main() {
+    let mut rng = XorShiftRng::from_seed(SEED);
+
+    println!("{{");
+    println!("  \"seed\": \"{}\",", hex(&SEED));
+
+    section("scalar_mul", false, || {
+        let s = scalar(&mut rng);
+        let p = point(&mut rng);
+        format!(
+            "\"scalar\": \"{}\", \"point\": \"{}\", \"result\": \"{}\"",
+            hex(&s.to_bytes()),
+            compressed(&p),
+            compressed(&(p * s)),
+        )
+    });
+
+    section("point_add", false, || {
+        let a = point(&mut rng);
+        let b = point(&mut rng);
+        format!(
+            "\"a\": \"{}\", \"b\": \"{}\", \"result\": \"{}\"",
+            compressed(&a),
+            compressed(&b),
+            compressed(&(a + b)),
+        )
+    });
+
+    section("compression", false, || {
+        let p = JubJubAffine::from(point(&mut rng));
+        format!(
+            "\"x\": \"{}\", \"y\": \"{}\", \"compressed\": \"{}\"",
+            hex(&p.get_x().to_bytes()),
+            hex(&p.get_y().to_bytes()),
+            hex(&p.to_bytes()),
+        )
+    });
+
+    section("elgamal", true, || {
+        let secret = scalar(&mut rng);
+        let public = GENERATOR_EXTENDED * secret;
+        let ephemeral = scalar(&mut rng);
+        let message = point(&mut rng);
+        let cipher = ElgamalCipher::encrypt_with_randomness(
+            &ephemeral,
+            &public,
+            &GENERATOR_EXTENDED,
+            &message,
+        );
+        format!(
+            "\"secret\": \"{}\", \"public\": \"{}\", \"ephemeral\": \"{}\", \
+             \"message\": \"{}\", \"cipher\": \"{}\"",
+            hex(&secret.to_bytes()),
+            compressed(&public),
+            hex(&ephemeral.to_bytes()),
+            compressed(&message),
+            hex(&cipher.to_bytes()),
+        )
+    });
+
+    println!("}}");
+}
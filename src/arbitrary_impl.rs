@@ -0,0 +1,105 @@
+//! Implementations of [`arbitrary::Arbitrary`], for structure-aware fuzzing.
+//!
+//! Only valid instances are produced: scalars are reduced from 64 bytes of
+//! fuzzer input, and points are multiples of
+//! [`GENERATOR_EXTENDED`](crate::GENERATOR_EXTENDED), so they
+//! are always in the prime order subgroup.
+//!
+//! [`BlsScalar`](crate::BlsScalar) is defined by `dusk-bls12_381`, so it
+//! can't implement the trait from this crate.
+
+use crate::elgamal::ElgamalCipher;
+use crate::{mul_by_generator, JubJubAffine, JubJubExtended, JubJubScalar};
+
+use arbitrary::{Arbitrary, Result, Unstructured};
+
+impl<'a> Arbitrary<'a> for JubJubScalar {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let bytes = <[u8; 64]>::arbitrary(u)?;
+        Ok(JubJubScalar::from_bytes_wide(&bytes))
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        <[u8; 64]>::size_hint(depth)
+    }
+}
+
+impl<'a> Arbitrary<'a> for JubJubExtended {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        JubJubScalar::arbitrary(u).map(|s| mul_by_generator(&s))
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        JubJubScalar::size_hint(depth)
+    }
+}
+
+impl<'a> Arbitrary<'a> for JubJubAffine {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        JubJubExtended::arbitrary(u).map(JubJubAffine::from)
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        JubJubExtended::size_hint(depth)
+    }
+}
+
+impl<'a> Arbitrary<'a> for ElgamalCipher {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let gamma = JubJubExtended::arbitrary(u)?;
+        let delta = JubJubExtended::arbitrary(u)?;
+
+        Ok(ElgamalCipher::new(gamma, delta))
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        let (min, max) = JubJubExtended::size_hint(depth);
+        (2 * min, max.map(|max| 2 * max))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::elgamal::ElgamalCipher;
+    use crate::{JubJubAffine, JubJubExtended, JubJubScalar};
+    use arbitrary::{Arbitrary, Unstructured};
+    use dusk_bytes::Serializable;
+    use rand_core::{OsRng, RngCore};
+
+    fn is_valid_point(p: &JubJubExtended) -> bool {
+        let affine = JubJubAffine::from(p);
+
+        affine.is_on_curve_vartime() && bool::from(p.is_torsion_free())
+    }
+
+    #[test]
+    fn valid_instances() {
+        let mut data = [0u8; 4096];
+        OsRng.fill_bytes(&mut data);
+        let mut u = Unstructured::new(&data);
+
+        for _ in 0..8 {
+            let s = JubJubScalar::arbitrary(&mut u).unwrap();
+            assert_eq!(JubJubScalar::from_bytes(&s.to_bytes()), Ok(s));
+
+            let p = JubJubExtended::arbitrary(&mut u).unwrap();
+            assert!(is_valid_point(&p));
+
+            let p = JubJubAffine::arbitrary(&mut u).unwrap();
+            assert!(is_valid_point(&p.into()));
+
+            let c = ElgamalCipher::arbitrary(&mut u).unwrap();
+            assert!(is_valid_point(c.gamma()));
+            assert!(is_valid_point(c.delta()));
+        }
+
+        // Non-canonical input bytes still produce canonical scalars
+        let data = [0xff; 64];
+        let s = JubJubScalar::arbitrary(&mut Unstructured::new(&data)).unwrap();
+        assert_eq!(JubJubScalar::from_bytes(&s.to_bytes()), Ok(s));
+
+        // An exhausted input is padded with zeros
+        let p = JubJubAffine::arbitrary(&mut Unstructured::new(&[])).unwrap();
+        assert_eq!(p, JubJubAffine::identity());
+    }
+}
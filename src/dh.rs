@@ -0,0 +1,157 @@
+//! Diffie-Hellman key exchange over Jubjub.
+//!
+//! Being `a` and `b` the secrets of two parties, and `A = G · a` and
+//! `B = G · b` their public keys, both parties compute the same shared point
+//! `a · B = b · A`.
+
+use crate::{dhke, JubJubAffine, JubJubExtended, JubJubScalar};
+
+use core::fmt;
+use dusk_bytes::{Error as BytesError, Serializable};
+
+/// Point shared between two parties after a Diffie-Hellman key exchange.
+///
+/// The shared point is not a uniformly distributed key; use
+/// `SharedSecret::hkdf` (under the `hkdf` feature) to derive key material
+/// from it.
+#[derive(Copy, Clone, PartialEq)]
+pub struct SharedSecret(JubJubAffine);
+
+impl fmt::Debug for SharedSecret {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SharedSecret").finish_non_exhaustive()
+    }
+}
+
+impl Serializable<32> for SharedSecret {
+    type Error = BytesError;
+
+    /// Serialize the shared point into its compressed form
+    fn to_bytes(&self) -> [u8; Self::SIZE] {
+        self.0.to_bytes()
+    }
+
+    /// Deserialize from a [`SharedSecret::to_bytes`] construction
+    fn from_bytes(bytes: &[u8; Self::SIZE]) -> Result<Self, Self::Error> {
+        JubJubAffine::from_bytes(bytes).map(Self)
+    }
+}
+
+impl From<JubJubAffine> for SharedSecret {
+    fn from(point: JubJubAffine) -> Self {
+        Self(point)
+    }
+}
+
+impl SharedSecret {
+    /// Compute the shared point `secret · public`.
+    pub fn new(secret: &JubJubScalar, public: &JubJubExtended) -> Self {
+        Self(dhke(secret, public))
+    }
+
+    /// Shared point
+    pub fn point(&self) -> &JubJubAffine {
+        &self.0
+    }
+
+    /// Derive key material into `okm` with HKDF-SHA256, using the compressed
+    /// shared point as input keying material.
+    ///
+    /// `salt` may be empty, in which case HKDF uses a string of zeros as
+    /// specified by RFC 5869.
+    ///
+    /// # Panics
+    ///
+    /// If `okm` is longer than `255 · 32` bytes, the maximum output of
+    /// HKDF-SHA256.
+    #[cfg(feature = "hkdf")]
+    pub fn hkdf(&self, salt: &[u8], info: &[u8], okm: &mut [u8]) {
+        let salt = match salt.is_empty() {
+            true => None,
+            false => Some(salt),
+        };
+
+        hkdf::Hkdf::<sha2::Sha256>::new(salt, &self.to_bytes())
+            .expand(info, okm)
+            .expect("The output exceeds the HKDF-SHA256 limit")
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg(test)]
+mod tests {
+    use super::SharedSecret;
+    use crate::{JubJubScalar, GENERATOR_EXTENDED};
+    use dusk_bytes::Serializable;
+    use rand_core::OsRng;
+
+    #[test]
+    fn shared() {
+        let a = JubJubScalar::random(&mut OsRng);
+        let b = JubJubScalar::random(&mut OsRng);
+
+        let a_shared = SharedSecret::new(&a, &(GENERATOR_EXTENDED * b));
+        let b_shared = SharedSecret::new(&b, &(GENERATOR_EXTENDED * a));
+        assert_eq!(a_shared, b_shared);
+
+        let bytes = a_shared.to_bytes();
+        assert_eq!(SharedSecret::from_bytes(&bytes).unwrap(), a_shared);
+
+        assert_eq!(format!("{:?}", a_shared), "SharedSecret { .. }");
+    }
+
+    #[cfg(feature = "hkdf")]
+    #[test]
+    fn hkdf() {
+        let a = JubJubScalar::random(&mut OsRng);
+        let b = JubJubScalar::random(&mut OsRng);
+
+        let a_shared = SharedSecret::new(&a, &(GENERATOR_EXTENDED * b));
+        let b_shared = SharedSecret::new(&b, &(GENERATOR_EXTENDED * a));
+
+        let mut a_okm = [0u8; 64];
+        let mut b_okm = [0u8; 64];
+        a_shared.hkdf(b"salt", b"info", &mut a_okm);
+        b_shared.hkdf(b"salt", b"info", &mut b_okm);
+        assert_eq!(a_okm, b_okm);
+
+        let mut okm = [0u8; 64];
+        a_shared.hkdf(b"salt", b"other info", &mut okm);
+        assert_ne!(okm, a_okm);
+
+        a_shared.hkdf(b"", b"info", &mut okm);
+        assert_ne!(okm, a_okm);
+    }
+
+    #[cfg(feature = "hkdf")]
+    #[test]
+    fn hkdf_vector() {
+        let a = JubJubScalar::from(2u64);
+        let b = JubJubScalar::from(3u64);
+
+        let shared = SharedSecret::new(&a, &(GENERATOR_EXTENDED * b));
+
+        let mut okm = [0u8; 32];
+        shared.hkdf(b"dusk-jubjub", b"hkdf-test", &mut okm);
+
+        assert_eq!(okm, VECTOR);
+    }
+
+    /// HKDF-SHA256 of the compressed `6 · G`, computed independently
+    #[cfg(feature = "hkdf")]
+    const VECTOR: [u8; 32] = [
+        0xd8, 0xc6, 0xf9, 0x9b, 0xef, 0xa5, 0x97, 0xd6, 0xac, 0x33, 0x88, 0x4c,
+        0xba, 0x1a, 0x29, 0x1c, 0xe2, 0x37, 0x52, 0x23, 0xc1, 0xe1, 0xec, 0x85,
+        0x42, 0xc6, 0x3c, 0xe6, 0x1e, 0xb5, 0x91, 0xd9,
+    ];
+
+    #[cfg(feature = "hkdf")]
+    #[test]
+    #[should_panic]
+    fn hkdf_too_long() {
+        let mut okm = [0u8; 255 * 32 + 1];
+        let shared =
+            SharedSecret::new(&JubJubScalar::one(), &GENERATOR_EXTENDED);
+        shared.hkdf(b"", b"", &mut okm);
+    }
+}
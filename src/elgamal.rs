@@ -1,7 +1,99 @@
 use crate::{JubJubAffine, JubJubExtended, JubJubScalar};
 
+use core::fmt;
 use core::ops::{Add, AddAssign, Mul, MulAssign, Sub, SubAssign};
 use dusk_bytes::{DeserializableSlice, Error as BytesError, Serializable};
+use rand_core::{CryptoRng, RngCore};
+use subtle::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption};
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+#[cfg(feature = "alloc")]
+mod accumulator;
+mod balance;
+mod decryptor;
+mod proof;
+mod tally;
+
+#[cfg(feature = "alloc")]
+pub use accumulator::BitAccumulator;
+pub use balance::{EncryptedBalance, KeyMismatch};
+pub use decryptor::ElgamalDecryptor;
+pub use proof::{
+    verify_decryption, verify_decryption_scalar, DecryptionProof,
+    EncryptionProof, EqualityProof, PlaintextEqProof, PlaintextProof,
+};
+pub use tally::{ElgamalAccumulator, SharedGammaTally};
+
+/// Error returned when a scalar can't be recovered from an
+/// [`ElgamalCipher`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum DecryptError {
+    /// The decrypted message is a valid point, but its scalar lies outside
+    /// of the searched bound.
+    OutOfRange,
+    /// The decrypted message is not in the prime order subgroup, so it
+    /// can't be a multiple of the generator.
+    InvalidCiphertext,
+}
+
+impl fmt::Display for DecryptError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DecryptError::OutOfRange => {
+                write!(f, "The decrypted scalar is out of range")
+            }
+            DecryptError::InvalidCiphertext => {
+                write!(f, "The decrypted message is not in the prime subgroup")
+            }
+        }
+    }
+}
+
+/// Error returned by [`ElgamalCipher::try_encrypt`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum EncryptError {
+    /// The public key is the identity or not in the prime order subgroup.
+    InvalidPublicKey,
+    /// The generator is the identity or not in the prime order subgroup.
+    InvalidGenerator,
+}
+
+impl fmt::Display for EncryptError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EncryptError::InvalidPublicKey => {
+                write!(f, "The public key is not a prime order point")
+            }
+            EncryptError::InvalidGenerator => {
+                write!(f, "The generator is not a prime order point")
+            }
+        }
+    }
+}
+
+/// Error returned by [`ElgamalCipher::weighted_sum_with_check`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum WeightError {
+    /// The number of weights doesn't match the number of ciphers.
+    LengthMismatch,
+    /// The weights don't sum to the expected value.
+    SumMismatch,
+}
+
+impl fmt::Display for WeightError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            WeightError::LengthMismatch => {
+                write!(f, "Each cipher requires a weight")
+            }
+            WeightError::SumMismatch => {
+                write!(f, "The weights don't sum to the expected value")
+            }
+        }
+    }
+}
 
 /// Tuple for assymetric encryption using ElGamal algorithm.
 ///
@@ -75,11 +167,8 @@ impl Serializable<64> for ElgamalCipher {
 
     /// Serialize the cipher into bytes
     fn to_bytes(&self) -> [u8; Self::SIZE] {
-        let gamma: JubJubAffine = self.gamma.into();
-        let gamma = gamma.to_bytes();
-
-        let delta: JubJubAffine = self.delta.into();
-        let delta = delta.to_bytes();
+        let gamma = self.gamma_affine().to_bytes();
+        let delta = self.delta_affine().to_bytes();
 
         let mut bytes = [0u8; Self::SIZE];
 
@@ -93,7 +182,7 @@ impl Serializable<64> for ElgamalCipher {
     fn from_bytes(bytes: &[u8; Self::SIZE]) -> Result<Self, Self::Error> {
         let gamma = JubJubAffine::from_slice(&bytes[..32])?;
         let delta = JubJubAffine::from_slice(&bytes[32..])?;
-        let cipher = ElgamalCipher::new(gamma.into(), delta.into());
+        let cipher = ElgamalCipher::from_affine(gamma, delta);
         Ok(cipher)
     }
 }
@@ -104,6 +193,46 @@ impl ElgamalCipher {
         Self { gamma, delta }
     }
 
+    /// Cipher with both `γ` and `δ` the identity point, as
+    /// [`ElgamalCipher::default`] is.
+    ///
+    /// It is the neutral element of the homomorphic addition, and decrypts to
+    /// the identity point under any secret, so it is an encryption of the
+    /// scalar `0` that can be used as the initial value of a sum of ciphers.
+    pub const fn identity() -> Self {
+        Self {
+            gamma: JubJubExtended::identity(),
+            delta: JubJubExtended::identity(),
+        }
+    }
+
+    /// Determines if both `γ` and `δ` are the identity point.
+    pub fn is_identity(&self) -> Choice {
+        self.gamma.is_identity() & self.delta.is_identity()
+    }
+
+    /// Build a cipher from an iterator yielding exactly `γ` and `δ`, in this
+    /// order, such as the one of [`ElgamalCipher::into_iter`].
+    ///
+    /// The result is `none` if the iterator yields any other number of
+    /// points.
+    pub fn from_points<I>(points: I) -> CtOption<Self>
+    where
+        I: IntoIterator<Item = JubJubExtended>,
+    {
+        let mut points = points.into_iter();
+
+        let gamma = points.next();
+        let delta = points.next();
+        let exact = points.next().is_none();
+
+        let is_some = gamma.is_some() && delta.is_some() && exact;
+        let cipher =
+            Self::new(gamma.unwrap_or_default(), delta.unwrap_or_default());
+
+        CtOption::new(cipher, Choice::from(is_some as u8))
+    }
+
     /// Getter for the gamma public key
     pub fn gamma(&self) -> &JubJubExtended {
         &self.gamma
@@ -114,24 +243,425 @@ impl ElgamalCipher {
         &self.delta
     }
 
+    /// [`ElgamalCipher`] constructor from affine components
+    pub fn from_affine(gamma: JubJubAffine, delta: JubJubAffine) -> Self {
+        Self::new(gamma.into(), delta.into())
+    }
+
+    /// Getter for the gamma public key in affine form
+    pub fn gamma_affine(&self) -> JubJubAffine {
+        self.gamma.into()
+    }
+
+    /// Getter for the delta ciphertext in affine form
+    pub fn delta_affine(&self) -> JubJubAffine {
+        self.delta.into()
+    }
+
     /// Uses assymetric encryption to return a cipher construction.
     ///
     /// The decryption will expect the secret of `public`.
+    ///
+    /// Neither `public` nor `generator` are validated: an identity public
+    /// key yields `δ = message`, and a small order one leaks information
+    /// about the message. Use [`ElgamalCipher::try_encrypt`] for keys that
+    /// aren't trusted; only the identity is caught, by a debug assertion.
+    ///
+    /// `secret` is the ephemeral randomness of the cipher, which makes this
+    /// the same as [`ElgamalCipher::encrypt_with_randomness`].
     pub fn encrypt(
         secret: &JubJubScalar,
         public: &JubJubExtended,
         generator: &JubJubExtended,
         message: &JubJubExtended,
     ) -> Self {
+        Self::encrypt_with_randomness(secret, public, generator, message)
+    }
+
+    /// Encrypt `message` to `public` with the explicit randomness
+    /// `ephemeral`, producing `γ = G · r` and `δ = M + A · r`.
+    ///
+    /// The cipher is fully determined by its inputs, which makes this
+    /// suitable for reproducible test vectors. In production, `ephemeral`
+    /// must be freshly sampled with [`JubJubScalar::random`] for every
+    /// cipher: reusing it for two messages reveals their difference, and a
+    /// predictable one reveals the message.
+    ///
+    /// In debug builds, this panics if `public` or `generator` is the
+    /// identity, since the cipher then leaves `message` in the clear.
+    pub fn encrypt_with_randomness(
+        ephemeral: &JubJubScalar,
+        public: &JubJubExtended,
+        generator: &JubJubExtended,
+        message: &JubJubExtended,
+    ) -> Self {
+        debug_assert!(
+            !bool::from(public.is_identity()),
+            "encrypting to the identity public key"
+        );
+        debug_assert!(
+            !bool::from(generator.is_identity()),
+            "encrypting with the identity generator"
+        );
+
+        let gamma = generator * ephemeral;
+        let delta = message + public * ephemeral;
+
+        Self::new(gamma, delta)
+    }
+
+    /// Same as [`ElgamalCipher::encrypt`], but checking first that both
+    /// `public` and `generator` are points of the prime order subgroup other
+    /// than the identity.
+    pub fn try_encrypt(
+        secret: &JubJubScalar,
+        public: &JubJubExtended,
+        generator: &JubJubExtended,
+        message: &JubJubExtended,
+    ) -> Result<Self, EncryptError> {
+        let is_prime_order =
+            |p: &JubJubExtended| !p.is_identity() & p.is_torsion_free();
+
+        if !bool::from(is_prime_order(public)) {
+            return Err(EncryptError::InvalidPublicKey);
+        }
+        if !bool::from(is_prime_order(generator)) {
+            return Err(EncryptError::InvalidGenerator);
+        }
+
+        Ok(Self::encrypt(secret, public, generator, message))
+    }
+
+    /// Same as [`ElgamalCipher::encrypt`], with the products of the
+    /// randomness `r` already computed by the caller as `gamma = generator
+    /// · r` and `shared = public · r`.
+    ///
+    /// This only performs the addition `δ = message + shared`, so protocols
+    /// can compute the scalar multiplications ahead of time or in parallel.
+    /// The randomness must not be reused across messages, or the difference
+    /// of the plaintexts is revealed.
+    pub fn encrypt_with_shared(
+        gamma: &JubJubExtended,
+        shared: &JubJubExtended,
+        message: &JubJubExtended,
+    ) -> Self {
+        Self::new(*gamma, message + shared)
+    }
+
+    /// Same as [`ElgamalCipher::encrypt`], for affine inputs.
+    ///
+    /// The multiplications are performed directly on the affine points, so
+    /// there is no need to convert them to [`JubJubExtended`].
+    ///
+    /// In debug builds, this panics if `public` or `generator` is the
+    /// identity, as [`ElgamalCipher::encrypt_with_randomness`] does.
+    pub fn encrypt_affine(
+        secret: &JubJubScalar,
+        public: &JubJubAffine,
+        generator: &JubJubAffine,
+        message: &JubJubAffine,
+    ) -> Self {
+        debug_assert!(
+            public != &JubJubAffine::identity(),
+            "encrypting to the identity public key"
+        );
+        debug_assert!(
+            generator != &JubJubAffine::identity(),
+            "encrypting with the identity generator"
+        );
+
         let gamma = generator * secret;
-        let delta = message + public * secret;
+        let delta = public * secret + message;
 
         Self::new(gamma, delta)
     }
 
+    /// Prove knowledge of the scalar `message` and the randomness `r` of the
+    /// cipher produced by [`ElgamalCipher::encrypt`] with the message point
+    /// `generator · message`.
+    ///
+    /// The `transcript` binds the proof to a context, and must be provided
+    /// again to [`ElgamalCipher::verify_plaintext_knowledge`].
+    pub fn prove_plaintext_knowledge(
+        message: &JubJubScalar,
+        r: &JubJubScalar,
+        public: &JubJubExtended,
+        generator: &JubJubExtended,
+        transcript: &[u8],
+    ) -> PlaintextProof {
+        PlaintextProof::prove(message, r, public, generator, transcript)
+    }
+
+    /// Verify a [`PlaintextProof`] for this cipher.
+    pub fn verify_plaintext_knowledge(
+        &self,
+        proof: &PlaintextProof,
+        public: &JubJubExtended,
+        generator: &JubJubExtended,
+        transcript: &[u8],
+    ) -> bool {
+        proof.verify(self, public, generator, transcript)
+    }
+
+    /// Prove that `c1`, encrypted to `public1` with the randomness `r1`, and
+    /// `c2`, encrypted to `public2` with the randomness `r2`, encrypt the same
+    /// message.
+    ///
+    /// See [`EqualityProof`] for the exact relation proven.
+    pub fn prove_plaintext_equality(
+        c1: &ElgamalCipher,
+        r1: &JubJubScalar,
+        public1: &JubJubExtended,
+        c2: &ElgamalCipher,
+        r2: &JubJubScalar,
+        public2: &JubJubExtended,
+        generator: &JubJubExtended,
+    ) -> EqualityProof {
+        EqualityProof::prove(c1, r1, public1, c2, r2, public2, generator)
+    }
+
     /// Perform the decryption with the provided secret.
+    ///
+    /// Use an [`ElgamalDecryptor`] to decrypt many ciphers with the same
+    /// secret.
     pub fn decrypt(&self, secret: &JubJubScalar) -> JubJubExtended {
-        self.delta - self.gamma * secret
+        self.delta - self.shared_point(secret)
+    }
+
+    /// Determines, in constant time, whether the cipher decrypts to the
+    /// identity point with the provided secret, meaning it encrypts the
+    /// scalar `0`.
+    ///
+    /// Unlike [`ElgamalCipher::decrypt_scalar`], no discrete logarithm
+    /// search is performed.
+    pub fn decrypts_to_identity(&self, secret: &JubJubScalar) -> Choice {
+        self.decrypt(secret).is_identity()
+    }
+
+    /// Determines, in constant time, whether this cipher and `other` decrypt
+    /// to the same message point with the provided secret, such as a cipher
+    /// and its re-randomization.
+    ///
+    /// This is a diagnostic helper for the holder of the secret, and proves
+    /// nothing to anyone else; see [`PlaintextEqProof`] for a zero-knowledge
+    /// proof of the same relation.
+    pub fn same_plaintext(
+        &self,
+        other: &ElgamalCipher,
+        secret: &JubJubScalar,
+    ) -> Choice {
+        self.decrypt(secret).ct_eq(&other.decrypt(secret))
+    }
+
+    /// Determines, in constant time, whether this cipher is the encryption
+    /// of `message` to `public` with the randomness `r`, as produced by
+    /// [`ElgamalCipher::encrypt`].
+    ///
+    /// This is meant for tests and audits where the randomness is known;
+    /// see [`EncryptionProof`] to convince a verifier that doesn't know it.
+    pub fn is_encryption_of(
+        &self,
+        message: &JubJubExtended,
+        public: &JubJubExtended,
+        generator: &JubJubExtended,
+        r: &JubJubScalar,
+    ) -> Choice {
+        let gamma = generator * r;
+        let delta = message + public * r;
+
+        self.gamma.ct_eq(&gamma) & self.delta.ct_eq(&delta)
+    }
+
+    /// Perform the decryption with the provided secret, and recover the
+    /// scalar `m` of the message `M = generator · m` if it lies in `[0, max]`.
+    ///
+    /// Returns [`DecryptError::InvalidCiphertext`] if `M` is not in the prime
+    /// order subgroup, and [`DecryptError::OutOfRange`] if `m` is not found
+    /// within the bound, which is also the case for a wrong `secret`.
+    ///
+    /// The scalar is recovered with a linear discrete logarithm search, so
+    /// this function is variable time and runs in `O(max)`.
+    pub fn decrypt_scalar(
+        &self,
+        secret: &JubJubScalar,
+        generator: &JubJubExtended,
+        max: u64,
+    ) -> Result<u64, DecryptError> {
+        let message = self.decrypt(secret);
+
+        if !bool::from(message.is_torsion_free()) {
+            return Err(DecryptError::InvalidCiphertext);
+        }
+
+        let mut candidate = JubJubExtended::identity();
+        for value in 0..=max {
+            if candidate == message {
+                return Ok(value);
+            }
+            candidate += generator;
+        }
+
+        Err(DecryptError::OutOfRange)
+    }
+
+    /// Compute the Diffie-Hellman value `γ · a` shared between the sender and
+    /// the owner of `secret`, which is also `A · r` on the sender side.
+    ///
+    /// This is the value removed from `δ` by [`ElgamalCipher::decrypt`], and
+    /// allows callers to derive their own keys from it.
+    pub fn shared_point(&self, secret: &JubJubScalar) -> JubJubExtended {
+        self.gamma * secret
+    }
+
+    /// Add `other` to this cipher if `choice` is set, leaving it untouched
+    /// otherwise.
+    ///
+    /// The sum is always computed and then selected with
+    /// [`JubJubExtended::conditional_select`], so the timing is independent
+    /// of `choice`, such as when tallying ballots obliviously.
+    pub fn conditional_add_assign(
+        &mut self,
+        other: &ElgamalCipher,
+        choice: Choice,
+    ) {
+        let sum = *self + *other;
+
+        self.gamma =
+            JubJubExtended::conditional_select(&self.gamma, &sum.gamma, choice);
+        self.delta =
+            JubJubExtended::conditional_select(&self.delta, &sum.delta, choice);
+    }
+
+    /// Subtract `weight · other` from this cipher, in place.
+    ///
+    /// This is the same as `*self -= other * weight`, without building the
+    /// intermediate cipher, and decrypts to the message of this cipher minus
+    /// `weight` times the message of `other`.
+    pub fn sub_scaled(&mut self, other: &ElgamalCipher, weight: &JubJubScalar) {
+        self.gamma -= other.gamma * weight;
+        self.delta -= other.delta * weight;
+    }
+
+    /// Re-randomize the cipher by adding a fresh encryption of zero to it,
+    /// returning the new cipher and the random offset `t` used, such that
+    /// `c' = c + (generator · t, public · t)`.
+    ///
+    /// Both ciphers decrypt to the same message, and `c'` is unlinkable to
+    /// `c` for anyone that doesn't know the secret or `t`. The offset is the
+    /// witness of the relation between the two, used by interactive gadgets
+    /// such as the multiplication of two ciphers with the help of the
+    /// recipient:
+    ///
+    /// 1. The requester computes `(c', t)` and sends `c'` to a helper.
+    /// 2. The helper operates on `c'`, and proves the operation was correct.
+    /// 3. The requester, knowing `t`, relates the result back to `c`, and can
+    ///    prove to a third party that `c'` is a re-randomization of `c` with a
+    ///    [`PlaintextEqProof`], for which `t` is the witness.
+    ///
+    /// The offset must be kept secret while `c'` needs to be unlinkable.
+    pub fn rerandomize_to_zero_offset<R>(
+        &self,
+        public: &JubJubExtended,
+        generator: &JubJubExtended,
+        rng: &mut R,
+    ) -> (Self, JubJubScalar)
+    where
+        R: RngCore + CryptoRng,
+    {
+        let offset = JubJubScalar::random(rng);
+        let zero = Self::new(generator * offset, public * offset);
+
+        (self + &zero, offset)
+    }
+
+    /// Scale the cipher by `blinding`, returning the blinded cipher and the
+    /// inverse of `blinding`.
+    ///
+    /// This allows a helper holding the secret to decrypt the cipher without
+    /// learning its plaintext:
+    ///
+    /// 1. The requester computes `(c', b⁻¹) = c.blind(b)` with a random `b`,
+    ///    and sends `c'` to the helper.
+    /// 2. The helper returns `M' = c'.decrypt(a) = M · b`.
+    /// 3. The requester recovers `M = M' · b⁻¹`.
+    ///
+    /// # Panics
+    ///
+    /// If `blinding` is zero, since it has no inverse.
+    pub fn blind(&self, blinding: &JubJubScalar) -> (Self, JubJubScalar) {
+        let inverse = blinding.invert().unwrap();
+
+        (self * blinding, inverse)
+    }
+
+    /// Multiply the encrypted message by `ω^power`, being `ω` the primitive
+    /// `2^log_n`-th root of unity of [`JubJubScalar`].
+    ///
+    /// The result is `none` if `log_n` exceeds
+    /// [`JubJubScalar::TWO_ADICITY`].
+    pub fn scale_by_root(&self, log_n: u32, power: u64) -> CtOption<Self> {
+        JubJubScalar::root_of_unity(log_n)
+            .map(|omega| self * omega.pow(&[power, 0, 0, 0]))
+    }
+
+    /// Homomorphically compute `Σ weights[i] · ciphers[i]`, after checking
+    /// that the weights, which are public, sum to `expected_weight_sum`.
+    ///
+    /// This catches misconfigured weight vectors, such as the ones of a
+    /// weighted average, before they silently produce a wrong result.
+    pub fn weighted_sum_with_check(
+        ciphers: &[ElgamalCipher],
+        weights: &[JubJubScalar],
+        expected_weight_sum: JubJubScalar,
+    ) -> Result<ElgamalCipher, WeightError> {
+        if ciphers.len() != weights.len() {
+            return Err(WeightError::LengthMismatch);
+        }
+
+        let sum: JubJubScalar = weights.iter().sum();
+        if sum != expected_weight_sum {
+            return Err(WeightError::SumMismatch);
+        }
+
+        Ok(ciphers
+            .iter()
+            .zip(weights.iter())
+            .fold(ElgamalCipher::identity(), |acc, (c, w)| acc + c * w))
+    }
+
+    /// Perform the decryption of many ciphers with the same secret.
+    ///
+    /// The secret is recoded once into signed radix-16 digits by an
+    /// [`ElgamalDecryptor`], and the digits are shared across all the `γ · a`
+    /// windowed multiplications.
+    #[cfg(feature = "alloc")]
+    pub fn decrypt_batch(
+        ciphers: &[ElgamalCipher],
+        secret: &JubJubScalar,
+    ) -> Vec<JubJubExtended> {
+        let decryptor = ElgamalDecryptor::new(*secret);
+
+        ciphers.iter().map(|c| decryptor.decrypt(c)).collect()
+    }
+}
+
+impl IntoIterator for ElgamalCipher {
+    type Item = JubJubExtended;
+    type IntoIter = core::array::IntoIter<JubJubExtended, 2>;
+
+    /// Iterate over `[γ, δ]`
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIterator::into_iter([self.gamma, self.delta])
+    }
+}
+
+impl<'a> IntoIterator for &'a ElgamalCipher {
+    type Item = &'a JubJubExtended;
+    type IntoIter = core::array::IntoIter<&'a JubJubExtended, 2>;
+
+    /// Iterate over `[γ, δ]`
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIterator::into_iter([&self.gamma, &self.delta])
     }
 }
 
@@ -211,10 +741,14 @@ impl<'b> MulAssign<&'b JubJubScalar> for ElgamalCipher {
 #[cfg(test)]
 mod tests {
 
-    use super::ElgamalCipher;
-    use crate::{JubJubExtended, JubJubScalar, GENERATOR_EXTENDED};
+    use super::{DecryptError, ElgamalCipher, EncryptError, WeightError};
+    use crate::{
+        BlsScalar, JubJubAffine, JubJubExtended, JubJubScalar, GENERATOR,
+        GENERATOR_EXTENDED,
+    };
     use dusk_bytes::Serializable;
     use rand_core::OsRng;
+    use subtle::Choice;
 
     fn gen() -> (JubJubScalar, JubJubExtended, JubJubScalar, JubJubExtended) {
         let a = JubJubScalar::random(&mut OsRng);
@@ -239,6 +773,148 @@ mod tests {
         assert_eq!(m, decrypt);
     }
 
+    #[test]
+    fn encrypt_with_randomness() {
+        let (a, _, b, b_g) = gen();
+
+        let m = GENERATOR_EXTENDED * JubJubScalar::random(&mut OsRng);
+        let cipher = ElgamalCipher::encrypt_with_randomness(
+            &a,
+            &b_g,
+            &GENERATOR_EXTENDED,
+            &m,
+        );
+        assert_eq!(cipher.decrypt(&b), m);
+        assert_eq!(
+            cipher,
+            ElgamalCipher::encrypt(&a, &b_g, &GENERATOR_EXTENDED, &m)
+        );
+
+        // Reusing the randomness leaks the difference of the messages
+        let n = m + GENERATOR_EXTENDED;
+        let other = ElgamalCipher::encrypt_with_randomness(
+            &a,
+            &b_g,
+            &GENERATOR_EXTENDED,
+            &n,
+        );
+        assert_eq!(other.gamma(), cipher.gamma());
+        assert_eq!(other.delta() - cipher.delta(), GENERATOR_EXTENDED);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "identity public key")]
+    fn encrypt_to_identity() {
+        let (a, _, _, _) = gen();
+
+        ElgamalCipher::encrypt(
+            &a,
+            &JubJubExtended::identity(),
+            &GENERATOR_EXTENDED,
+            &GENERATOR_EXTENDED,
+        );
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "identity public key")]
+    fn encrypt_affine_to_identity() {
+        let (a, _, _, _) = gen();
+
+        ElgamalCipher::encrypt_affine(
+            &a,
+            &JubJubAffine::identity(),
+            &GENERATOR,
+            &GENERATOR,
+        );
+    }
+
+    #[test]
+    fn encrypt_affine() {
+        let (a, _, b, b_g) = gen();
+
+        let m = JubJubScalar::random(&mut OsRng);
+        let m = GENERATOR_EXTENDED * m;
+
+        let cipher = ElgamalCipher::encrypt(&a, &b_g, &GENERATOR_EXTENDED, &m);
+        let cipher_affine = ElgamalCipher::encrypt_affine(
+            &a,
+            &b_g.into(),
+            &GENERATOR,
+            &m.into(),
+        );
+
+        assert_eq!(cipher, cipher_affine);
+        assert_eq!(m, cipher_affine.decrypt(&b));
+    }
+
+    #[test]
+    fn try_encrypt() {
+        let (a, _, b, b_g) = gen();
+
+        let m = GENERATOR_EXTENDED * JubJubScalar::random(&mut OsRng);
+
+        let cipher = ElgamalCipher::encrypt(&a, &b_g, &GENERATOR_EXTENDED, &m);
+        assert_eq!(
+            ElgamalCipher::try_encrypt(&a, &b_g, &GENERATOR_EXTENDED, &m),
+            Ok(cipher)
+        );
+        assert_eq!(cipher.decrypt(&b), m);
+
+        let identity = JubJubExtended::identity();
+        assert_eq!(
+            ElgamalCipher::try_encrypt(&a, &identity, &GENERATOR_EXTENDED, &m),
+            Err(EncryptError::InvalidPublicKey)
+        );
+        assert_eq!(
+            ElgamalCipher::try_encrypt(&a, &b_g, &identity, &m),
+            Err(EncryptError::InvalidGenerator)
+        );
+
+        let torsion = JubJubExtended::torsion_subgroup()[3];
+        assert_eq!(
+            ElgamalCipher::try_encrypt(&a, &torsion, &GENERATOR_EXTENDED, &m),
+            Err(EncryptError::InvalidPublicKey)
+        );
+        assert_eq!(
+            ElgamalCipher::try_encrypt(
+                &a,
+                &(b_g + torsion),
+                &GENERATOR_EXTENDED,
+                &m
+            ),
+            Err(EncryptError::InvalidPublicKey)
+        );
+        assert_eq!(
+            ElgamalCipher::try_encrypt(
+                &a,
+                &b_g,
+                &(GENERATOR_EXTENDED + torsion),
+                &m
+            ),
+            Err(EncryptError::InvalidGenerator)
+        );
+    }
+
+    #[test]
+    fn encrypt_with_shared() {
+        let (a, _, b, b_g) = gen();
+
+        let m = JubJubScalar::random(&mut OsRng);
+        let m = GENERATOR_EXTENDED * m;
+
+        let gamma = GENERATOR_EXTENDED * a;
+        let shared = b_g * a;
+
+        let cipher = ElgamalCipher::encrypt(&a, &b_g, &GENERATOR_EXTENDED, &m);
+        let cipher_shared =
+            ElgamalCipher::encrypt_with_shared(&gamma, &shared, &m);
+
+        assert_eq!(cipher, cipher_shared);
+        assert_eq!(m, cipher_shared.decrypt(&b));
+    }
+
     #[test]
     fn wrong_key() {
         let (a, _, b, b_g) = gen();
@@ -342,6 +1018,438 @@ mod tests {
         assert_eq!(result, decrypt);
     }
 
+    #[test]
+    fn homomorphic_signed_weights() {
+        let (a, _, b, b_g) = gen();
+
+        let m = JubJubScalar::random(&mut OsRng);
+        let n = JubJubScalar::random(&mut OsRng);
+
+        let c_m = ElgamalCipher::encrypt(
+            &a,
+            &b_g,
+            &GENERATOR_EXTENDED,
+            &(GENERATOR_EXTENDED * m),
+        );
+        let c_n = ElgamalCipher::encrypt(
+            &a,
+            &b_g,
+            &GENERATOR_EXTENDED,
+            &(GENERATOR_EXTENDED * n),
+        );
+
+        let cipher =
+            &c_m * JubJubScalar::from(-3i64) + &c_n * JubJubScalar::from(5i128);
+        let decrypt = cipher.decrypt(&b);
+
+        let result =
+            -(m * JubJubScalar::from(3u64)) + n * JubJubScalar::from(5u64);
+        assert_eq!(GENERATOR_EXTENDED * result, decrypt);
+    }
+
+    #[test]
+    fn decrypt_batch() {
+        let (a, _, b, b_g) = gen();
+
+        let mut cipher = [ElgamalCipher::default(); 8];
+        cipher.iter_mut().for_each(|c| {
+            let m = JubJubScalar::random(&mut OsRng);
+            let m = GENERATOR_EXTENDED * m;
+            *c = ElgamalCipher::encrypt(&a, &b_g, &GENERATOR_EXTENDED, &m)
+        });
+
+        let decrypt = ElgamalCipher::decrypt_batch(&cipher, &b);
+
+        assert_eq!(cipher.len(), decrypt.len());
+        cipher
+            .iter()
+            .zip(decrypt.iter())
+            .for_each(|(c, d)| assert_eq!(&c.decrypt(&b), d));
+
+        assert!(ElgamalCipher::decrypt_batch(&[], &b).is_empty());
+    }
+
+    #[test]
+    fn blinded_decryption() {
+        let (a, _, b, b_g) = gen();
+
+        let m = JubJubScalar::random(&mut OsRng);
+        let m = GENERATOR_EXTENDED * m;
+
+        let cipher = ElgamalCipher::encrypt(&a, &b_g, &GENERATOR_EXTENDED, &m);
+
+        let blinding = JubJubScalar::random(&mut OsRng);
+        let (blinded, inverse) = cipher.blind(&blinding);
+
+        let decrypt = blinded.decrypt(&b);
+        assert_ne!(m, decrypt);
+        assert_eq!(m, decrypt * inverse);
+    }
+
+    #[test]
+    #[should_panic]
+    fn blind_zero() {
+        ElgamalCipher::default().blind(&JubJubScalar::zero());
+    }
+
+    #[test]
+    fn shared_point() {
+        let (a, a_g, b, b_g) = gen();
+
+        let m = JubJubScalar::random(&mut OsRng);
+        let m = GENERATOR_EXTENDED * m;
+
+        let cipher = ElgamalCipher::encrypt(&a, &b_g, &GENERATOR_EXTENDED, &m);
+
+        assert_eq!(cipher.shared_point(&b), b_g * a);
+        assert_eq!(cipher.shared_point(&b), a_g * b);
+        assert_eq!(cipher.delta() - cipher.shared_point(&b), m);
+    }
+
+    #[test]
+    fn scale_by_root() {
+        let (a, _, b, b_g) = gen();
+
+        let m = JubJubScalar::random(&mut OsRng);
+        let m = GENERATOR_EXTENDED * m;
+
+        let cipher = ElgamalCipher::encrypt(&a, &b_g, &GENERATOR_EXTENDED, &m);
+
+        for log_n in 0..=JubJubScalar::TWO_ADICITY {
+            let n = 1u64 << log_n;
+            let omega = JubJubScalar::root_of_unity(log_n).unwrap();
+
+            for power in 0..2 * n {
+                let scaled = cipher.scale_by_root(log_n, power).unwrap();
+                assert_eq!(
+                    scaled.decrypt(&b),
+                    m * omega.pow(&[power, 0, 0, 0])
+                );
+
+                // ω^power · ω^(n - power mod n) = ω^n = 1
+                let restored =
+                    scaled.scale_by_root(log_n, n - power % n).unwrap();
+                assert_eq!(restored.decrypt(&b), m);
+            }
+        }
+
+        let scaled = cipher.scale_by_root(1, 1).unwrap();
+        assert_eq!(scaled.decrypt(&b), -m);
+
+        let invalid = JubJubScalar::TWO_ADICITY + 1;
+        assert!(bool::from(cipher.scale_by_root(invalid, 1).is_none()));
+    }
+
+    #[test]
+    fn into_iter() {
+        let (a, _, _, b_g) = gen();
+
+        let m = JubJubScalar::random(&mut OsRng);
+        let m = GENERATOR_EXTENDED * m;
+
+        let cipher = ElgamalCipher::encrypt(&a, &b_g, &GENERATOR_EXTENDED, &m);
+
+        let mut points = (&cipher).into_iter();
+        assert_eq!(points.next(), Some(cipher.gamma()));
+        assert_eq!(points.next(), Some(cipher.delta()));
+        assert_eq!(points.next(), None);
+
+        let mut count = 0;
+        for (i, p) in cipher.into_iter().enumerate() {
+            let expected = [cipher.gamma(), cipher.delta()][i];
+            assert_eq!(&p, expected);
+            count += 1;
+        }
+        assert_eq!(count, 2);
+
+        let restored = ElgamalCipher::from_points(cipher).unwrap();
+        assert_eq!(restored, cipher);
+
+        let none = ElgamalCipher::from_points(vec![*cipher.gamma()]);
+        assert!(bool::from(none.is_none()));
+
+        let none = ElgamalCipher::from_points(vec![m; 3]);
+        assert!(bool::from(none.is_none()));
+
+        let none = ElgamalCipher::from_points(vec![]);
+        assert!(bool::from(none.is_none()));
+    }
+
+    #[test]
+    fn weighted_sum_with_check() {
+        let (_, _, b, b_g) = gen();
+
+        let values = [10u64, 20, 60];
+        let weights = [
+            JubJubScalar::from(5u64),
+            JubJubScalar::from(3u64),
+            JubJubScalar::from(2u64),
+        ];
+
+        let ciphers: Vec<ElgamalCipher> = values
+            .iter()
+            .map(|v| {
+                let r = JubJubScalar::random(&mut OsRng);
+                let m = GENERATOR_EXTENDED * JubJubScalar::from(*v);
+                ElgamalCipher::encrypt(&r, &b_g, &GENERATOR_EXTENDED, &m)
+            })
+            .collect();
+
+        let sum = ElgamalCipher::weighted_sum_with_check(
+            &ciphers,
+            &weights,
+            JubJubScalar::from(10u64),
+        )
+        .unwrap();
+        assert_eq!(sum.decrypt_scalar(&b, &GENERATOR_EXTENDED, 500), Ok(230));
+
+        assert_eq!(
+            ElgamalCipher::weighted_sum_with_check(
+                &ciphers,
+                &weights,
+                JubJubScalar::from(11u64),
+            ),
+            Err(WeightError::SumMismatch)
+        );
+        assert_eq!(
+            ElgamalCipher::weighted_sum_with_check(
+                &ciphers[..2],
+                &weights,
+                JubJubScalar::from(10u64),
+            ),
+            Err(WeightError::LengthMismatch)
+        );
+    }
+
+    #[test]
+    fn identity() {
+        let identity = ElgamalCipher::identity();
+        assert_eq!(identity, ElgamalCipher::default());
+        assert!(bool::from(identity.is_identity()));
+
+        let (a, a_g, b, b_g) = gen();
+        assert_eq!(identity.decrypt(&a), JubJubExtended::identity());
+        assert_eq!(identity.decrypt(&b), JubJubExtended::identity());
+
+        let m = GENERATOR_EXTENDED * JubJubScalar::random(&mut OsRng);
+        let cipher = ElgamalCipher::encrypt(&a, &b_g, &GENERATOR_EXTENDED, &m);
+        assert!(!bool::from(cipher.is_identity()));
+
+        assert_eq!(cipher + identity, cipher);
+        assert_eq!(identity + cipher, cipher);
+        assert_eq!(cipher - identity, cipher);
+
+        let mut sum = identity;
+        sum += cipher;
+        assert_eq!(sum, cipher);
+        assert_eq!(sum.decrypt(&b), m);
+
+        // Only one component being the identity is not enough
+        let half = ElgamalCipher::new(JubJubExtended::identity(), a_g);
+        assert!(!bool::from(half.is_identity()));
+        let half = ElgamalCipher::new(a_g, JubJubExtended::identity());
+        assert!(!bool::from(half.is_identity()));
+    }
+
+    #[test]
+    fn conditional_add_assign() {
+        let (a, _, b, b_g) = gen();
+
+        let m = GENERATOR_EXTENDED * JubJubScalar::from(3u64);
+        let one = GENERATOR_EXTENDED * JubJubScalar::one();
+
+        let tally = ElgamalCipher::encrypt(&a, &b_g, &GENERATOR_EXTENDED, &m);
+        let r = JubJubScalar::random(&mut OsRng);
+        let ballot =
+            ElgamalCipher::encrypt(&r, &b_g, &GENERATOR_EXTENDED, &one);
+
+        let mut unchanged = tally;
+        unchanged.conditional_add_assign(&ballot, Choice::from(0));
+        assert_eq!(unchanged, tally);
+
+        let mut added = tally;
+        added.conditional_add_assign(&ballot, Choice::from(1));
+        assert_eq!(added, tally + ballot);
+        assert_eq!(added.decrypt_scalar(&b, &GENERATOR_EXTENDED, 10), Ok(4));
+    }
+
+    #[test]
+    fn sub_scaled() {
+        let (a, _, b, b_g) = gen();
+
+        let m = GENERATOR_EXTENDED * JubJubScalar::from(10u64);
+        let one = GENERATOR_EXTENDED * JubJubScalar::one();
+
+        let acc = ElgamalCipher::encrypt(&a, &b_g, &GENERATOR_EXTENDED, &m);
+        let r = JubJubScalar::random(&mut OsRng);
+        let c = ElgamalCipher::encrypt(&r, &b_g, &GENERATOR_EXTENDED, &one);
+
+        let weight = JubJubScalar::from(3u64);
+        let mut fused = acc;
+        fused.sub_scaled(&c, &weight);
+        assert_eq!(fused, acc - &c * weight);
+        assert_eq!(fused.decrypt_scalar(&b, &GENERATOR_EXTENDED, 10), Ok(7));
+
+        // A negative weight adds instead
+        let mut fused = acc;
+        fused.sub_scaled(&c, &-weight);
+        assert_eq!(fused, acc + &c * weight);
+        assert_eq!(fused.decrypt_scalar(&b, &GENERATOR_EXTENDED, 20), Ok(13));
+
+        let mut unchanged = acc;
+        unchanged.sub_scaled(&c, &JubJubScalar::zero());
+        assert_eq!(unchanged, acc);
+    }
+
+    #[test]
+    fn rerandomize_to_zero_offset() {
+        let (a, _, b, b_g) = gen();
+
+        let m = GENERATOR_EXTENDED * JubJubScalar::random(&mut OsRng);
+        let cipher = ElgamalCipher::encrypt(&a, &b_g, &GENERATOR_EXTENDED, &m);
+
+        let (rerandomized, offset) = cipher.rerandomize_to_zero_offset(
+            &b_g,
+            &GENERATOR_EXTENDED,
+            &mut OsRng,
+        );
+        assert_ne!(rerandomized, cipher);
+        assert_eq!(rerandomized.decrypt(&b), m);
+
+        // The difference is the encryption of zero with the offset
+        let zero = ElgamalCipher::encrypt(
+            &offset,
+            &b_g,
+            &GENERATOR_EXTENDED,
+            &JubJubExtended::identity(),
+        );
+        assert_eq!(rerandomized - cipher, zero);
+        assert_eq!(
+            rerandomized,
+            ElgamalCipher::encrypt(
+                &(a + offset),
+                &b_g,
+                &GENERATOR_EXTENDED,
+                &m
+            )
+        );
+
+        let (other, other_offset) = cipher.rerandomize_to_zero_offset(
+            &b_g,
+            &GENERATOR_EXTENDED,
+            &mut OsRng,
+        );
+        assert_ne!(other, rerandomized);
+        assert_ne!(other_offset, offset);
+    }
+
+    #[test]
+    fn same_plaintext() {
+        let (a, _, b, b_g) = gen();
+
+        let m = GENERATOR_EXTENDED * JubJubScalar::random(&mut OsRng);
+        let cipher = ElgamalCipher::encrypt(&a, &b_g, &GENERATOR_EXTENDED, &m);
+
+        let r = JubJubScalar::random(&mut OsRng);
+        let zero = ElgamalCipher::encrypt(
+            &r,
+            &b_g,
+            &GENERATOR_EXTENDED,
+            &JubJubExtended::identity(),
+        );
+        let rerandomized = cipher + zero;
+        assert_ne!(rerandomized.to_bytes(), cipher.to_bytes());
+        assert!(bool::from(cipher.same_plaintext(&rerandomized, &b)));
+
+        let other = GENERATOR_EXTENDED * JubJubScalar::random(&mut OsRng);
+        let other =
+            ElgamalCipher::encrypt(&a, &b_g, &GENERATOR_EXTENDED, &other);
+        assert!(!bool::from(cipher.same_plaintext(&other, &b)));
+    }
+
+    #[test]
+    fn is_encryption_of() {
+        let (a, a_g, b, b_g) = gen();
+        let g = GENERATOR_EXTENDED;
+
+        let m = g * JubJubScalar::random(&mut OsRng);
+        let cipher = ElgamalCipher::encrypt(&a, &b_g, &g, &m);
+        assert!(bool::from(cipher.is_encryption_of(&m, &b_g, &g, &a)));
+
+        let other = m + g;
+        assert!(!bool::from(cipher.is_encryption_of(&other, &b_g, &g, &a)));
+        assert!(!bool::from(cipher.is_encryption_of(&m, &b_g, &g, &b)));
+        assert!(!bool::from(cipher.is_encryption_of(&m, &a_g, &g, &a)));
+        assert!(!bool::from(cipher.is_encryption_of(&m, &b_g, &b_g, &a)));
+
+        // Same cipher in another projective representation
+        let (gamma, delta) = (cipher.gamma(), cipher.delta());
+        let cipher =
+            ElgamalCipher::new(gamma.double() - gamma, delta.double() - delta);
+        assert!(bool::from(cipher.is_encryption_of(&m, &b_g, &g, &a)));
+    }
+
+    #[test]
+    fn decrypts_to_identity() {
+        let (a, _, b, b_g) = gen();
+
+        let zero = ElgamalCipher::encrypt(
+            &a,
+            &b_g,
+            &GENERATOR_EXTENDED,
+            &JubJubExtended::identity(),
+        );
+        assert_eq!(zero.decrypts_to_identity(&b).unwrap_u8(), 1);
+        assert_eq!(zero.decrypts_to_identity(&a).unwrap_u8(), 0);
+
+        let m = GENERATOR_EXTENDED * JubJubScalar::from(3u64);
+        let cipher = ElgamalCipher::encrypt(&a, &b_g, &GENERATOR_EXTENDED, &m);
+        assert_eq!(cipher.decrypts_to_identity(&b).unwrap_u8(), 0);
+
+        // The difference of two encryptions of the same scalar is zero
+        let other = ElgamalCipher::encrypt(&b, &b_g, &GENERATOR_EXTENDED, &m);
+        assert_eq!((cipher - other).decrypts_to_identity(&b).unwrap_u8(), 1);
+    }
+
+    #[test]
+    fn decrypt_scalar() {
+        let (a, _, b, b_g) = gen();
+
+        let m = GENERATOR_EXTENDED * JubJubScalar::from(37u64);
+        let cipher = ElgamalCipher::encrypt(&a, &b_g, &GENERATOR_EXTENDED, &m);
+
+        assert_eq!(cipher.decrypt_scalar(&b, &GENERATOR_EXTENDED, 100), Ok(37));
+        assert_eq!(cipher.decrypt_scalar(&b, &GENERATOR_EXTENDED, 37), Ok(37));
+        assert_eq!(
+            cipher.decrypt_scalar(&b, &GENERATOR_EXTENDED, 36),
+            Err(DecryptError::OutOfRange)
+        );
+        assert_eq!(
+            cipher.decrypt_scalar(&a, &GENERATOR_EXTENDED, 100),
+            Err(DecryptError::OutOfRange)
+        );
+
+        let zero = ElgamalCipher::encrypt(
+            &a,
+            &b_g,
+            &GENERATOR_EXTENDED,
+            &JubJubExtended::identity(),
+        );
+        assert_eq!(zero.decrypt_scalar(&b, &GENERATOR_EXTENDED, 0), Ok(0));
+
+        // Shift the message out of the prime order subgroup
+        let torsion = JubJubAffine::from_raw_unchecked(
+            BlsScalar::zero(),
+            -BlsScalar::one(),
+        );
+        let coset =
+            ElgamalCipher::new(*cipher.gamma(), cipher.delta() + torsion);
+        assert_eq!(
+            coset.decrypt_scalar(&b, &GENERATOR_EXTENDED, 100),
+            Err(DecryptError::InvalidCiphertext)
+        );
+    }
+
     #[test]
     fn to_bytes() {
         let (a, _, b, b_g) = gen();
@@ -357,4 +1465,47 @@ mod tests {
 
         assert_eq!(m, decrypt);
     }
+
+    #[test]
+    fn from_affine() {
+        let gamma = JubJubAffine::from(
+            GENERATOR_EXTENDED * JubJubScalar::random(&mut OsRng),
+        );
+        let delta = JubJubAffine::from(
+            GENERATOR_EXTENDED * JubJubScalar::random(&mut OsRng),
+        );
+
+        let cipher = ElgamalCipher::from_affine(gamma, delta);
+        assert_eq!(
+            cipher,
+            ElgamalCipher::new(
+                JubJubExtended::from(gamma),
+                JubJubExtended::from(delta)
+            )
+        );
+        assert_eq!(cipher.gamma_affine(), gamma);
+        assert_eq!(cipher.delta_affine(), delta);
+
+        let identity = JubJubAffine::identity();
+        assert_eq!(
+            ElgamalCipher::from_affine(identity, identity),
+            ElgamalCipher::identity()
+        );
+    }
+
+    #[test]
+    fn affine_components() {
+        let (a, _, _, b_g) = gen();
+
+        let m = JubJubScalar::random(&mut OsRng);
+        let m = GENERATOR_EXTENDED * m;
+
+        let cipher = ElgamalCipher::encrypt(&a, &b_g, &GENERATOR_EXTENDED, &m);
+        let gamma = cipher.gamma_affine();
+        let delta = cipher.delta_affine();
+
+        assert_eq!(gamma, JubJubAffine::from(cipher.gamma()));
+        assert_eq!(delta, JubJubAffine::from(cipher.delta()));
+        assert_eq!(cipher, ElgamalCipher::from_affine(gamma, delta));
+    }
 }
@@ -0,0 +1,151 @@
+use super::ElgamalCipher;
+use crate::{JubJubExtended, JubJubScalar};
+
+use alloc::vec::Vec;
+use rand_core::{CryptoRng, RngCore};
+
+/// Encrypted bitmap, where every slot is an [`ElgamalCipher`] of a scalar
+/// that is zero if the slot is unset.
+///
+/// Slots are set by homomorphically adding an encryption of one, so the
+/// holder of the accumulator can update it without learning its content.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BitAccumulator {
+    slots: Vec<ElgamalCipher>,
+    public: JubJubExtended,
+    generator: JubJubExtended,
+}
+
+impl BitAccumulator {
+    /// Create an accumulator of `slots` slots, all set to a fresh encryption
+    /// of zero to `public`.
+    ///
+    /// The encryptions of zero are randomized with `rng`, so set and unset
+    /// slots are indistinguishable.
+    pub fn new<R>(
+        slots: usize,
+        public: JubJubExtended,
+        generator: JubJubExtended,
+        rng: &mut R,
+    ) -> Self
+    where
+        R: RngCore + CryptoRng,
+    {
+        let mut accumulator = Self {
+            slots: Vec::with_capacity(slots),
+            public,
+            generator,
+        };
+
+        let zero = JubJubExtended::identity();
+        for _ in 0..slots {
+            let cipher = accumulator.encrypt(&zero, rng);
+            accumulator.slots.push(cipher);
+        }
+
+        accumulator
+    }
+
+    fn encrypt<R>(&self, message: &JubJubExtended, rng: &mut R) -> ElgamalCipher
+    where
+        R: RngCore + CryptoRng,
+    {
+        let r = JubJubScalar::random(rng);
+        ElgamalCipher::encrypt(&r, &self.public, &self.generator, message)
+    }
+
+    /// Number of slots of the accumulator
+    pub fn len(&self) -> usize {
+        self.slots.len()
+    }
+
+    /// Whether the accumulator has no slots
+    pub fn is_empty(&self) -> bool {
+        self.slots.is_empty()
+    }
+
+    /// Encrypted slots of the accumulator
+    pub fn slots(&self) -> &[ElgamalCipher] {
+        &self.slots
+    }
+
+    /// Set the slot `i` by adding a fresh encryption of one to it.
+    ///
+    /// # Panics
+    ///
+    /// If `i` is out of bounds.
+    pub fn set<R>(&mut self, i: usize, rng: &mut R)
+    where
+        R: RngCore + CryptoRng,
+    {
+        let one = self.encrypt(&self.generator, rng);
+        self.slots[i] += one;
+    }
+
+    /// Decrypt the slots with the secret of the public key.
+    ///
+    /// A slot is reported as set unless it decrypts to zero. The slots are
+    /// decrypted with [`ElgamalCipher::decrypt_scalar`] bounded by `2`.
+    pub fn decrypt_bits(&self, secret: &JubJubScalar) -> Vec<bool> {
+        self.slots
+            .iter()
+            .map(|c| c.decrypt_scalar(secret, &self.generator, 2) != Ok(0))
+            .collect()
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg(test)]
+mod tests {
+    use super::BitAccumulator;
+    use crate::{JubJubScalar, GENERATOR_EXTENDED};
+    use rand_core::OsRng;
+
+    #[test]
+    fn decrypt_bits() {
+        let secret = JubJubScalar::random(&mut OsRng);
+        let public = GENERATOR_EXTENDED * secret;
+
+        let mut accumulator =
+            BitAccumulator::new(10, public, GENERATOR_EXTENDED, &mut OsRng);
+        assert_eq!(accumulator.len(), 10);
+        assert_eq!(accumulator.decrypt_bits(&secret), vec![false; 10]);
+
+        accumulator.set(1, &mut OsRng);
+        accumulator.set(4, &mut OsRng);
+        accumulator.set(9, &mut OsRng);
+
+        let mut expected = vec![false; 10];
+        expected[1] = true;
+        expected[4] = true;
+        expected[9] = true;
+        assert_eq!(accumulator.decrypt_bits(&secret), expected);
+
+        // Setting a slot twice keeps it set
+        accumulator.set(4, &mut OsRng);
+        assert_eq!(accumulator.decrypt_bits(&secret), expected);
+
+        let other = JubJubScalar::random(&mut OsRng);
+        assert_ne!(accumulator.decrypt_bits(&other), expected);
+    }
+
+    #[test]
+    fn randomized_zeros() {
+        let public = GENERATOR_EXTENDED * JubJubScalar::random(&mut OsRng);
+        let accumulator =
+            BitAccumulator::new(2, public, GENERATOR_EXTENDED, &mut OsRng);
+
+        assert_ne!(accumulator.slots()[0], accumulator.slots()[1]);
+        assert!(!accumulator.is_empty());
+    }
+
+    #[test]
+    #[should_panic]
+    fn set_out_of_bounds() {
+        let public = GENERATOR_EXTENDED * JubJubScalar::random(&mut OsRng);
+        let mut accumulator =
+            BitAccumulator::new(2, public, GENERATOR_EXTENDED, &mut OsRng);
+
+        accumulator.set(2, &mut OsRng);
+    }
+}
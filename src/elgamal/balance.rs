@@ -0,0 +1,216 @@
+use super::ElgamalCipher;
+use crate::{
+    mul_by_generator, JubJubAffine, JubJubExtended, JubJubScalar,
+    GENERATOR_EXTENDED,
+};
+
+use core::fmt;
+use dusk_bytes::{DeserializableSlice, Error as BytesError, Serializable};
+use sha2::{Digest, Sha256};
+
+/// Error returned when combining [`EncryptedBalance`] structures encrypted
+/// under different public keys.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct KeyMismatch;
+
+impl fmt::Display for KeyMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "The balances are encrypted under different keys")
+    }
+}
+
+/// An amount encrypted with [`ElgamalCipher`], tagged with the identifier of
+/// the public key it is encrypted to.
+///
+/// The amount `v` is represented as the point `G · v`, being `G` the
+/// [`GENERATOR_EXTENDED`], which is also the generator of the public key.
+///
+/// Balances can only be combined if they share the same key identifier, so
+/// ciphers under different keys are never mixed.
+#[derive(Debug, Copy, Clone, PartialEq, Default)]
+pub struct EncryptedBalance {
+    cipher: ElgamalCipher,
+    key_id: [u8; 32],
+}
+
+impl Serializable<96> for EncryptedBalance {
+    type Error = BytesError;
+
+    /// Serialize the balance into bytes
+    fn to_bytes(&self) -> [u8; Self::SIZE] {
+        let mut bytes = [0u8; Self::SIZE];
+
+        bytes[..64].copy_from_slice(&self.cipher.to_bytes());
+        bytes[64..].copy_from_slice(&self.key_id);
+
+        bytes
+    }
+
+    /// Deserialize from a [`EncryptedBalance::to_bytes`] construction
+    fn from_bytes(bytes: &[u8; Self::SIZE]) -> Result<Self, Self::Error> {
+        let cipher = ElgamalCipher::from_slice(&bytes[..64])?;
+
+        let mut key_id = [0u8; 32];
+        key_id.copy_from_slice(&bytes[64..]);
+
+        Ok(Self { cipher, key_id })
+    }
+}
+
+impl EncryptedBalance {
+    /// Identifier of a public key, computed as the SHA-256 hash of its
+    /// compressed form.
+    pub fn key_id(public: &JubJubExtended) -> [u8; 32] {
+        let mut id = [0u8; 32];
+        id.copy_from_slice(&Sha256::digest(
+            &JubJubAffine::from(public).to_bytes(),
+        ));
+
+        id
+    }
+
+    /// Encrypt `value` to `public` with the randomness `r`.
+    pub fn encrypt(
+        r: &JubJubScalar,
+        public: &JubJubExtended,
+        value: u64,
+    ) -> Self {
+        let message = mul_by_generator(&JubJubScalar::from(value));
+        let gamma = mul_by_generator(r);
+        let cipher =
+            ElgamalCipher::encrypt_with_shared(&gamma, &(public * r), &message);
+
+        Self {
+            cipher,
+            key_id: Self::key_id(public),
+        }
+    }
+
+    /// Underlying cipher of the balance
+    pub fn cipher(&self) -> &ElgamalCipher {
+        &self.cipher
+    }
+
+    /// Identifier of the public key the balance is encrypted to
+    pub fn public_key_id(&self) -> &[u8; 32] {
+        &self.key_id
+    }
+
+    /// Homomorphically add `other` to this balance.
+    ///
+    /// Returns [`KeyMismatch`] if the balances are encrypted under different
+    /// keys.
+    pub fn add(&self, other: &Self) -> Result<Self, KeyMismatch> {
+        self.combine(other, |a, b| a + b)
+    }
+
+    /// Homomorphically subtract `other` from this balance.
+    ///
+    /// Returns [`KeyMismatch`] if the balances are encrypted under different
+    /// keys.
+    pub fn sub(&self, other: &Self) -> Result<Self, KeyMismatch> {
+        self.combine(other, |a, b| a - b)
+    }
+
+    fn combine<F>(&self, other: &Self, f: F) -> Result<Self, KeyMismatch>
+    where
+        F: Fn(&ElgamalCipher, &ElgamalCipher) -> ElgamalCipher,
+    {
+        if self.key_id != other.key_id {
+            return Err(KeyMismatch);
+        }
+
+        Ok(Self {
+            cipher: f(&self.cipher, &other.cipher),
+            key_id: self.key_id,
+        })
+    }
+
+    /// Decrypt the balance, returning its amount if it lies in `[0, max]`.
+    ///
+    /// Returns `None` if `secret` doesn't match the key the balance is
+    /// encrypted to, or if the amount is out of the given range, which is
+    /// also the case when homomorphic operations over- or underflowed.
+    ///
+    /// The amount is recovered with [`ElgamalCipher::decrypt_scalar`], so
+    /// this function is variable time and runs in `O(max)`.
+    pub fn checked_decrypt(
+        &self,
+        secret: &JubJubScalar,
+        max: u64,
+    ) -> Option<u64> {
+        let public = mul_by_generator(secret);
+        if Self::key_id(&public) != self.key_id {
+            return None;
+        }
+
+        self.cipher
+            .decrypt_scalar(secret, &GENERATOR_EXTENDED, max)
+            .ok()
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg(test)]
+mod tests {
+    use super::{EncryptedBalance, KeyMismatch};
+    use crate::{JubJubExtended, JubJubScalar, GENERATOR_EXTENDED};
+    use dusk_bytes::Serializable;
+    use rand_core::OsRng;
+
+    fn gen() -> (JubJubScalar, JubJubExtended) {
+        let secret = JubJubScalar::random(&mut OsRng);
+        let public = GENERATOR_EXTENDED * secret;
+
+        (secret, public)
+    }
+
+    fn encrypt(public: &JubJubExtended, value: u64) -> EncryptedBalance {
+        let r = JubJubScalar::random(&mut OsRng);
+        EncryptedBalance::encrypt(&r, public, value)
+    }
+
+    #[test]
+    fn matched_keys() {
+        let (secret, public) = gen();
+
+        let a = encrypt(&public, 700);
+        let b = encrypt(&public, 250);
+
+        let sum = a.add(&b).unwrap();
+        assert_eq!(sum.checked_decrypt(&secret, 1000), Some(950));
+
+        let diff = a.sub(&b).unwrap();
+        assert_eq!(diff.checked_decrypt(&secret, 1000), Some(450));
+
+        let balance = EncryptedBalance::from_bytes(&sum.to_bytes()).unwrap();
+        assert_eq!(balance, sum);
+    }
+
+    #[test]
+    fn mismatched_keys() {
+        let (_, public_a) = gen();
+        let (secret_b, public_b) = gen();
+
+        let a = encrypt(&public_a, 10);
+        let b = encrypt(&public_b, 20);
+
+        assert_eq!(a.add(&b), Err(KeyMismatch));
+        assert_eq!(a.sub(&b), Err(KeyMismatch));
+        assert_eq!(a.checked_decrypt(&secret_b, 100), None);
+    }
+
+    #[test]
+    fn out_of_range() {
+        let (secret, public) = gen();
+
+        let a = encrypt(&public, 10);
+        let b = encrypt(&public, 20);
+
+        assert_eq!(a.checked_decrypt(&secret, 9), None);
+        assert_eq!(a.checked_decrypt(&secret, 10), Some(10));
+
+        let underflow = a.sub(&b).unwrap();
+        assert_eq!(underflow.checked_decrypt(&secret, 1000), None);
+    }
+}
@@ -0,0 +1,112 @@
+use super::ElgamalCipher;
+use crate::{ExtendedNielsPoint, JubJubExtended, JubJubScalar};
+
+use core::fmt;
+
+/// Long-lived decryptor of [`ElgamalCipher`]s under a fixed secret.
+///
+/// The secret is recoded once into signed radix-16 digits, so every
+/// decryption only builds a small table of multiples of `γ` and performs a
+/// constant-time windowed multiplication, with 64 additions instead of one
+/// per bit of the secret.
+#[derive(Clone)]
+pub struct ElgamalDecryptor {
+    secret: JubJubScalar,
+    digits: [i8; 64],
+}
+
+impl fmt::Debug for ElgamalDecryptor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ElgamalDecryptor").finish_non_exhaustive()
+    }
+}
+
+impl ElgamalDecryptor {
+    /// Create a decryptor for the ciphers encrypted to the public key of
+    /// `secret`.
+    pub fn new(secret: JubJubScalar) -> Self {
+        let digits = secret.to_radix_16();
+
+        Self { secret, digits }
+    }
+
+    /// Secret used to decrypt
+    pub fn secret(&self) -> &JubJubScalar {
+        &self.secret
+    }
+
+    /// Compute the Diffie-Hellman value `γ · a` of the cipher, as
+    /// [`ElgamalCipher::shared_point`] does.
+    pub fn shared_point(&self, cipher: &ElgamalCipher) -> JubJubExtended {
+        let gamma = cipher.gamma();
+
+        let mut table = [gamma.to_niels(); 8];
+        let mut p = *gamma;
+        for entry in table.iter_mut().skip(1) {
+            p += gamma;
+            *entry = p.to_niels();
+        }
+
+        self.digits
+            .iter()
+            .rev()
+            .fold(JubJubExtended::identity(), |acc, d| {
+                let acc = acc.double().double().double().double();
+                acc + ExtendedNielsPoint::lookup_signed(&table, *d)
+            })
+    }
+
+    /// Perform the decryption, returning the same point as
+    /// [`ElgamalCipher::decrypt`] with the secret of the decryptor.
+    pub fn decrypt(&self, cipher: &ElgamalCipher) -> JubJubExtended {
+        cipher.delta() - self.shared_point(cipher)
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg(test)]
+mod tests {
+    use super::ElgamalDecryptor;
+    use crate::elgamal::ElgamalCipher;
+    use crate::{JubJubExtended, JubJubScalar, GENERATOR_EXTENDED};
+    use rand_core::OsRng;
+
+    #[test]
+    fn decrypt() {
+        let secret = JubJubScalar::random(&mut OsRng);
+        let public = GENERATOR_EXTENDED * secret;
+
+        let decryptor = ElgamalDecryptor::new(secret);
+        assert_eq!(decryptor.secret(), &secret);
+        assert_eq!(format!("{:?}", decryptor), "ElgamalDecryptor { .. }");
+
+        for _ in 0..32 {
+            let r = JubJubScalar::random(&mut OsRng);
+            let m = GENERATOR_EXTENDED * JubJubScalar::random(&mut OsRng);
+            let cipher =
+                ElgamalCipher::encrypt(&r, &public, &GENERATOR_EXTENDED, &m);
+
+            assert_eq!(decryptor.decrypt(&cipher), cipher.decrypt(&secret));
+            assert_eq!(decryptor.decrypt(&cipher), m);
+        }
+
+        let identity = ElgamalCipher::identity();
+        assert_eq!(decryptor.decrypt(&identity), JubJubExtended::identity());
+
+        // Points outside of the prime order subgroup are multiplied alike
+        let torsion = JubJubExtended::torsion_subgroup()[0];
+        let cipher = ElgamalCipher::new(GENERATOR_EXTENDED + torsion, torsion);
+        assert_eq!(decryptor.decrypt(&cipher), cipher.decrypt(&secret));
+
+        for s in [
+            JubJubScalar::zero(),
+            JubJubScalar::one(),
+            -JubJubScalar::one(),
+        ]
+        .iter()
+        {
+            let decryptor = ElgamalDecryptor::new(*s);
+            assert_eq!(decryptor.decrypt(&cipher), cipher.decrypt(s));
+        }
+    }
+}
@@ -0,0 +1,1286 @@
+use super::ElgamalCipher;
+use crate::transcript::{PointHasher, Transcript};
+use crate::{JubJubAffine, JubJubExtended, JubJubScalar};
+
+use dusk_bytes::{DeserializableSlice, Error as BytesError, Serializable};
+use rand_core::{CryptoRng, RngCore};
+use sha2::{Digest, Sha512};
+
+/// Compute a Fiat-Shamir challenge from a domain label, a caller-provided
+/// context and a list of points, bound by their [`PointHasher`] digest.
+///
+/// This is the challenge of the proofs that predate [`Transcript`]. They keep
+/// it since their statements are fixed lists of points hashed in one shot,
+/// which this frames unambiguously: the label and the context are length
+/// prefixed, and the points have a fixed-size encoding. Newer proofs derive
+/// their challenges from a [`Transcript`] instead.
+fn challenge(
+    label: &[u8],
+    context: &[u8],
+    points: &[&JubJubExtended],
+) -> JubJubScalar {
+    let mut hasher = Sha512::new();
+    hasher.update((label.len() as u64).to_le_bytes());
+    hasher.update(label);
+    hasher.update((context.len() as u64).to_le_bytes());
+    hasher.update(context);
+
+    let mut points_hasher = PointHasher::new(label);
+    points.iter().for_each(|p| points_hasher.update(p));
+    hasher.update(points_hasher.finalize());
+
+    let mut bytes = [0u8; 64];
+    bytes.copy_from_slice(&hasher.finalize());
+
+    JubJubScalar::from_bytes_wide(&bytes)
+}
+
+/// Check that every point of a statement lies in the prime order subgroup.
+///
+/// The proofs of this module only attest relations between the prime order
+/// components of their statement, so a torsion component could otherwise be
+/// smuggled in whenever the challenge happens to annihilate it.
+fn torsion_free(points: &[&JubJubExtended]) -> bool {
+    points.iter().all(|p| p.is_torsion_free().into())
+}
+
+/// Derive a deterministic nonce from the witness, the statement and a
+/// caller-provided context, distinguished by `tag`.
+fn nonce(
+    label: &[u8],
+    tag: u8,
+    witness: &[&JubJubScalar],
+    statement: &[&JubJubExtended],
+    context: &[u8],
+) -> JubJubScalar {
+    let mut hasher = Sha512::new();
+    hasher.update(label);
+    hasher.update([tag]);
+    witness.iter().for_each(|w| hasher.update(w.to_bytes()));
+    statement
+        .iter()
+        .for_each(|p| hasher.update(JubJubAffine::from(*p).to_bytes()));
+    hasher.update(context);
+
+    let mut bytes = [0u8; 64];
+    bytes.copy_from_slice(&hasher.finalize());
+
+    JubJubScalar::from_bytes_wide(&bytes)
+}
+
+/// Chaum-Pedersen proof of knowledge of `x` such that `X = G · x` and
+/// `Y = H · x`, made non-interactive via Fiat-Shamir.
+#[derive(Debug, Copy, Clone, PartialEq, Default)]
+struct Dleq {
+    challenge: JubJubScalar,
+    response: JubJubScalar,
+}
+
+impl Serializable<64> for Dleq {
+    type Error = BytesError;
+
+    fn to_bytes(&self) -> [u8; Self::SIZE] {
+        let mut bytes = [0u8; Self::SIZE];
+
+        bytes[..32].copy_from_slice(&self.challenge.to_bytes());
+        bytes[32..].copy_from_slice(&self.response.to_bytes());
+
+        bytes
+    }
+
+    fn from_bytes(bytes: &[u8; Self::SIZE]) -> Result<Self, Self::Error> {
+        let challenge = JubJubScalar::from_slice(&bytes[..32])?;
+        let response = JubJubScalar::from_slice(&bytes[32..])?;
+
+        Ok(Self {
+            challenge,
+            response,
+        })
+    }
+}
+
+/// Statement proven by a [`Dleq`], defining how its challenge is derived.
+trait DleqStatement {
+    /// Domain label of the proof.
+    const LABEL: &'static [u8];
+
+    /// Compute the challenge from the points `[G, H, X, Y, K_G, K_H]`, where
+    /// `K_G` and `K_H` are the commitments of the prover.
+    fn challenge(points: &[&JubJubExtended; 6]) -> JubJubScalar {
+        challenge(Self::LABEL, &[], points)
+    }
+}
+
+impl Dleq {
+    fn prove<S, R>(
+        x: &JubJubScalar,
+        g: &JubJubExtended,
+        h: &JubJubExtended,
+        rng: &mut R,
+    ) -> Self
+    where
+        S: DleqStatement,
+        R: RngCore + CryptoRng,
+    {
+        let x_g = g * x;
+        let x_h = h * x;
+
+        let k = JubJubScalar::random(rng);
+        let k_g = g * k;
+        let k_h = h * k;
+
+        let challenge = S::challenge(&[g, h, &x_g, &x_h, &k_g, &k_h]);
+        let response = k + challenge * x;
+
+        Self {
+            challenge,
+            response,
+        }
+    }
+
+    fn verify<S: DleqStatement>(
+        &self,
+        g: &JubJubExtended,
+        h: &JubJubExtended,
+        x_g: &JubJubExtended,
+        x_h: &JubJubExtended,
+    ) -> bool {
+        if !torsion_free(&[g, h, x_g, x_h]) {
+            return false;
+        }
+
+        let k_g = g * self.response - x_g * self.challenge;
+        let k_h = h * self.response - x_h * self.challenge;
+
+        let challenge = S::challenge(&[g, h, x_g, x_h, &k_g, &k_h]);
+
+        challenge == self.challenge
+    }
+}
+
+/// Non-interactive proof that an [`ElgamalCipher`] was honestly formed, i.e.
+/// the same randomness `r` was used for both of its components.
+///
+/// Being `G` the generator, `A` the public key and `M` the message, the proof
+/// attests knowledge of `r` such that `γ = G · r` and `δ - M = A · r`.
+///
+/// This is a Chaum-Pedersen proof of discrete logarithm equality made
+/// non-interactive via Fiat-Shamir.
+#[derive(Debug, Copy, Clone, PartialEq, Default)]
+pub struct EncryptionProof(Dleq);
+
+impl Serializable<64> for EncryptionProof {
+    type Error = BytesError;
+
+    /// Serialize the proof into bytes
+    fn to_bytes(&self) -> [u8; Self::SIZE] {
+        self.0.to_bytes()
+    }
+
+    /// Deserialize from a [`EncryptionProof::to_bytes`] construction
+    fn from_bytes(bytes: &[u8; Self::SIZE]) -> Result<Self, Self::Error> {
+        Dleq::from_bytes(bytes).map(Self)
+    }
+}
+
+impl DleqStatement for EncryptionProof {
+    const LABEL: &'static [u8] = b"dusk-jubjub-elgamal-encryption";
+}
+
+impl EncryptionProof {
+    /// Prove that the cipher produced by [`ElgamalCipher::encrypt`] with the
+    /// randomness `r` is well-formed.
+    pub fn prove<R>(
+        r: &JubJubScalar,
+        public: &JubJubExtended,
+        generator: &JubJubExtended,
+        rng: &mut R,
+    ) -> Self
+    where
+        R: RngCore + CryptoRng,
+    {
+        Self(Dleq::prove::<Self, _>(r, generator, public, rng))
+    }
+
+    /// Verify the proof for `cipher`, provided the message point `message`
+    /// it is claimed to encrypt.
+    pub fn verify(
+        &self,
+        cipher: &ElgamalCipher,
+        public: &JubJubExtended,
+        generator: &JubJubExtended,
+        message: &JubJubExtended,
+    ) -> bool {
+        let shared = cipher.delta() - message;
+
+        self.0
+            .verify::<Self>(generator, public, cipher.gamma(), &shared)
+    }
+}
+
+/// Non-interactive proof that two [`ElgamalCipher`] under the same public key
+/// encrypt the same message.
+///
+/// Being `G` the generator, `A` the public key, and `c1 = (γ1; δ1)`,
+/// `c2 = (γ2; δ2)` the ciphers, `c1 - c2` is an encryption of the identity if
+/// and only if the messages match. The proof attests knowledge of
+/// `d = r1 - r2` such that `γ1 - γ2 = G · d` and `δ1 - δ2 = A · d`.
+#[derive(Debug, Copy, Clone, PartialEq, Default)]
+pub struct PlaintextEqProof(Dleq);
+
+impl Serializable<64> for PlaintextEqProof {
+    type Error = BytesError;
+
+    /// Serialize the proof into bytes
+    fn to_bytes(&self) -> [u8; Self::SIZE] {
+        self.0.to_bytes()
+    }
+
+    /// Deserialize from a [`PlaintextEqProof::to_bytes`] construction
+    fn from_bytes(bytes: &[u8; Self::SIZE]) -> Result<Self, Self::Error> {
+        Dleq::from_bytes(bytes).map(Self)
+    }
+}
+
+impl DleqStatement for PlaintextEqProof {
+    const LABEL: &'static [u8] = b"dusk-jubjub-elgamal-plaintext-eq";
+}
+
+impl PlaintextEqProof {
+    /// Prove that `c1` and `c2` encrypt the same message, provided the
+    /// difference `r1 - r2` of the randomness used to produce them.
+    pub fn prove<R>(
+        c1: &ElgamalCipher,
+        c2: &ElgamalCipher,
+        shared_r_diff: &JubJubScalar,
+        public: &JubJubExtended,
+        generator: &JubJubExtended,
+        rng: &mut R,
+    ) -> Self
+    where
+        R: RngCore + CryptoRng,
+    {
+        debug_assert_eq!((c1 - c2).gamma(), &(generator * shared_r_diff));
+
+        Self(Dleq::prove::<Self, _>(
+            shared_r_diff,
+            generator,
+            public,
+            rng,
+        ))
+    }
+
+    /// Verify that `c1` and `c2` encrypt the same message.
+    pub fn verify(
+        &self,
+        c1: &ElgamalCipher,
+        c2: &ElgamalCipher,
+        public: &JubJubExtended,
+        generator: &JubJubExtended,
+    ) -> bool {
+        let diff = c1 - c2;
+
+        self.0
+            .verify::<Self>(generator, public, diff.gamma(), diff.delta())
+    }
+}
+
+/// Non-interactive proof that an [`ElgamalCipher`] decrypts to a claimed
+/// message, produced by the owner of the secret key.
+///
+/// Being `G` the generator, `A` the public key and `M` the claimed message,
+/// the proof attests knowledge of `a` such that `A = G · a` and
+/// `δ - M = γ · a`, without revealing the secret.
+///
+/// This is a Chaum-Pedersen proof of discrete logarithm equality made
+/// non-interactive via Fiat-Shamir, with the challenge derived from a
+/// [`Transcript`].
+#[derive(Debug, Copy, Clone, PartialEq, Default)]
+pub struct DecryptionProof(Dleq);
+
+impl Serializable<64> for DecryptionProof {
+    type Error = BytesError;
+
+    /// Serialize the proof into bytes
+    fn to_bytes(&self) -> [u8; Self::SIZE] {
+        self.0.to_bytes()
+    }
+
+    /// Deserialize from a [`DecryptionProof::to_bytes`] construction
+    fn from_bytes(bytes: &[u8; Self::SIZE]) -> Result<Self, Self::Error> {
+        Dleq::from_bytes(bytes).map(Self)
+    }
+}
+
+impl DleqStatement for DecryptionProof {
+    const LABEL: &'static [u8] = b"dusk-jubjub-elgamal-decryption";
+
+    fn challenge(points: &[&JubJubExtended; 6]) -> JubJubScalar {
+        const LABELS: [&[u8]; 6] =
+            [b"generator", b"gamma", b"public", b"shared", b"k_g", b"k_h"];
+
+        let mut transcript = Transcript::new(Self::LABEL);
+        LABELS
+            .iter()
+            .zip(points.iter())
+            .for_each(|(label, p)| transcript.append_point(label, p));
+
+        transcript.challenge_scalar(b"challenge")
+    }
+}
+
+impl DecryptionProof {
+    /// Prove that `cipher` decrypts to [`ElgamalCipher::decrypt`] with
+    /// `secret`.
+    pub fn prove<R>(
+        cipher: &ElgamalCipher,
+        secret: &JubJubScalar,
+        generator: &JubJubExtended,
+        rng: &mut R,
+    ) -> Self
+    where
+        R: RngCore + CryptoRng,
+    {
+        Self(Dleq::prove::<Self, _>(
+            secret,
+            generator,
+            cipher.gamma(),
+            rng,
+        ))
+    }
+
+    /// Verify that `cipher`, encrypted to `public`, decrypts to `message`.
+    pub fn verify(
+        &self,
+        cipher: &ElgamalCipher,
+        public: &JubJubExtended,
+        generator: &JubJubExtended,
+        message: &JubJubExtended,
+    ) -> bool {
+        // A torsion component shared by `δ` and the claimed message would
+        // cancel out in `δ - M`, so both are checked on their own
+        if !torsion_free(&[cipher.delta(), message]) {
+            return false;
+        }
+
+        let shared = cipher.delta() - message;
+
+        self.0
+            .verify::<Self>(generator, cipher.gamma(), public, &shared)
+    }
+}
+
+/// Verify that `cipher`, encrypted to `public`, decrypts to the `claimed`
+/// message, as attested by a [`DecryptionProof`] of the owner of the secret.
+///
+/// This is [`DecryptionProof::verify`], for verifiers that receive the
+/// cipher, the claimed plaintext and the proof together.
+pub fn verify_decryption(
+    cipher: &ElgamalCipher,
+    public: &JubJubExtended,
+    generator: &JubJubExtended,
+    claimed: &JubJubExtended,
+    proof: &DecryptionProof,
+) -> bool {
+    proof.verify(cipher, public, generator, claimed)
+}
+
+/// Verify that `cipher`, encrypted to `public`, decrypts to the message
+/// `generator · claimed_scalar`, as recovered by
+/// [`ElgamalCipher::decrypt_scalar`].
+pub fn verify_decryption_scalar(
+    cipher: &ElgamalCipher,
+    public: &JubJubExtended,
+    generator: &JubJubExtended,
+    claimed_scalar: u64,
+    proof: &DecryptionProof,
+) -> bool {
+    let claimed = generator * JubJubScalar::from(claimed_scalar);
+
+    verify_decryption(cipher, public, generator, &claimed, proof)
+}
+
+/// Non-interactive proof of knowledge of the scalar message `m` and the
+/// randomness `r` of an [`ElgamalCipher`].
+///
+/// Being `G` the generator and `A` the public key, the proof attests knowledge
+/// of `m` and `r` such that `γ = G · r` and `δ = G · m + A · r`. It is a
+/// Sigma protocol made non-interactive via Fiat-Shamir, bound to a
+/// caller-provided transcript.
+///
+/// The nonces are derived deterministically from the witness, the statement
+/// and the transcript, so no randomness source is required.
+#[derive(Debug, Copy, Clone, PartialEq, Default)]
+pub struct PlaintextProof {
+    challenge: JubJubScalar,
+    response_m: JubJubScalar,
+    response_r: JubJubScalar,
+}
+
+impl Serializable<96> for PlaintextProof {
+    type Error = BytesError;
+
+    /// Serialize the proof into bytes
+    fn to_bytes(&self) -> [u8; Self::SIZE] {
+        let mut bytes = [0u8; Self::SIZE];
+
+        bytes[..32].copy_from_slice(&self.challenge.to_bytes());
+        bytes[32..64].copy_from_slice(&self.response_m.to_bytes());
+        bytes[64..].copy_from_slice(&self.response_r.to_bytes());
+
+        bytes
+    }
+
+    /// Deserialize from a [`PlaintextProof::to_bytes`] construction
+    fn from_bytes(bytes: &[u8; Self::SIZE]) -> Result<Self, Self::Error> {
+        let challenge = JubJubScalar::from_slice(&bytes[..32])?;
+        let response_m = JubJubScalar::from_slice(&bytes[32..64])?;
+        let response_r = JubJubScalar::from_slice(&bytes[64..])?;
+
+        Ok(Self {
+            challenge,
+            response_m,
+            response_r,
+        })
+    }
+}
+
+impl PlaintextProof {
+    const LABEL: &'static [u8] = b"dusk-jubjub-elgamal-plaintext";
+
+    /// Prove knowledge of `message` and `r` for the cipher produced by
+    /// [`ElgamalCipher::encrypt`] with the message point `generator · message`.
+    pub fn prove(
+        message: &JubJubScalar,
+        r: &JubJubScalar,
+        public: &JubJubExtended,
+        generator: &JubJubExtended,
+        transcript: &[u8],
+    ) -> Self {
+        let cipher = ElgamalCipher::encrypt(
+            r,
+            public,
+            generator,
+            &(generator * message),
+        );
+        let statement = [generator, public, cipher.gamma(), cipher.delta()];
+
+        let witness = [message, r];
+        let k_m = nonce(Self::LABEL, 0, &witness, &statement, transcript);
+        let k_r = nonce(Self::LABEL, 1, &witness, &statement, transcript);
+
+        let t_gamma = generator * k_r;
+        let t_delta = generator * k_m + public * k_r;
+
+        let challenge = challenge(
+            Self::LABEL,
+            transcript,
+            &[
+                generator,
+                public,
+                cipher.gamma(),
+                cipher.delta(),
+                &t_gamma,
+                &t_delta,
+            ],
+        );
+
+        Self {
+            challenge,
+            response_m: k_m + challenge * message,
+            response_r: k_r + challenge * r,
+        }
+    }
+
+    /// Verify the proof for `cipher` against the same `transcript` used to
+    /// produce it.
+    pub fn verify(
+        &self,
+        cipher: &ElgamalCipher,
+        public: &JubJubExtended,
+        generator: &JubJubExtended,
+        transcript: &[u8],
+    ) -> bool {
+        if !torsion_free(&[generator, public, cipher.gamma(), cipher.delta()]) {
+            return false;
+        }
+
+        let t_gamma =
+            generator * self.response_r - cipher.gamma() * self.challenge;
+        let t_delta = generator * self.response_m + public * self.response_r
+            - cipher.delta() * self.challenge;
+
+        let challenge = challenge(
+            Self::LABEL,
+            transcript,
+            &[
+                generator,
+                public,
+                cipher.gamma(),
+                cipher.delta(),
+                &t_gamma,
+                &t_delta,
+            ],
+        );
+
+        challenge == self.challenge
+    }
+}
+
+/// Non-interactive proof that two [`ElgamalCipher`], possibly under different
+/// public keys, encrypt the same message.
+///
+/// Being `G` the generator, `c1 = (γ1; δ1)` a cipher under `A1` and
+/// `c2 = (γ2; δ2)` a cipher under `A2`, the proof attests knowledge of `r1`
+/// and `r2` such that:
+///
+/// - `γ1 = G · r1`
+/// - `γ2 = G · r2`
+/// - `δ1 - δ2 = A1 · r1 - A2 · r2`
+///
+/// which holds if and only if `δ1 - A1 · r1 = δ2 - A2 · r2`, i.e. both ciphers
+/// decrypt to the same message. It is a Sigma protocol made non-interactive
+/// via Fiat-Shamir, with nonces derived deterministically from the witness
+/// and the statement.
+#[derive(Debug, Copy, Clone, PartialEq, Default)]
+pub struct EqualityProof {
+    challenge: JubJubScalar,
+    response_r1: JubJubScalar,
+    response_r2: JubJubScalar,
+}
+
+impl Serializable<96> for EqualityProof {
+    type Error = BytesError;
+
+    /// Serialize the proof into bytes
+    fn to_bytes(&self) -> [u8; Self::SIZE] {
+        let mut bytes = [0u8; Self::SIZE];
+
+        bytes[..32].copy_from_slice(&self.challenge.to_bytes());
+        bytes[32..64].copy_from_slice(&self.response_r1.to_bytes());
+        bytes[64..].copy_from_slice(&self.response_r2.to_bytes());
+
+        bytes
+    }
+
+    /// Deserialize from a [`EqualityProof::to_bytes`] construction
+    fn from_bytes(bytes: &[u8; Self::SIZE]) -> Result<Self, Self::Error> {
+        let challenge = JubJubScalar::from_slice(&bytes[..32])?;
+        let response_r1 = JubJubScalar::from_slice(&bytes[32..64])?;
+        let response_r2 = JubJubScalar::from_slice(&bytes[64..])?;
+
+        Ok(Self {
+            challenge,
+            response_r1,
+            response_r2,
+        })
+    }
+}
+
+impl EqualityProof {
+    const LABEL: &'static [u8] = b"dusk-jubjub-elgamal-plaintext-equality";
+
+    /// Prove that `c1`, encrypted to `public1` with the randomness `r1`, and
+    /// `c2`, encrypted to `public2` with the randomness `r2`, encrypt the same
+    /// message.
+    pub fn prove(
+        c1: &ElgamalCipher,
+        r1: &JubJubScalar,
+        public1: &JubJubExtended,
+        c2: &ElgamalCipher,
+        r2: &JubJubScalar,
+        public2: &JubJubExtended,
+        generator: &JubJubExtended,
+    ) -> Self {
+        let delta = c1.delta() - c2.delta();
+        let statement =
+            [generator, public1, public2, c1.gamma(), c2.gamma(), &delta];
+
+        let witness = [r1, r2];
+        let k1 = nonce(Self::LABEL, 0, &witness, &statement, &[]);
+        let k2 = nonce(Self::LABEL, 1, &witness, &statement, &[]);
+
+        let t1 = generator * k1;
+        let t2 = generator * k2;
+        let t3 = public1 * k1 - public2 * k2;
+
+        let challenge = challenge(
+            Self::LABEL,
+            &[],
+            &[
+                generator,
+                public1,
+                public2,
+                c1.gamma(),
+                c2.gamma(),
+                &delta,
+                &t1,
+                &t2,
+                &t3,
+            ],
+        );
+
+        Self {
+            challenge,
+            response_r1: k1 + challenge * r1,
+            response_r2: k2 + challenge * r2,
+        }
+    }
+
+    /// Verify that `c1`, encrypted to `public1`, and `c2`, encrypted to
+    /// `public2`, encrypt the same message.
+    pub fn verify(
+        &self,
+        c1: &ElgamalCipher,
+        public1: &JubJubExtended,
+        c2: &ElgamalCipher,
+        public2: &JubJubExtended,
+        generator: &JubJubExtended,
+    ) -> bool {
+        // A torsion component shared by `δ1` and `δ2` would cancel out in
+        // their difference, so both are checked on their own
+        if !torsion_free(&[
+            generator,
+            public1,
+            public2,
+            c1.gamma(),
+            c1.delta(),
+            c2.gamma(),
+            c2.delta(),
+        ]) {
+            return false;
+        }
+
+        let delta = c1.delta() - c2.delta();
+
+        let t1 = generator * self.response_r1 - c1.gamma() * self.challenge;
+        let t2 = generator * self.response_r2 - c2.gamma() * self.challenge;
+        let t3 = public1 * self.response_r1
+            - public2 * self.response_r2
+            - delta * self.challenge;
+
+        let challenge = challenge(
+            Self::LABEL,
+            &[],
+            &[
+                generator,
+                public1,
+                public2,
+                c1.gamma(),
+                c2.gamma(),
+                &delta,
+                &t1,
+                &t2,
+                &t3,
+            ],
+        );
+
+        challenge == self.challenge
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg(test)]
+mod tests {
+    use super::{
+        challenge, verify_decryption, verify_decryption_scalar,
+        DecryptionProof, Dleq, DleqStatement, EncryptionProof, EqualityProof,
+        PlaintextEqProof, PlaintextProof,
+    };
+    use crate::elgamal::ElgamalCipher;
+    use crate::{JubJubExtended, JubJubScalar, GENERATOR_EXTENDED};
+    use dusk_bytes::Serializable;
+    use rand_core::OsRng;
+
+    fn gen() -> (JubJubScalar, JubJubExtended, JubJubScalar, JubJubExtended) {
+        let secret = JubJubScalar::random(&mut OsRng);
+        let public = GENERATOR_EXTENDED * secret;
+
+        let r = JubJubScalar::random(&mut OsRng);
+        let m = JubJubScalar::random(&mut OsRng);
+        let m = GENERATOR_EXTENDED * m;
+
+        (secret, public, r, m)
+    }
+
+    /// Forge a [`Dleq`] for `x_g = g · x + t_g` and `x_h = h · x + t_h`,
+    /// where `t_g` and `t_h` are small order points, by grinding nonces
+    /// until the challenge annihilates the torsion components.
+    fn forge_dleq<S: DleqStatement>(
+        x: &JubJubScalar,
+        g: &JubJubExtended,
+        h: &JubJubExtended,
+        t_g: &JubJubExtended,
+        t_h: &JubJubExtended,
+    ) -> Dleq {
+        let x_g = g * x + t_g;
+        let x_h = h * x + t_h;
+
+        loop {
+            let k = JubJubScalar::random(&mut OsRng);
+
+            for j in 0..8u64 {
+                let j = JubJubScalar::from(j);
+                let k_g = g * k + t_g * j;
+                let k_h = h * k + t_h * j;
+
+                let c = S::challenge(&[g, h, &x_g, &x_h, &k_g, &k_h]);
+                let e = j + c;
+
+                if bool::from((t_g * e).is_identity())
+                    && bool::from((t_h * e).is_identity())
+                {
+                    let forged = Dleq {
+                        challenge: c,
+                        response: k + c * x,
+                    };
+
+                    // The verification equations hold, only the subgroup
+                    // check can reject the forgery
+                    let k_g = g * forged.response - x_g * c;
+                    let k_h = h * forged.response - x_h * c;
+                    assert_eq!(
+                        c,
+                        S::challenge(&[g, h, &x_g, &x_h, &k_g, &k_h])
+                    );
+
+                    return forged;
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn encryption_proof() {
+        let (_, public, r, m) = gen();
+
+        let cipher =
+            ElgamalCipher::encrypt(&r, &public, &GENERATOR_EXTENDED, &m);
+        let proof = EncryptionProof::prove(
+            &r,
+            &public,
+            &GENERATOR_EXTENDED,
+            &mut OsRng,
+        );
+
+        assert!(proof.verify(&cipher, &public, &GENERATOR_EXTENDED, &m));
+
+        let proof = EncryptionProof::from_bytes(&proof.to_bytes()).unwrap();
+        assert!(proof.verify(&cipher, &public, &GENERATOR_EXTENDED, &m));
+
+        let wrong = m + GENERATOR_EXTENDED;
+        assert!(!proof.verify(&cipher, &public, &GENERATOR_EXTENDED, &wrong));
+    }
+
+    #[test]
+    fn encryption_proof_mismatched_r() {
+        let (_, public, r, m) = gen();
+        let r_prime = r + JubJubScalar::one();
+
+        let cipher =
+            ElgamalCipher::new(GENERATOR_EXTENDED * r, m + public * r_prime);
+
+        let proof = EncryptionProof::prove(
+            &r,
+            &public,
+            &GENERATOR_EXTENDED,
+            &mut OsRng,
+        );
+        assert!(!proof.verify(&cipher, &public, &GENERATOR_EXTENDED, &m));
+
+        let proof = EncryptionProof::prove(
+            &r_prime,
+            &public,
+            &GENERATOR_EXTENDED,
+            &mut OsRng,
+        );
+        assert!(!proof.verify(&cipher, &public, &GENERATOR_EXTENDED, &m));
+    }
+
+    #[test]
+    fn encryption_proof_torsioned_gamma() {
+        let (_, public, r, m) = gen();
+        let torsion = JubJubExtended::torsion_subgroup()[0];
+
+        let cipher = ElgamalCipher::new(
+            GENERATOR_EXTENDED * r + torsion,
+            m + public * r,
+        );
+
+        let proof = EncryptionProof(forge_dleq::<EncryptionProof>(
+            &r,
+            &GENERATOR_EXTENDED,
+            &public,
+            &torsion,
+            &JubJubExtended::identity(),
+        ));
+        assert!(!proof.verify(&cipher, &public, &GENERATOR_EXTENDED, &m));
+    }
+
+    #[test]
+    fn plaintext_eq_proof_torsioned_diff() {
+        let (_, public, r1, m) = gen();
+        let r2 = JubJubScalar::random(&mut OsRng);
+        let torsion = JubJubExtended::torsion_subgroup()[0];
+
+        let c1 = ElgamalCipher::encrypt(&r1, &public, &GENERATOR_EXTENDED, &m);
+        let c2 = ElgamalCipher::encrypt(&r2, &public, &GENERATOR_EXTENDED, &m);
+        let c1 = ElgamalCipher::new(c1.gamma() + torsion, *c1.delta());
+
+        let proof = PlaintextEqProof(forge_dleq::<PlaintextEqProof>(
+            &(r1 - r2),
+            &GENERATOR_EXTENDED,
+            &public,
+            &torsion,
+            &JubJubExtended::identity(),
+        ));
+        assert!(!proof.verify(&c1, &c2, &public, &GENERATOR_EXTENDED));
+    }
+
+    #[test]
+    fn plaintext_eq_proof() {
+        let (_, public, r1, m) = gen();
+        let r2 = JubJubScalar::random(&mut OsRng);
+
+        let c1 = ElgamalCipher::encrypt(&r1, &public, &GENERATOR_EXTENDED, &m);
+        let c2 = ElgamalCipher::encrypt(&r2, &public, &GENERATOR_EXTENDED, &m);
+
+        let proof = PlaintextEqProof::prove(
+            &c1,
+            &c2,
+            &(r1 - r2),
+            &public,
+            &GENERATOR_EXTENDED,
+            &mut OsRng,
+        );
+        assert!(proof.verify(&c1, &c2, &public, &GENERATOR_EXTENDED));
+
+        let proof = PlaintextEqProof::from_bytes(&proof.to_bytes()).unwrap();
+        assert!(proof.verify(&c1, &c2, &public, &GENERATOR_EXTENDED));
+        assert!(!proof.verify(&c2, &c1, &public, &GENERATOR_EXTENDED));
+    }
+
+    #[test]
+    fn plaintext_eq_proof_different_messages() {
+        let (_, public, r1, m) = gen();
+        let r2 = JubJubScalar::random(&mut OsRng);
+        let n = m + GENERATOR_EXTENDED;
+
+        let c1 = ElgamalCipher::encrypt(&r1, &public, &GENERATOR_EXTENDED, &m);
+        let c2 = ElgamalCipher::encrypt(&r2, &public, &GENERATOR_EXTENDED, &n);
+
+        let proof = PlaintextEqProof::prove(
+            &c1,
+            &c2,
+            &(r1 - r2),
+            &public,
+            &GENERATOR_EXTENDED,
+            &mut OsRng,
+        );
+        assert!(!proof.verify(&c1, &c2, &public, &GENERATOR_EXTENDED));
+    }
+
+    #[test]
+    fn decryption_proof() {
+        let (secret, public, r, m) = gen();
+
+        let cipher =
+            ElgamalCipher::encrypt(&r, &public, &GENERATOR_EXTENDED, &m);
+        let proof = DecryptionProof::prove(
+            &cipher,
+            &secret,
+            &GENERATOR_EXTENDED,
+            &mut OsRng,
+        );
+        assert!(verify_decryption(
+            &cipher,
+            &public,
+            &GENERATOR_EXTENDED,
+            &m,
+            &proof
+        ));
+
+        let proof = DecryptionProof::from_bytes(&proof.to_bytes()).unwrap();
+        assert!(proof.verify(&cipher, &public, &GENERATOR_EXTENDED, &m));
+
+        let wrong = m + GENERATOR_EXTENDED;
+        assert!(!verify_decryption(
+            &cipher,
+            &public,
+            &GENERATOR_EXTENDED,
+            &wrong,
+            &proof
+        ));
+
+        // A proof made with another secret doesn't verify under `public`
+        let other = JubJubScalar::random(&mut OsRng);
+        let forged = DecryptionProof::prove(
+            &cipher,
+            &other,
+            &GENERATOR_EXTENDED,
+            &mut OsRng,
+        );
+        let message = cipher.decrypt(&other);
+        assert!(!verify_decryption(
+            &cipher,
+            &public,
+            &GENERATOR_EXTENDED,
+            &message,
+            &forged
+        ));
+    }
+
+    #[test]
+    fn decryption_proof_scalar() {
+        let (secret, public, r, _) = gen();
+        let m = GENERATOR_EXTENDED * JubJubScalar::from(42u64);
+
+        let cipher =
+            ElgamalCipher::encrypt(&r, &public, &GENERATOR_EXTENDED, &m);
+        let proof = DecryptionProof::prove(
+            &cipher,
+            &secret,
+            &GENERATOR_EXTENDED,
+            &mut OsRng,
+        );
+
+        for claimed in [0, 41, 42, 43, u64::MAX].iter() {
+            assert_eq!(
+                verify_decryption_scalar(
+                    &cipher,
+                    &public,
+                    &GENERATOR_EXTENDED,
+                    *claimed,
+                    &proof
+                ),
+                *claimed == 42
+            );
+        }
+    }
+
+    #[test]
+    fn decryption_proof_torsioned_claim() {
+        let (secret, public, r, m) = gen();
+        let torsion = JubJubExtended::torsion_subgroup()[0];
+
+        let cipher =
+            ElgamalCipher::encrypt(&r, &public, &GENERATOR_EXTENDED, &m);
+        let claimed = m + torsion;
+
+        let proof = DecryptionProof(forge_dleq::<DecryptionProof>(
+            &secret,
+            &GENERATOR_EXTENDED,
+            cipher.gamma(),
+            &JubJubExtended::identity(),
+            &-torsion,
+        ));
+        assert!(!verify_decryption(
+            &cipher,
+            &public,
+            &GENERATOR_EXTENDED,
+            &claimed,
+            &proof
+        ));
+
+        // The same torsion on both `δ` and the claim cancels out in `δ - M`
+        let cipher =
+            ElgamalCipher::new(*cipher.gamma(), cipher.delta() + torsion);
+        let proof = DecryptionProof::prove(
+            &cipher,
+            &secret,
+            &GENERATOR_EXTENDED,
+            &mut OsRng,
+        );
+        assert!(!verify_decryption(
+            &cipher,
+            &public,
+            &GENERATOR_EXTENDED,
+            &claimed,
+            &proof
+        ));
+    }
+
+    #[test]
+    fn plaintext_proof() {
+        let (_, public, r, _) = gen();
+        let m = JubJubScalar::random(&mut OsRng);
+        let transcript = b"plaintext-proof-test";
+
+        let cipher = ElgamalCipher::encrypt(
+            &r,
+            &public,
+            &GENERATOR_EXTENDED,
+            &(GENERATOR_EXTENDED * m),
+        );
+        let proof = ElgamalCipher::prove_plaintext_knowledge(
+            &m,
+            &r,
+            &public,
+            &GENERATOR_EXTENDED,
+            transcript,
+        );
+
+        assert!(cipher.verify_plaintext_knowledge(
+            &proof,
+            &public,
+            &GENERATOR_EXTENDED,
+            transcript
+        ));
+
+        let proof = PlaintextProof::from_bytes(&proof.to_bytes()).unwrap();
+        assert!(cipher.verify_plaintext_knowledge(
+            &proof,
+            &public,
+            &GENERATOR_EXTENDED,
+            transcript
+        ));
+
+        assert!(!cipher.verify_plaintext_knowledge(
+            &proof,
+            &public,
+            &GENERATOR_EXTENDED,
+            b"another-transcript"
+        ));
+    }
+
+    #[test]
+    fn plaintext_proof_tampered() {
+        let (_, public, r, _) = gen();
+        let m = JubJubScalar::random(&mut OsRng);
+        let transcript = b"plaintext-proof-test";
+
+        let cipher = ElgamalCipher::encrypt(
+            &r,
+            &public,
+            &GENERATOR_EXTENDED,
+            &(GENERATOR_EXTENDED * m),
+        );
+        let proof = ElgamalCipher::prove_plaintext_knowledge(
+            &m,
+            &r,
+            &public,
+            &GENERATOR_EXTENDED,
+            transcript,
+        );
+
+        let tampered = ElgamalCipher::new(
+            *cipher.gamma(),
+            cipher.delta() + GENERATOR_EXTENDED,
+        );
+        assert!(!tampered.verify_plaintext_knowledge(
+            &proof,
+            &public,
+            &GENERATOR_EXTENDED,
+            transcript
+        ));
+
+        let tampered = ElgamalCipher::new(
+            cipher.gamma() + GENERATOR_EXTENDED,
+            *cipher.delta(),
+        );
+        assert!(!tampered.verify_plaintext_knowledge(
+            &proof,
+            &public,
+            &GENERATOR_EXTENDED,
+            transcript
+        ));
+
+        let mut bytes = proof.to_bytes();
+        bytes[40] ^= 1;
+        let tampered = PlaintextProof::from_bytes(&bytes).unwrap();
+        assert!(!cipher.verify_plaintext_knowledge(
+            &tampered,
+            &public,
+            &GENERATOR_EXTENDED,
+            transcript
+        ));
+    }
+
+    #[test]
+    fn plaintext_proof_torsioned_gamma() {
+        let (_, public, r, _) = gen();
+        let m = JubJubScalar::random(&mut OsRng);
+        let transcript = b"plaintext-proof-test";
+        let torsion = JubJubExtended::torsion_subgroup()[0];
+
+        let cipher = ElgamalCipher::new(
+            GENERATOR_EXTENDED * r + torsion,
+            GENERATOR_EXTENDED * m + public * r,
+        );
+
+        // Grind the nonces until the challenge annihilates the torsion of `γ`
+        let proof = 'forge: loop {
+            let k_m = JubJubScalar::random(&mut OsRng);
+            let k_r = JubJubScalar::random(&mut OsRng);
+
+            for j in 0..8u64 {
+                let j = JubJubScalar::from(j);
+                let t_gamma = GENERATOR_EXTENDED * k_r + torsion * j;
+                let t_delta = GENERATOR_EXTENDED * k_m + public * k_r;
+
+                let c = challenge(
+                    PlaintextProof::LABEL,
+                    transcript,
+                    &[
+                        &GENERATOR_EXTENDED,
+                        &public,
+                        cipher.gamma(),
+                        cipher.delta(),
+                        &t_gamma,
+                        &t_delta,
+                    ],
+                );
+
+                if bool::from((torsion * (j + c)).is_identity()) {
+                    break 'forge PlaintextProof {
+                        challenge: c,
+                        response_m: k_m + c * m,
+                        response_r: k_r + c * r,
+                    };
+                }
+            }
+        };
+
+        // The verification equations hold, only the subgroup check can
+        // reject the forgery
+        let t_gamma = GENERATOR_EXTENDED * proof.response_r
+            - cipher.gamma() * proof.challenge;
+        let t_delta = GENERATOR_EXTENDED * proof.response_m
+            + public * proof.response_r
+            - cipher.delta() * proof.challenge;
+        assert_eq!(
+            proof.challenge,
+            challenge(
+                PlaintextProof::LABEL,
+                transcript,
+                &[
+                    &GENERATOR_EXTENDED,
+                    &public,
+                    cipher.gamma(),
+                    cipher.delta(),
+                    &t_gamma,
+                    &t_delta,
+                ],
+            )
+        );
+
+        assert!(!cipher.verify_plaintext_knowledge(
+            &proof,
+            &public,
+            &GENERATOR_EXTENDED,
+            transcript
+        ));
+    }
+
+    #[test]
+    fn equality_proof() {
+        let (_, public1, r1, m) = gen();
+        let (_, public2, r2, _) = gen();
+
+        let c1 = ElgamalCipher::encrypt(&r1, &public1, &GENERATOR_EXTENDED, &m);
+        let c2 = ElgamalCipher::encrypt(&r2, &public2, &GENERATOR_EXTENDED, &m);
+
+        let proof = ElgamalCipher::prove_plaintext_equality(
+            &c1,
+            &r1,
+            &public1,
+            &c2,
+            &r2,
+            &public2,
+            &GENERATOR_EXTENDED,
+        );
+        assert!(proof.verify(
+            &c1,
+            &public1,
+            &c2,
+            &public2,
+            &GENERATOR_EXTENDED
+        ));
+
+        let proof = EqualityProof::from_bytes(&proof.to_bytes()).unwrap();
+        assert!(proof.verify(
+            &c1,
+            &public1,
+            &c2,
+            &public2,
+            &GENERATOR_EXTENDED
+        ));
+        assert!(!proof.verify(
+            &c2,
+            &public2,
+            &c1,
+            &public1,
+            &GENERATOR_EXTENDED
+        ));
+
+        let mut bytes = proof.to_bytes();
+        bytes[70] ^= 1;
+        let proof = EqualityProof::from_bytes(&bytes).unwrap();
+        assert!(!proof.verify(
+            &c1,
+            &public1,
+            &c2,
+            &public2,
+            &GENERATOR_EXTENDED
+        ));
+    }
+
+    #[test]
+    fn equality_proof_different_messages() {
+        let (_, public1, r1, m) = gen();
+        let (_, public2, r2, _) = gen();
+        let n = m + GENERATOR_EXTENDED;
+
+        let c1 = ElgamalCipher::encrypt(&r1, &public1, &GENERATOR_EXTENDED, &m);
+        let c2 = ElgamalCipher::encrypt(&r2, &public2, &GENERATOR_EXTENDED, &n);
+
+        let proof = ElgamalCipher::prove_plaintext_equality(
+            &c1,
+            &r1,
+            &public1,
+            &c2,
+            &r2,
+            &public2,
+            &GENERATOR_EXTENDED,
+        );
+        assert!(!proof.verify(
+            &c1,
+            &public1,
+            &c2,
+            &public2,
+            &GENERATOR_EXTENDED
+        ));
+    }
+
+    #[test]
+    fn equality_proof_torsioned_deltas() {
+        let (_, public1, r1, m) = gen();
+        let (_, public2, r2, _) = gen();
+        let torsion = JubJubExtended::torsion_subgroup()[0];
+
+        let c1 = ElgamalCipher::encrypt(&r1, &public1, &GENERATOR_EXTENDED, &m);
+        let c2 = ElgamalCipher::encrypt(&r2, &public2, &GENERATOR_EXTENDED, &m);
+
+        let proof = ElgamalCipher::prove_plaintext_equality(
+            &c1,
+            &r1,
+            &public1,
+            &c2,
+            &r2,
+            &public2,
+            &GENERATOR_EXTENDED,
+        );
+
+        // The torsion cancels out in `δ1 - δ2`, so the verification
+        // equations still hold and only the subgroup check can reject it
+        let c1 = ElgamalCipher::new(*c1.gamma(), c1.delta() + torsion);
+        let c2 = ElgamalCipher::new(*c2.gamma(), c2.delta() + torsion);
+        assert!(!proof.verify(
+            &c1,
+            &public1,
+            &c2,
+            &public2,
+            &GENERATOR_EXTENDED
+        ));
+    }
+}
@@ -0,0 +1,218 @@
+use super::ElgamalCipher;
+use crate::{JubJubExtended, JubJubScalar};
+
+/// Running homomorphic sum of [`ElgamalCipher`]s, such as the tally of a
+/// vote.
+///
+/// The tally starts as [`ElgamalCipher::identity`], an encryption of zero,
+/// and decrypts to the sum of the messages of the ciphers added to it, minus
+/// the ones removed from it.
+#[derive(Debug, Copy, Clone, PartialEq, Default)]
+pub struct ElgamalAccumulator {
+    tally: ElgamalCipher,
+}
+
+impl From<ElgamalCipher> for ElgamalAccumulator {
+    fn from(tally: ElgamalCipher) -> Self {
+        Self { tally }
+    }
+}
+
+impl ElgamalAccumulator {
+    /// Create an accumulator with an empty tally.
+    pub const fn new() -> Self {
+        Self {
+            tally: ElgamalCipher::identity(),
+        }
+    }
+
+    /// Current encrypted tally
+    pub fn tally(&self) -> &ElgamalCipher {
+        &self.tally
+    }
+
+    /// Add `cipher` to the tally.
+    pub fn add(&mut self, cipher: &ElgamalCipher) {
+        self.tally += *cipher;
+    }
+
+    /// Retract a `cipher` previously added with [`ElgamalAccumulator::add`],
+    /// restoring the tally to what it would be without it.
+    ///
+    /// The accumulator can't tell whether `cipher` was ever added. Removing
+    /// a cipher that wasn't subtracts its message nonetheless, leaving a
+    /// tally that no longer matches the accepted ciphers.
+    pub fn remove(&mut self, cipher: &ElgamalCipher) {
+        self.tally -= *cipher;
+    }
+}
+
+/// Running homomorphic sum of [`ElgamalCipher`]s that all share the same
+/// `γ`, as when every cipher is encrypted with the same randomness.
+///
+/// The `γ` of the sum is `count · γ`, so only the sum of the `δ` and the
+/// number of ciphers are tracked, and the tally is reconstructed when it is
+/// read. This halves the point additions of an [`ElgamalAccumulator`].
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct SharedGammaTally {
+    shared_gamma: JubJubExtended,
+    delta: JubJubExtended,
+    count: u64,
+}
+
+impl SharedGammaTally {
+    /// Create an empty tally of the ciphers sharing `shared_gamma`.
+    pub const fn new(shared_gamma: JubJubExtended) -> Self {
+        Self {
+            shared_gamma,
+            delta: JubJubExtended::identity(),
+            count: 0,
+        }
+    }
+
+    /// `γ` shared by the ciphers of the tally
+    pub fn shared_gamma(&self) -> &JubJubExtended {
+        &self.shared_gamma
+    }
+
+    /// Sum of the `δ` of the ciphers added so far
+    pub fn delta(&self) -> &JubJubExtended {
+        &self.delta
+    }
+
+    /// Number of ciphers added so far
+    pub fn count(&self) -> u64 {
+        self.count
+    }
+
+    /// Add the `δ` of a cipher sharing the `γ` of the tally.
+    pub fn add_delta(&mut self, delta: &JubJubExtended) {
+        self.delta += delta;
+        self.count += 1;
+    }
+
+    /// Add `cipher` to the tally.
+    ///
+    /// Only its `δ` is used: in debug builds, this panics if its `γ` isn't
+    /// the shared one, which would make the tally decrypt to garbage.
+    pub fn add(&mut self, cipher: &ElgamalCipher) {
+        debug_assert_eq!(cipher.gamma(), &self.shared_gamma);
+
+        self.add_delta(cipher.delta());
+    }
+
+    /// Current encrypted tally, with `γ` reconstructed as `count · γ`
+    pub fn tally(&self) -> ElgamalCipher {
+        let gamma = self.shared_gamma * JubJubScalar::from(self.count);
+
+        ElgamalCipher::new(gamma, self.delta)
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg(test)]
+mod tests {
+    use super::{ElgamalAccumulator, SharedGammaTally};
+    use crate::elgamal::ElgamalCipher;
+    use crate::{JubJubExtended, JubJubScalar, GENERATOR_EXTENDED};
+    use rand_core::OsRng;
+
+    fn vote(value: u64, public: &JubJubExtended) -> ElgamalCipher {
+        let r = JubJubScalar::random(&mut OsRng);
+        let m = GENERATOR_EXTENDED * JubJubScalar::from(value);
+
+        ElgamalCipher::encrypt(&r, public, &GENERATOR_EXTENDED, &m)
+    }
+
+    #[test]
+    fn add_remove() {
+        let secret = JubJubScalar::random(&mut OsRng);
+        let public = GENERATOR_EXTENDED * secret;
+
+        let mut accumulator = ElgamalAccumulator::new();
+        assert_eq!(accumulator, ElgamalAccumulator::default());
+        assert_eq!(accumulator.tally(), &ElgamalCipher::identity());
+
+        let votes = [vote(1, &public), vote(0, &public), vote(1, &public)];
+        for v in votes.iter() {
+            accumulator.add(v);
+        }
+        assert_eq!(
+            accumulator.tally().decrypt_scalar(
+                &secret,
+                &GENERATOR_EXTENDED,
+                10
+            ),
+            Ok(2)
+        );
+
+        let prior = accumulator;
+        let retracted = vote(1, &public);
+        accumulator.add(&retracted);
+        assert_ne!(accumulator, prior);
+        accumulator.remove(&retracted);
+        assert_eq!(accumulator, prior);
+
+        accumulator.remove(&votes[0]);
+        assert_eq!(
+            accumulator.tally().decrypt_scalar(
+                &secret,
+                &GENERATOR_EXTENDED,
+                10
+            ),
+            Ok(1)
+        );
+
+        let from = ElgamalAccumulator::from(votes[2]);
+        assert_eq!(from.tally(), &votes[2]);
+    }
+
+    #[test]
+    fn shared_gamma() {
+        let secret = JubJubScalar::random(&mut OsRng);
+        let public = GENERATOR_EXTENDED * secret;
+
+        let r = JubJubScalar::random(&mut OsRng);
+        let votes: Vec<ElgamalCipher> = [1u64, 0, 1, 1, 0]
+            .iter()
+            .map(|v| {
+                let m = GENERATOR_EXTENDED * JubJubScalar::from(*v);
+                ElgamalCipher::encrypt(&r, &public, &GENERATOR_EXTENDED, &m)
+            })
+            .collect();
+
+        let mut accumulator = ElgamalAccumulator::new();
+        let mut tally = SharedGammaTally::new(GENERATOR_EXTENDED * r);
+        assert_eq!(tally.count(), 0);
+        assert_eq!(tally.tally(), ElgamalCipher::identity());
+
+        for v in votes.iter() {
+            accumulator.add(v);
+            tally.add(v);
+        }
+        assert_eq!(tally.count(), 5);
+        assert_eq!(tally.shared_gamma(), votes[0].gamma());
+        assert_eq!(&tally.tally(), accumulator.tally());
+        assert_eq!(
+            tally
+                .tally()
+                .decrypt_scalar(&secret, &GENERATOR_EXTENDED, 10),
+            Ok(3)
+        );
+
+        let mut deltas = SharedGammaTally::new(*votes[0].gamma());
+        votes.iter().for_each(|v| deltas.add_delta(v.delta()));
+        assert_eq!(deltas, tally);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic]
+    fn shared_gamma_mismatch() {
+        let public = GENERATOR_EXTENDED * JubJubScalar::random(&mut OsRng);
+        let vote = vote(1, &public);
+
+        let mut tally = SharedGammaTally::new(GENERATOR_EXTENDED);
+        tally.add(&vote);
+    }
+}
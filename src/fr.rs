@@ -7,16 +7,21 @@ use canonical_derive::Canon;
 use core::cmp::{Ord, Ordering, PartialOrd};
 use core::convert::TryInto;
 use core::fmt;
+use core::iter::{Product, Sum};
 use core::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
 use core::ops::{Index, IndexMut};
 use dusk_bytes::{Error as BytesError, Serializable};
 use rand_core::{CryptoRng, RngCore};
+use sha2::{Digest, Sha512};
 
 use subtle::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption};
 
 use crate::util::{adc, mac, sbb};
 use crate::BlsScalar;
 
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
 /// Represents an element of the scalar field $\mathbb{F}_r$ of the Jubjub
 /// elliptic curve construction.
 // The internal representation of this type is four 64-bit unsigned
@@ -53,6 +58,29 @@ impl From<i8> for Fr {
     }
 }
 
+impl From<i64> for Fr {
+    fn from(val: i64) -> Fr {
+        let magnitude = Fr::from(val.unsigned_abs());
+        Fr::conditional_select(
+            &magnitude,
+            &-magnitude,
+            Choice::from((val < 0) as u8),
+        )
+    }
+}
+
+impl From<i128> for Fr {
+    fn from(val: i128) -> Fr {
+        let abs = val.unsigned_abs();
+        let magnitude = Fr([abs as u64, (abs >> 64) as u64, 0, 0]) * R2;
+        Fr::conditional_select(
+            &magnitude,
+            &-magnitude,
+            Choice::from((val < 0) as u8),
+        )
+    }
+}
+
 impl From<Fr> for BlsScalar {
     fn from(scalar: Fr) -> BlsScalar {
         let bls_scalar = BlsScalar::from_bytes(&scalar.to_bytes());
@@ -213,12 +241,66 @@ const R3: Fr = Fr([
     0x05874f84946737ec,
 ]);
 
+impl Fr {
+    /// Number of bits of the modulus `r`.
+    pub const NUM_BITS: u32 = 252;
+
+    /// Number of bits that can be stored in a scalar without reduction,
+    /// meaning every integer lower than `2^CAPACITY` is a canonical scalar.
+    pub const CAPACITY: u32 = Self::NUM_BITS - 1;
+
+    /// The 2-adicity `S` of the field, such that `r - 1 = 2^S · t` with `t`
+    /// odd.
+    pub const TWO_ADICITY: u32 = 1;
+
+    /// A generator of the `2^S`-th roots of unity, being `S` the
+    /// [`Fr::TWO_ADICITY`]. Since `S = 1`, this is `-1`.
+    pub const ROOT_OF_UNITY: Fr = Fr::neg(&R);
+
+    /// Returns the primitive `2^log_n`-th root of unity, computed as
+    /// `ROOT_OF_UNITY^(2^(S - log_n))`.
+    ///
+    /// The result is `none` if `log_n` exceeds the [`Fr::TWO_ADICITY`].
+    pub fn root_of_unity(log_n: u32) -> CtOption<Fr> {
+        let valid = log_n <= Self::TWO_ADICITY;
+        let squarings = Self::TWO_ADICITY.saturating_sub(log_n);
+
+        let root = Self::ROOT_OF_UNITY.pow2k(squarings);
+
+        CtOption::new(root, Choice::from(valid as u8))
+    }
+}
+
 impl Default for Fr {
     fn default() -> Self {
         Self::zero()
     }
 }
 
+impl Sum for Fr {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Fr::zero(), |acc, x| acc + x)
+    }
+}
+
+impl<'a> Sum<&'a Fr> for Fr {
+    fn sum<I: Iterator<Item = &'a Fr>>(iter: I) -> Self {
+        iter.fold(Fr::zero(), |acc, x| acc + x)
+    }
+}
+
+impl Product for Fr {
+    fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Fr::one(), |acc, x| acc * x)
+    }
+}
+
+impl<'a> Product<&'a Fr> for Fr {
+    fn product<I: Iterator<Item = &'a Fr>>(iter: I) -> Self {
+        iter.fold(Fr::one(), |acc, x| acc * x)
+    }
+}
+
 impl Serializable<32> for Fr {
     type Error = BytesError;
 
@@ -320,6 +402,76 @@ impl Fr {
         }
     }
 
+    /// Converts an element of `Fr` into a byte representation in
+    /// big-endian byte order.
+    ///
+    /// This is the byte-reversed [`Serializable::to_bytes`] encoding, which
+    /// is little-endian.
+    pub fn to_bytes_be(&self) -> [u8; Self::SIZE] {
+        let mut bytes = self.to_bytes();
+        bytes.reverse();
+        bytes
+    }
+
+    /// Attempts to convert a big-endian byte representation of
+    /// a field element into an element of `Fr`, failing if the input
+    /// is not canonical (is not smaller than r).
+    ///
+    /// This is the counterpart of [`Fr::to_bytes_be`]; use
+    /// [`Serializable::from_bytes`] for the little-endian encoding.
+    pub fn from_bytes_be(bytes: &[u8; Self::SIZE]) -> Result<Self, BytesError> {
+        let mut bytes = *bytes;
+        bytes.reverse();
+        Self::from_bytes(&bytes)
+    }
+
+    /// Parses an arbitrary-length decimal string into an element of `Fr`,
+    /// reducing it modulo r.
+    ///
+    /// Fails with [`BytesError::InvalidChar`] if the string contains anything
+    /// but ASCII digits, and with [`BytesError::InvalidData`] if it is empty.
+    /// Use [`Fr::from_dec_str_canonical`] to reject values not smaller than
+    /// r instead.
+    pub fn from_dec_str(s: &str) -> Result<Self, BytesError> {
+        if s.is_empty() {
+            return Err(BytesError::InvalidData);
+        }
+
+        let ten = Fr::from(10u64);
+        s.char_indices().try_fold(Fr::zero(), |acc, (index, ch)| {
+            ch.to_digit(10)
+                .map(|d| acc * ten + Fr::from(d as u64))
+                .ok_or(BytesError::InvalidChar { ch, index })
+        })
+    }
+
+    /// Same as [`Fr::from_dec_str`], but fails with
+    /// [`BytesError::InvalidData`] if the value is not smaller than r.
+    ///
+    /// Leading zeros are accepted.
+    pub fn from_dec_str_canonical(s: &str) -> Result<Self, BytesError> {
+        let value = Self::from_dec_str(s)?;
+
+        // The value is canonical if and only if its reduction has the same
+        // decimal representation as the input
+        let s = s.trim_start_matches('0').as_bytes();
+
+        let mut digits = [0u8; 76];
+        value.to_digits(10, &mut digits);
+        let len = digits.iter().rposition(|d| *d != 0).map_or(0, |i| i + 1);
+
+        let canonical = s.len() == len
+            && s.iter()
+                .rev()
+                .zip(digits.iter())
+                .all(|(c, d)| c - b'0' == *d);
+
+        match canonical {
+            true => Ok(value),
+            false => Err(BytesError::InvalidData),
+        }
+    }
+
     /// Converts a 512-bit little endian integer into
     /// an element of Fr by reducing modulo r.
     pub fn from_bytes_wide(bytes: &[u8; 64]) -> Fr {
@@ -364,6 +516,21 @@ impl Fr {
         (&Fr(val)).mul(&R2)
     }
 
+    /// Returns the internal Montgomery form representation of this element,
+    /// as four 64-bit limbs in little-endian order.
+    pub const fn to_montgomery_limbs(&self) -> [u64; 4] {
+        self.0
+    }
+
+    /// Constructs an element from its internal Montgomery form
+    /// representation, as returned by [`Fr::to_montgomery_limbs`].
+    ///
+    /// The limbs are used as they are, without any reduction or check: it is
+    /// up to the caller to provide a representation smaller than the modulus.
+    pub const fn from_montgomery_limbs(limbs: [u64; 4]) -> Self {
+        Fr(limbs)
+    }
+
     /// Squares this element.
     #[inline]
     pub const fn square(&self) -> Fr {
@@ -410,6 +577,42 @@ impl Fr {
         Fr::from_bytes_wide(&bytes)
     }
 
+    /// Generate a uniformly random element of the multiplicative group of
+    /// the field, that is, a random nonzero element.
+    ///
+    /// Elements are sampled with [`Fr::random`] until a nonzero one is
+    /// found, which is expected to happen at the first attempt.
+    pub fn random_nonzero<T>(rand: &mut T) -> Fr
+    where
+        T: RngCore + CryptoRng,
+    {
+        loop {
+            let r = Fr::random(rand);
+            if r != Fr::zero() {
+                return r;
+            }
+        }
+    }
+
+    /// Hash `data` to an element of `Fr` under the given `domain`, computing
+    /// `SHA-512(len(domain) || domain || data[0] || data[1] || ...)` and
+    /// reducing the 512-bit digest with [`Fr::from_bytes_wide`].
+    ///
+    /// The domain is prefixed with its length as a little-endian `u64`, so
+    /// distinct domains never collide. The slices of `data` are concatenated
+    /// as they are: callers hashing variable-length items should frame them.
+    pub fn hash_to_scalar(domain: &[u8], data: &[&[u8]]) -> Fr {
+        let mut hasher = Sha512::new();
+        hasher.update((domain.len() as u64).to_le_bytes());
+        hasher.update(domain);
+        data.iter().for_each(|d| hasher.update(d));
+
+        let mut bytes = [0u8; 64];
+        bytes.copy_from_slice(&hasher.finalize());
+
+        Fr::from_bytes_wide(&bytes)
+    }
+
     /// Computes the square root of this element, if it exists.
     pub fn sqrt(&self) -> CtOption<Self> {
         // Because r = 3 (mod 4)
@@ -429,6 +632,11 @@ impl Fr {
         )
     }
 
+    /// Computes `self^(2^k)` with `k` successive squarings.
+    pub fn pow2k(&self, k: u32) -> Self {
+        (0..k).fold(*self, |acc, _| acc.square())
+    }
+
     /// Exponentiates `self` by `by`, where `by` is a
     /// little-endian order integer exponent.
     pub fn pow(&self, by: &[u64; 4]) -> Self {
@@ -464,15 +672,60 @@ impl Fr {
         res
     }
 
+    /// Computes `base^exps[i]` into `out[i]` for every exponent, where the
+    /// exponents are little-endian integers as in [`Fr::pow_vartime`].
+    ///
+    /// The powers `base^0, ..., base^15` are computed once and shared across
+    /// all the exponents, which are then processed in 4-bit windows: every
+    /// exponent costs 4 squarings and at most one multiplication per window.
+    ///
+    /// **This operation is variable time with respect to the exponents**,
+    /// since zero windows are skipped.
+    ///
+    /// # Panics
+    ///
+    /// If `exps` and `out` have different lengths.
+    pub fn pow_batch(base: &Fr, exps: &[[u64; 4]], out: &mut [Fr]) {
+        assert_eq!(exps.len(), out.len(), "Each exponent requires an output");
+
+        let mut table = [Fr::one(); 16];
+        for i in 1..16 {
+            table[i] = table[i - 1] * base;
+        }
+
+        for (e, o) in exps.iter().zip(out.iter_mut()) {
+            let mut res = Fr::one();
+            let mut started = false;
+
+            for limb in e.iter().rev() {
+                for i in (0..16).rev() {
+                    if started {
+                        res = res.pow2k(4);
+                    }
+
+                    let window = ((limb >> (4 * i)) & 0x0f) as usize;
+                    if window != 0 {
+                        res *= table[window];
+                        started = true;
+                    }
+                }
+            }
+
+            *o = res;
+        }
+    }
+
+    /// Computes the multiplicative inverse of this element, or zero if the
+    /// element is zero.
+    ///
+    /// This is constant time, including for the zero element.
+    pub fn invert_or_zero(&self) -> Self {
+        self.invert().unwrap_or(Fr::zero())
+    }
+
     /// Computes the multiplicative inverse of this element,
     /// failing if the element is zero.
     pub fn invert(&self) -> CtOption<Self> {
-        #[inline(always)]
-        fn square_assign_multi(n: &mut Fr, num_times: usize) {
-            for _ in 0..num_times {
-                *n = n.square();
-            }
-        }
         // found using https://github.com/kwantam/addchain
         let mut t1 = self.square();
         let mut t0 = t1.square();
@@ -496,75 +749,75 @@ impl Fr {
         let t5 = t11 * &t3;
         t3 = t5 * &t0;
         t0 = t5.square();
-        square_assign_multi(&mut t0, 5);
+        t0 = t0.pow2k(5);
         t0.mul_assign(&t3);
-        square_assign_multi(&mut t0, 6);
+        t0 = t0.pow2k(6);
         t0.mul_assign(&t8);
-        square_assign_multi(&mut t0, 7);
+        t0 = t0.pow2k(7);
         t0.mul_assign(&t19);
-        square_assign_multi(&mut t0, 6);
+        t0 = t0.pow2k(6);
         t0.mul_assign(&t13);
-        square_assign_multi(&mut t0, 8);
+        t0 = t0.pow2k(8);
         t0.mul_assign(&t14);
-        square_assign_multi(&mut t0, 6);
+        t0 = t0.pow2k(6);
         t0.mul_assign(&t18);
-        square_assign_multi(&mut t0, 7);
+        t0 = t0.pow2k(7);
         t0.mul_assign(&t17);
-        square_assign_multi(&mut t0, 5);
+        t0 = t0.pow2k(5);
         t0.mul_assign(&t16);
-        square_assign_multi(&mut t0, 3);
+        t0 = t0.pow2k(3);
         t0.mul_assign(self);
-        square_assign_multi(&mut t0, 11);
+        t0 = t0.pow2k(11);
         t0.mul_assign(&t11);
-        square_assign_multi(&mut t0, 8);
+        t0 = t0.pow2k(8);
         t0.mul_assign(&t5);
-        square_assign_multi(&mut t0, 5);
+        t0 = t0.pow2k(5);
         t0.mul_assign(&t15);
-        square_assign_multi(&mut t0, 8);
+        t0 = t0.pow2k(8);
         t0.mul_assign(self);
-        square_assign_multi(&mut t0, 12);
+        t0 = t0.pow2k(12);
         t0.mul_assign(&t13);
-        square_assign_multi(&mut t0, 7);
+        t0 = t0.pow2k(7);
         t0.mul_assign(&t9);
-        square_assign_multi(&mut t0, 5);
+        t0 = t0.pow2k(5);
         t0.mul_assign(&t15);
-        square_assign_multi(&mut t0, 14);
+        t0 = t0.pow2k(14);
         t0.mul_assign(&t14);
-        square_assign_multi(&mut t0, 5);
+        t0 = t0.pow2k(5);
         t0.mul_assign(&t13);
-        square_assign_multi(&mut t0, 2);
+        t0 = t0.pow2k(2);
         t0.mul_assign(self);
-        square_assign_multi(&mut t0, 6);
+        t0 = t0.pow2k(6);
         t0.mul_assign(self);
-        square_assign_multi(&mut t0, 9);
+        t0 = t0.pow2k(9);
         t0.mul_assign(&t7);
-        square_assign_multi(&mut t0, 6);
+        t0 = t0.pow2k(6);
         t0.mul_assign(&t12);
-        square_assign_multi(&mut t0, 8);
+        t0 = t0.pow2k(8);
         t0.mul_assign(&t11);
-        square_assign_multi(&mut t0, 3);
+        t0 = t0.pow2k(3);
         t0.mul_assign(self);
-        square_assign_multi(&mut t0, 12);
+        t0 = t0.pow2k(12);
         t0.mul_assign(&t9);
-        square_assign_multi(&mut t0, 11);
+        t0 = t0.pow2k(11);
         t0.mul_assign(&t8);
-        square_assign_multi(&mut t0, 8);
+        t0 = t0.pow2k(8);
         t0.mul_assign(&t7);
-        square_assign_multi(&mut t0, 4);
+        t0 = t0.pow2k(4);
         t0.mul_assign(&t6);
-        square_assign_multi(&mut t0, 10);
+        t0 = t0.pow2k(10);
         t0.mul_assign(&t5);
-        square_assign_multi(&mut t0, 7);
+        t0 = t0.pow2k(7);
         t0.mul_assign(&t3);
-        square_assign_multi(&mut t0, 6);
+        t0 = t0.pow2k(6);
         t0.mul_assign(&t4);
-        square_assign_multi(&mut t0, 7);
+        t0 = t0.pow2k(7);
         t0.mul_assign(&t3);
-        square_assign_multi(&mut t0, 5);
+        t0 = t0.pow2k(5);
         t0.mul_assign(&t2);
-        square_assign_multi(&mut t0, 6);
+        t0 = t0.pow2k(6);
         t0.mul_assign(&t2);
-        square_assign_multi(&mut t0, 7);
+        t0 = t0.pow2k(7);
         t0.mul_assign(&t1);
 
         CtOption::new(t0, !self.ct_eq(&Self::zero()))
@@ -614,7 +867,12 @@ impl Fr {
         let (r7, _) = adc(r7, carry2, carry);
 
         // Result may be within MODULUS of the correct value
-        (&Fr([r4, r5, r6, r7])).sub(&MODULUS)
+        let r = Fr::sub(&Fr([r4, r5, r6, r7]), &MODULUS);
+
+        #[cfg(debug_assertions)]
+        r.debug_assert_valid();
+
+        r
     }
 
     /// Multiplies this element by another element
@@ -661,7 +919,12 @@ impl Fr {
         let (d2, carry) = adc(d2, MODULUS.0[2] & borrow, carry);
         let (d3, _) = adc(d3, MODULUS.0[3] & borrow, carry);
 
-        Fr([d0, d1, d2, d3])
+        let r = Fr([d0, d1, d2, d3]);
+
+        #[cfg(debug_assertions)]
+        r.debug_assert_valid();
+
+        r
     }
 
     /// Adds this element to another element.
@@ -694,7 +957,35 @@ impl Fr {
             as u64)
             .wrapping_sub(1);
 
-        Fr([d0 & mask, d1 & mask, d2 & mask, d3 & mask])
+        let r = Fr([d0 & mask, d1 & mask, d2 & mask, d3 & mask]);
+
+        #[cfg(debug_assertions)]
+        r.debug_assert_valid();
+
+        r
+    }
+
+    /// Checks whether the internal representation of this element is
+    /// smaller than the modulus, which is the case for every element built
+    /// through the public API except [`Fr::from_montgomery_limbs`].
+    ///
+    /// In debug builds, this invariant is asserted after every arithmetic
+    /// operation.
+    pub const fn is_valid(&self) -> bool {
+        let (_, borrow) = sbb(self.0[0], MODULUS.0[0], 0);
+        let (_, borrow) = sbb(self.0[1], MODULUS.0[1], borrow);
+        let (_, borrow) = sbb(self.0[2], MODULUS.0[2], borrow);
+        let (_, borrow) = sbb(self.0[3], MODULUS.0[3], borrow);
+
+        // The subtraction underflows only if `self` is smaller than the
+        // modulus
+        borrow != 0
+    }
+
+    #[cfg(debug_assertions)]
+    #[inline]
+    const fn debug_assert_valid(&self) {
+        debug_assert!(self.is_valid(), "Non-canonical JubJubScalar");
     }
     /// Reduces bit representation of numbers, such that
     /// they can be evaluated in terms of the least significant bit.
@@ -704,6 +995,70 @@ impl Fr {
         )
     }
 
+    /// Returns the bit `i` of the canonical little endian representation
+    /// of the scalar, with bit `0` being the least significant.
+    ///
+    /// The scalar is inspected in constant time, but `i` is not secret. Bits
+    /// past the [`Fr::NUM_BITS`] are always zero, including any `i >= 256`.
+    pub fn bit(&self, i: usize) -> Choice {
+        let bytes = self.to_bytes();
+        let byte = bytes.get(i / 8).copied().unwrap_or(0);
+
+        Choice::from((byte >> (i % 8)) & 1)
+    }
+
+    /// Returns the [`Fr::NUM_BITS`] = 252 bits of the canonical little endian
+    /// representation of the scalar, with the least significant bit first.
+    ///
+    /// The higher bits of the 256-bit representation are always zero, so
+    /// they are left out.
+    pub fn to_le_bits(&self) -> [bool; Self::NUM_BITS as usize] {
+        let bytes = self.to_bytes();
+
+        let mut bits = [false; Self::NUM_BITS as usize];
+        for (i, bit) in bits.iter_mut().enumerate() {
+            *bit = (bytes[i / 8] >> (i % 8)) & 1 == 1;
+        }
+
+        bits
+    }
+
+    /// Compose a scalar from little endian bits, with the least significant
+    /// bit first, as returned by [`Fr::to_le_bits`].
+    ///
+    /// Any number of bits is accepted, so both the 252 bits of
+    /// [`Fr::to_le_bits`] and a full 256-bit representation can be used.
+    /// The result is none if the bits encode a value greater or equal to the
+    /// modulus, including any bit set past the 256th.
+    ///
+    /// The bits are inspected in constant time, but their number is not
+    /// secret.
+    pub fn from_le_bits(bits: &[bool]) -> CtOption<Fr> {
+        let mut tmp = Fr([0, 0, 0, 0]);
+        let mut overflow = Choice::from(0);
+
+        for (i, bit) in bits.iter().enumerate() {
+            let bit = *bit as u64;
+            match tmp.0.get_mut(i / 64) {
+                Some(limb) => *limb |= bit << (i % 64),
+                None => overflow |= Choice::from(bit as u8),
+            }
+        }
+
+        // Try to subtract the modulus, underflowing if the value is smaller
+        let (_, borrow) = sbb(tmp.0[0], MODULUS.0[0], 0);
+        let (_, borrow) = sbb(tmp.0[1], MODULUS.0[1], borrow);
+        let (_, borrow) = sbb(tmp.0[2], MODULUS.0[2], borrow);
+        let (_, borrow) = sbb(tmp.0[3], MODULUS.0[3], borrow);
+        let is_some = Choice::from((borrow as u8) & 1) & !overflow;
+
+        // Convert to Montgomery form by computing
+        // (a.R^0 * R^2) / R = a.R
+        tmp *= &R2;
+
+        CtOption::new(tmp, is_some)
+    }
+
     /// Evaluate if a `Scalar, from Fr` is even or not.
     pub fn is_even(&self) -> bool {
         self.0[0] % 2 == 0
@@ -760,6 +1115,128 @@ impl Fr {
     }
 }
 
+impl Fr {
+    /// Recodes this element into 64 signed radix-16 digits, in little-endian
+    /// order, as [`Fr::to_signed_digits`] with 4-bit windows does, but
+    /// without allocating.
+    ///
+    /// Every digit lies in `[-8, 8)`, except the last one which is either `0`
+    /// or `1` since the element is lower than `2^252`.
+    pub(crate) fn to_radix_16(self) -> [i8; 64] {
+        let bytes = self.to_bytes();
+
+        let mut digits = [0i8; 64];
+        for (i, b) in bytes.iter().enumerate() {
+            digits[2 * i] = (b & 0x0f) as i8;
+            digits[2 * i + 1] = (b >> 4) as i8;
+        }
+        for i in 0..63 {
+            let carry = (digits[i] + 8) >> 4;
+            digits[i] -= carry << 4;
+            digits[i + 1] += carry;
+        }
+
+        digits
+    }
+
+    /// Recodes this element into signed digits of `window_bits` bits, in
+    /// little-endian order, such that `self = Σ d_i · 2^(i · window_bits)`.
+    ///
+    /// Every digit lies in `[-2^(window_bits - 1), 2^(window_bits - 1))`,
+    /// except the last one which is either `0` or `1`. Since the modulus is
+    /// 252 bits long, `ceil(252 / window_bits) + 1` digits are returned.
+    ///
+    /// # Panics
+    ///
+    /// If `window_bits` is zero or greater than `31`.
+    #[cfg(feature = "alloc")]
+    pub fn to_signed_digits(&self, window_bits: u32) -> Vec<i32> {
+        assert!(window_bits > 0 && window_bits < 32, "Invalid window size");
+
+        let bytes = self.to_bytes();
+        let bit = |i: u32| match bytes.get(i as usize / 8) {
+            Some(b) => ((b >> (i % 8)) & 1) as i64,
+            None => 0,
+        };
+
+        let radix = 1i64 << window_bits;
+        let half = radix >> 1;
+        let windows = 252u32.div_ceil(window_bits);
+
+        let mut carry = 0i64;
+        let mut digits = Vec::with_capacity(windows as usize + 1);
+        for w in 0..windows {
+            let window = (0..window_bits)
+                .fold(0i64, |acc, i| acc | (bit(w * window_bits + i) << i));
+
+            let value = window + carry;
+            carry = (value >= half) as i64;
+            digits.push((value - carry * radix) as i32);
+        }
+        digits.push(carry as i32);
+
+        digits
+    }
+
+    /// Decomposes the canonical representation of this element into
+    /// little-endian digits in the given `base`, such that
+    /// `self = Σ out[i] · base^i`. Unused trailing digits are set to zero.
+    ///
+    /// Since the modulus is 252 bits long, `ceil(252 / log2(base))` digits
+    /// are enough for any element: e.g. 252 in base 2, 76 in base 10, 63 in
+    /// base 16 and 32 in base 256.
+    ///
+    /// # Panics
+    ///
+    /// If `base` is not in `[2, 256]`, or if `out` is too short to hold the
+    /// decomposition.
+    pub fn to_digits(&self, base: u32, out: &mut [u8]) {
+        assert!((2..=256).contains(&base), "Invalid base");
+
+        let bytes = self.to_bytes();
+        let mut limbs = [0u64; 4];
+        limbs
+            .iter_mut()
+            .zip(bytes.chunks_exact(8))
+            .for_each(|(l, c)| {
+                let mut buf = [0u8; 8];
+                buf.copy_from_slice(c);
+                *l = u64::from_le_bytes(buf);
+            });
+
+        let base = base as u128;
+        for digit in out.iter_mut() {
+            let mut rem = 0u128;
+            for limb in limbs.iter_mut().rev() {
+                let cur = (rem << 64) | *limb as u128;
+                *limb = (cur / base) as u64;
+                rem = cur % base;
+            }
+            *digit = rem as u8;
+        }
+
+        assert!(limbs.iter().all(|l| *l == 0), "Not enough digits");
+    }
+
+    /// Reconstructs an element from its little-endian digits in the given
+    /// `base`, computing `Σ digits[i] · base^i` modulo `r`.
+    ///
+    /// This is the inverse of [`Fr::to_digits`].
+    ///
+    /// # Panics
+    ///
+    /// If `base` is not in `[2, 256]`.
+    pub fn from_digits(base: u32, digits: &[u8]) -> Fr {
+        assert!((2..=256).contains(&base), "Invalid base");
+
+        let radix = Fr::from(base as u64);
+        digits
+            .iter()
+            .rev()
+            .fold(Fr::zero(), |acc, d| acc * radix + Fr::from(*d as u64))
+    }
+}
+
 impl<'a> From<&'a Fr> for [u8; Fr::SIZE] {
     fn from(value: &'a Fr) -> [u8; Fr::SIZE] {
         value.to_bytes()
@@ -797,6 +1274,23 @@ fn test_debug() {
     );
 }
 
+#[test]
+fn test_from_signed() {
+    assert_eq!(Fr::from(-1i64), -Fr::one());
+    assert_eq!(Fr::from(0i64), Fr::zero());
+    assert_eq!(Fr::from(3i64), Fr::from(3u64));
+    assert_eq!(Fr::from(-3i64), -Fr::from(3u64));
+    assert_eq!(Fr::from(i64::MIN), -Fr::from(1u64 << 63));
+
+    assert_eq!(Fr::from(-1i128), -Fr::one());
+    assert_eq!(Fr::from(-3i128), Fr::from(-3i64));
+    assert_eq!(
+        Fr::from(i128::MAX),
+        Fr::from(u64::MAX) * Fr::from(1u64 << 63) + Fr::from(i64::MAX)
+    );
+    assert_eq!(Fr::from(i128::MIN), -Fr::from(i128::MAX) - Fr::one());
+}
+
 #[test]
 fn test_equality() {
     assert_eq!(Fr::zero(), Fr::zero());
@@ -908,6 +1402,42 @@ fn test_from_bytes() {
     .is_err());
 }
 
+#[test]
+fn test_bytes_be() {
+    let mut cur = LARGEST;
+
+    for _ in 0..100 {
+        let le = cur.to_bytes();
+        let be = cur.to_bytes_be();
+
+        let mut reversed = le;
+        reversed.reverse();
+        assert_eq!(reversed, be);
+
+        assert_eq!(Fr::from_bytes(&le).unwrap(), cur);
+        assert_eq!(Fr::from_bytes_be(&be).unwrap(), cur);
+
+        cur = cur.square() + R2;
+    }
+
+    assert_eq!(
+        Fr::one().to_bytes_be(),
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 1
+        ]
+    );
+
+    // modulus is invalid
+    let mut modulus = MODULUS.0;
+    modulus.reverse();
+    let mut bytes = [0u8; 32];
+    for (chunk, limb) in bytes.chunks_mut(8).zip(modulus.iter()) {
+        chunk.copy_from_slice(&limb.to_be_bytes());
+    }
+    assert!(Fr::from_bytes_be(&bytes).is_err());
+}
+
 #[test]
 fn test_from_u512_zero() {
     assert_eq!(
@@ -1035,6 +1565,23 @@ fn test_negation() {
     assert_eq!(tmp, LARGEST);
 }
 
+#[test]
+fn test_negation_identities() {
+    use rand_core::OsRng;
+
+    assert_eq!(-Fr::zero(), Fr::zero());
+    assert_eq!(-(-Fr::one()), Fr::one());
+
+    for _ in 0..100 {
+        let x = Fr::random(&mut OsRng);
+
+        assert_eq!(x + (-x), Fr::zero());
+        assert_eq!(x + (-&x), Fr::zero());
+        assert_eq!(-(-x), x);
+        assert_eq!(-&x, -x);
+    }
+}
+
 #[test]
 fn test_subtraction() {
     let mut tmp = LARGEST;
@@ -1121,6 +1668,98 @@ fn test_inversion() {
     }
 }
 
+#[test]
+fn test_pow2k() {
+    let mut x = R2;
+    for _ in 0..100 {
+        assert_eq!(x.pow2k(0), x);
+        assert_eq!(x.pow2k(1), x.square());
+        assert_eq!(x.pow2k(3), x.square().square().square());
+        assert_eq!(x.pow2k(64), x.pow(&[0, 1, 0, 0]));
+
+        x = x.square() + R2;
+    }
+}
+
+#[test]
+fn test_sum_product() {
+    let values: Vec<Fr> = (1..=5u64).map(Fr::from).collect();
+
+    assert_eq!(values.iter().sum::<Fr>(), Fr::from(15u64));
+    assert_eq!(values.iter().copied().product::<Fr>(), Fr::from(120u64));
+    assert_eq!(core::iter::empty::<Fr>().sum::<Fr>(), Fr::zero());
+    assert_eq!(core::iter::empty::<Fr>().product::<Fr>(), Fr::one());
+}
+
+#[test]
+fn test_random_nonzero() {
+    use rand_core::OsRng;
+
+    // Yields zeros for the first 64 bytes
+    struct ZeroFirst(usize);
+
+    impl RngCore for ZeroFirst {
+        fn next_u32(&mut self) -> u32 {
+            self.next_u64() as u32
+        }
+
+        fn next_u64(&mut self) -> u64 {
+            let mut bytes = [0u8; 8];
+            self.fill_bytes(&mut bytes);
+            u64::from_le_bytes(bytes)
+        }
+
+        fn fill_bytes(&mut self, dest: &mut [u8]) {
+            for b in dest.iter_mut() {
+                *b = if self.0 < 64 { 0 } else { 0xa5 };
+                self.0 += 1;
+            }
+        }
+
+        fn try_fill_bytes(
+            &mut self,
+            dest: &mut [u8],
+        ) -> Result<(), rand_core::Error> {
+            self.fill_bytes(dest);
+            Ok(())
+        }
+    }
+
+    impl CryptoRng for ZeroFirst {}
+
+    let mut rng = ZeroFirst(0);
+    assert_eq!(Fr::random(&mut rng), Fr::zero());
+
+    let mut rng = ZeroFirst(0);
+    let x = Fr::random_nonzero(&mut rng);
+    assert_ne!(x, Fr::zero());
+    assert_eq!(rng.0, 128);
+
+    for _ in 0..100 {
+        let x = Fr::random_nonzero(&mut OsRng);
+        assert_ne!(x, Fr::zero());
+        assert_eq!(x * x.invert().unwrap(), Fr::one());
+    }
+}
+
+#[test]
+fn test_invert_or_zero() {
+    use rand_core::OsRng;
+
+    assert_eq!(Fr::zero().invert_or_zero(), Fr::zero());
+    assert_eq!(Fr::one().invert_or_zero(), Fr::one());
+
+    for _ in 0..100 {
+        let x = Fr::random(&mut OsRng);
+        if x == Fr::zero() {
+            continue;
+        }
+
+        assert_eq!(x.invert_or_zero() * x, Fr::one());
+        assert_eq!(x.invert_or_zero(), x.invert().unwrap());
+    }
+}
+
 #[test]
 fn test_invert_is_pow() {
     let r_minus_2 = [
@@ -1173,6 +1812,23 @@ fn test_sqrt() {
     assert_eq!(47, none_count);
 }
 
+#[test]
+fn test_montgomery_limbs() {
+    use rand_core::OsRng;
+
+    assert_eq!(Fr::one().to_montgomery_limbs(), R.0);
+    assert_eq!(Fr::from_montgomery_limbs(R2.0), R2);
+
+    for _ in 0..100 {
+        let x = Fr::random(&mut OsRng);
+        assert_eq!(Fr::from_montgomery_limbs(x.to_montgomery_limbs()), x);
+
+        // The BLS scalar exposes its Montgomery form limbs directly
+        let y = BlsScalar::random(&mut OsRng);
+        assert_eq!(BlsScalar(*y.internal_repr()), y);
+    }
+}
+
 #[test]
 fn test_from_raw() {
     assert_eq!(
@@ -1190,6 +1846,259 @@ fn test_from_raw() {
     assert_eq!(Fr::from_raw([1, 0, 0, 0]), R);
 }
 
+#[test]
+fn test_signed_digits() {
+    use rand_core::OsRng;
+
+    fn reconstruct(digits: &[i32], window_bits: u32) -> Fr {
+        let radix = Fr::from(1u64 << window_bits);
+        digits
+            .iter()
+            .rev()
+            .fold(Fr::zero(), |acc, d| acc * radix + Fr::from(*d as i64))
+    }
+
+    let mut scalars = vec![Fr::zero(), Fr::one(), -Fr::one(), LARGEST];
+    scalars.extend((0..20).map(|_| Fr::random(&mut OsRng)));
+
+    for window_bits in 1..=8 {
+        let half = 1i32 << (window_bits - 1);
+
+        for s in scalars.iter() {
+            let digits = s.to_signed_digits(window_bits);
+
+            assert_eq!(digits.len() as u32, 252u32.div_ceil(window_bits) + 1);
+            assert!(digits[..digits.len() - 1]
+                .iter()
+                .all(|d| *d >= -half && *d < half));
+            assert!(
+                digits[digits.len() - 1] == 0 || digits[digits.len() - 1] == 1
+            );
+
+            assert_eq!(reconstruct(&digits, window_bits), *s);
+        }
+    }
+
+    let s = LARGEST.to_signed_digits(16);
+    assert_eq!(reconstruct(&s, 16), LARGEST);
+}
+
+#[test]
+#[should_panic]
+fn test_signed_digits_empty_window() {
+    Fr::one().to_signed_digits(0);
+}
+
+#[test]
+fn test_arithmetic_vectors() {
+    // Computed independently with arbitrary precision integers, so the
+    // results don't depend on the limb representation.
+    let a = Fr::from_bytes(&[
+        54, 127, 235, 254, 87, 111, 168, 3, 217, 252, 208, 25, 173, 156, 250,
+        128, 159, 194, 124, 234, 8, 184, 119, 37, 205, 68, 11, 199, 178, 115,
+        100, 13,
+    ])
+    .unwrap();
+    let b = Fr::from_bytes(&[
+        24, 100, 64, 177, 75, 169, 114, 36, 23, 228, 126, 101, 158, 155, 160,
+        180, 132, 111, 112, 244, 125, 134, 36, 10, 49, 151, 233, 186, 173, 139,
+        23, 12,
+    ])
+    .unwrap();
+
+    let vectors = [
+        (
+            a + b,
+            [
+                151, 182, 52, 217, 68, 10, 132, 87, 109, 208, 135, 178, 183,
+                23, 51, 143, 35, 247, 184, 221, 133, 3, 53, 41, 85, 44, 193,
+                28, 118, 74, 254, 10,
+            ],
+        ),
+        (
+            a - b,
+            [
+                30, 27, 171, 77, 12, 198, 53, 223, 193, 24, 82, 180, 14, 1, 90,
+                204, 26, 83, 12, 246, 138, 49, 83, 27, 156, 173, 33, 12, 5,
+                232, 76, 1,
+            ],
+        ),
+        (
+            a * b,
+            [
+                242, 19, 122, 171, 185, 154, 48, 232, 161, 226, 247, 153, 215,
+                98, 242, 2, 22, 152, 149, 104, 185, 60, 99, 210, 215, 177, 210,
+                51, 229, 194, 220, 3,
+            ],
+        ),
+        (
+            a.square(),
+            [
+                0, 38, 83, 195, 44, 24, 164, 250, 8, 35, 196, 232, 84, 72, 116,
+                27, 103, 52, 122, 56, 244, 1, 147, 35, 203, 98, 162, 1, 191,
+                94, 136, 10,
+            ],
+        ),
+        (
+            a.invert().unwrap(),
+            [
+                170, 201, 220, 109, 30, 252, 179, 71, 87, 137, 184, 70, 81,
+                206, 189, 210, 213, 238, 181, 87, 87, 149, 139, 23, 107, 236,
+                205, 89, 123, 126, 238, 0,
+            ],
+        ),
+    ];
+
+    for (result, expected) in vectors.iter() {
+        assert_eq!(&result.to_bytes(), expected);
+    }
+}
+
+#[test]
+fn test_is_valid() {
+    use rand_core::OsRng;
+
+    assert!(Fr::zero().is_valid());
+    assert!(Fr::one().is_valid());
+    assert!(LARGEST.is_valid());
+    assert!((0..100).all(|_| Fr::random(&mut OsRng).is_valid()));
+
+    assert!(!Fr::from_montgomery_limbs(MODULUS.0).is_valid());
+    assert!(!Fr::from_montgomery_limbs([u64::MAX; 4]).is_valid());
+
+    let mut limbs = MODULUS.0;
+    limbs[0] -= 1;
+    assert!(Fr::from_montgomery_limbs(limbs).is_valid());
+}
+
+#[test]
+fn test_from_dec_str() {
+    const R: &str = "6554484396890773809930967563523245729705921265872317281365359162392183254199";
+    const R_MINUS_ONE: &str = "6554484396890773809930967563523245729705921265872317281365359162392183254198";
+    const R_PLUS_TWO: &str = "6554484396890773809930967563523245729705921265872317281365359162392183254201";
+
+    assert_eq!(Fr::from_dec_str("0"), Ok(Fr::zero()));
+    assert_eq!(Fr::from_dec_str("1"), Ok(Fr::one()));
+    assert_eq!(Fr::from_dec_str("000042"), Ok(Fr::from(42u64)));
+    assert_eq!(
+        Fr::from_dec_str("18446744073709551616"),
+        Ok(Fr::from(u64::MAX) + Fr::one())
+    );
+
+    assert_eq!(Fr::from_dec_str(R_MINUS_ONE), Ok(-Fr::one()));
+    assert_eq!(Fr::from_dec_str(R), Ok(Fr::zero()));
+    assert_eq!(Fr::from_dec_str(R_PLUS_TWO), Ok(Fr::from(2u64)));
+    assert_eq!(Fr::from_dec_str(&[R, "0"].concat()), Ok(Fr::zero()));
+
+    assert_eq!(Fr::from_dec_str_canonical("0"), Ok(Fr::zero()));
+    assert_eq!(Fr::from_dec_str_canonical("0042"), Ok(Fr::from(42u64)));
+    assert_eq!(Fr::from_dec_str_canonical(R_MINUS_ONE), Ok(-Fr::one()));
+    assert_eq!(Fr::from_dec_str_canonical(R), Err(BytesError::InvalidData));
+    assert_eq!(
+        Fr::from_dec_str_canonical(R_PLUS_TWO),
+        Err(BytesError::InvalidData)
+    );
+
+    assert_eq!(Fr::from_dec_str(""), Err(BytesError::InvalidData));
+    assert_eq!(Fr::from_dec_str_canonical(""), Err(BytesError::InvalidData));
+    assert_eq!(
+        Fr::from_dec_str("12a4"),
+        Err(BytesError::InvalidChar { ch: 'a', index: 2 })
+    );
+    assert_eq!(
+        Fr::from_dec_str("-1"),
+        Err(BytesError::InvalidChar { ch: '-', index: 0 })
+    );
+    assert_eq!(
+        Fr::from_dec_str("1 "),
+        Err(BytesError::InvalidChar { ch: ' ', index: 1 })
+    );
+    assert_eq!(
+        Fr::from_dec_str("\u{0663}"),
+        Err(BytesError::InvalidChar {
+            ch: '\u{0663}',
+            index: 0
+        })
+    );
+}
+
+#[test]
+fn test_root_of_unity() {
+    assert_eq!(Fr::ROOT_OF_UNITY, -Fr::one());
+    assert_eq!(
+        Fr::ROOT_OF_UNITY.pow(&[1 << Fr::TWO_ADICITY, 0, 0, 0]),
+        Fr::one()
+    );
+
+    // r - 1 = 2^S · t with t odd
+    let mut t = MODULUS;
+    t.divn(Fr::TWO_ADICITY);
+    assert_eq!(t.0[0] & 1, 1);
+
+    assert_eq!(Fr::root_of_unity(0).unwrap(), Fr::one());
+    assert_eq!(Fr::root_of_unity(1).unwrap(), -Fr::one());
+    assert!(bool::from(Fr::root_of_unity(2).is_none()));
+    assert!(bool::from(Fr::root_of_unity(u32::MAX).is_none()));
+}
+
+#[test]
+fn test_hash_to_scalar() {
+    let a = Fr::hash_to_scalar(b"domain", &[b"some", b"data"]);
+
+    assert_eq!(a, Fr::hash_to_scalar(b"domain", &[b"some", b"data"]));
+    assert_eq!(a, Fr::hash_to_scalar(b"domain", &[b"somedata"]));
+    assert_ne!(a, Fr::hash_to_scalar(b"domain", &[b"other", b"data"]));
+    assert_ne!(a, Fr::hash_to_scalar(b"another", &[b"some", b"data"]));
+
+    // The domain is framed, so it can't be shifted into the data
+    assert_ne!(a, Fr::hash_to_scalar(b"domains", &[b"ome", b"data"]));
+    assert_eq!(
+        Fr::hash_to_scalar(b"", &[]),
+        Fr::hash_to_scalar(b"", &[b""])
+    );
+
+    let mut hasher = Sha512::new();
+    hasher.update(6u64.to_le_bytes());
+    hasher.update(b"domain");
+    hasher.update(b"somedata");
+    let mut bytes = [0u8; 64];
+    bytes.copy_from_slice(&hasher.finalize());
+    assert_eq!(a, Fr::from_bytes_wide(&bytes));
+}
+
+#[test]
+fn test_digits() {
+    use rand_core::OsRng;
+
+    let mut scalars = vec![Fr::zero(), Fr::one(), -Fr::one(), LARGEST];
+    scalars.extend((0..20).map(|_| Fr::random(&mut OsRng)));
+
+    for (base, len) in [(2, 252), (10, 76), (16, 63), (256, 32)].iter() {
+        for s in scalars.iter() {
+            let mut digits = [0xffu8; 256];
+            s.to_digits(*base, &mut digits[..*len]);
+
+            assert!(digits[..*len].iter().all(|d| (*d as u32) < *base));
+            assert_eq!(Fr::from_digits(*base, &digits[..*len]), *s);
+
+            s.to_digits(*base, &mut digits);
+            assert!(digits[*len..].iter().all(|d| *d == 0));
+            assert_eq!(Fr::from_digits(*base, &digits), *s);
+        }
+    }
+
+    let mut digits = [0u8; 3];
+    Fr::from(255u64).to_digits(10, &mut digits);
+    assert_eq!(digits, [5, 5, 2]);
+}
+
+#[test]
+#[should_panic]
+fn test_digits_too_short() {
+    let mut digits = [0u8; 75];
+    LARGEST.to_digits(10, &mut digits);
+}
+
 #[test]
 fn w_naf() {
     let fr = Fr::from(1122334455u64);
@@ -1202,3 +2111,149 @@ fn w_naf() {
     buf.copy_from_slice(&computed[0..31]);
     assert!(&naf3_fr[..] == &computed[..31]);
 }
+
+#[test]
+fn test_num_bits() {
+    let top = MODULUS.0[3];
+    assert_eq!(Fr::NUM_BITS, 256 - top.leading_zeros());
+
+    // 2^CAPACITY is the largest power of two lower than the modulus
+    assert_eq!(top >> (Fr::CAPACITY - 192), 1);
+    assert_eq!(Fr::CAPACITY, Fr::NUM_BITS - 1);
+}
+
+#[test]
+fn test_bit() {
+    let scalars = [
+        Fr::zero(),
+        Fr::one(),
+        Fr::from(0xdead_beefu64),
+        Fr::from(u64::MAX),
+        -Fr::one(),
+        Fr::from_raw([
+            0x0123_4567_89ab_cdef,
+            0xfedc_ba98_7654_3210,
+            0x0f1e_2d3c_4b5a_6978,
+            0x0102_0304_0506_0708,
+        ]),
+    ];
+
+    for scalar in scalars.iter() {
+        let mut reconstructed = Fr::zero();
+        for i in (0..256).rev() {
+            reconstructed = reconstructed.double();
+            if bool::from(scalar.bit(i)) {
+                reconstructed += Fr::one();
+            }
+        }
+        assert_eq!(&reconstructed, scalar);
+
+        for i in Fr::NUM_BITS as usize..300 {
+            assert!(!bool::from(scalar.bit(i)));
+        }
+    }
+
+    let value = 0xdead_beefu64;
+    for i in 0..64 {
+        let expected = (value >> i) & 1 == 1;
+        assert_eq!(bool::from(Fr::from(value).bit(i)), expected);
+    }
+
+    // The highest bit of `r - 1` is set
+    assert!(bool::from((-Fr::one()).bit(Fr::NUM_BITS as usize - 1)));
+}
+
+#[test]
+fn test_le_bits() {
+    let scalars = [
+        Fr::zero(),
+        Fr::one(),
+        Fr::from(0xdead_beefu64),
+        -Fr::one(),
+        Fr::from_raw([
+            0x0123_4567_89ab_cdef,
+            0xfedc_ba98_7654_3210,
+            0x0f1e_2d3c_4b5a_6978,
+            0x0102_0304_0506_0708,
+        ]),
+    ];
+
+    for scalar in scalars.iter() {
+        let bits = scalar.to_le_bits();
+        assert_eq!(bits.len(), Fr::NUM_BITS as usize);
+        for (i, bit) in bits.iter().enumerate() {
+            assert_eq!(*bit, bool::from(scalar.bit(i)));
+        }
+
+        assert_eq!(Fr::from_le_bits(&bits).unwrap(), *scalar);
+
+        // Zero padding up to and past 256 bits is accepted
+        let mut padded = [false; 300];
+        padded[..bits.len()].copy_from_slice(&bits);
+        assert_eq!(Fr::from_le_bits(&padded[..256]).unwrap(), *scalar);
+        assert_eq!(Fr::from_le_bits(&padded).unwrap(), *scalar);
+    }
+
+    assert_eq!(Fr::from_le_bits(&[]).unwrap(), Fr::zero());
+    assert_eq!(Fr::from_le_bits(&[false, true]).unwrap(), Fr::from(2u64));
+
+    // All ones encode 2^252 - 1, above the modulus
+    let ones = [true; Fr::NUM_BITS as usize];
+    assert!(bool::from(Fr::from_le_bits(&ones).is_none()));
+
+    // The modulus itself is rejected, and `r - 1` accepted
+    let mut modulus = [false; 256];
+    for (i, bit) in modulus.iter_mut().enumerate() {
+        *bit = (MODULUS.0[i / 64] >> (i % 64)) & 1 == 1;
+    }
+    assert!(bool::from(Fr::from_le_bits(&modulus).is_none()));
+    modulus[0] = false;
+    assert_eq!(Fr::from_le_bits(&modulus).unwrap(), -Fr::one());
+
+    let mut overflow = [false; 257];
+    overflow[256] = true;
+    assert!(bool::from(Fr::from_le_bits(&overflow).is_none()));
+}
+
+#[test]
+fn test_pow_batch() {
+    let base = Fr::from(0x1234_5678u64).invert().unwrap();
+
+    let exps = [
+        [0, 0, 0, 0],
+        [1, 0, 0, 0],
+        [16, 0, 0, 0],
+        [0, 0, 0, 1 << 63],
+        [u64::MAX; 4],
+        [
+            0xd0970e5ed6f72cb6,
+            0xa6682093ccc81082,
+            0x06673b0101343b00,
+            0x0e7db4ea6533afa9,
+        ],
+        [
+            0x0123_4567_89ab_cdef,
+            0,
+            0xfedc_ba98_7654_3210,
+            0x0f0f_0f0f_0f0f_0f0f,
+        ],
+    ];
+    let mut out = [Fr::zero(); 7];
+
+    Fr::pow_batch(&base, &exps, &mut out);
+    for (e, o) in exps.iter().zip(out.iter()) {
+        assert_eq!(o, &base.pow_vartime(e));
+    }
+
+    // Fermat's little theorem: base^(r - 1) = 1
+    assert_eq!(out[5], Fr::one());
+
+    Fr::pow_batch(&base, &[], &mut []);
+}
+
+#[test]
+#[should_panic]
+fn test_pow_batch_mismatched() {
+    let mut out = [Fr::zero(); 2];
+    Fr::pow_batch(&Fr::one(), &[[1, 0, 0, 0]], &mut out);
+}
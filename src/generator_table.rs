@@ -0,0 +1,3915 @@
+//! Fixed-base table of [`GENERATOR_EXTENDED`], generated by
+//! `examples/gen_generator_table.rs`.
+//!
+//! Entry `[i][j]` is `(j + 1) · 256^i · G`.
+//!
+//! [`GENERATOR_EXTENDED`]: crate::GENERATOR_EXTENDED
+
+use crate::{AffineNielsPoint, BlsScalar, JubJubAffine};
+
+pub(crate) static GENERATOR_TABLE: [[AffineNielsPoint; 8]; 32] = [
+    [
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0x4df7b7ffec7beaca,
+                0x2e3ebb21fd6c54ed,
+                0xf1fbf02d0fd6cce6,
+                0x3fd2814c43ac65a6,
+            ]),
+            BlsScalar::from_raw([
+                0x0000000000000012,
+                0x0000000000000000,
+                0x0000000000000000,
+                0x0000000000000000,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0x80eee554cba976b4,
+                0xcaf3ad858ea04c0c,
+                0x8654a8c770137476,
+                0x3406866c17fc106f,
+            ]),
+            BlsScalar::from_raw([
+                0x5ff2d4023d8c2e5f,
+                0xf59f8a8c277fdbe7,
+                0xacd365a062345c7b,
+                0x477abd3191d9c67e,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0x6ff29ccfc8721673,
+                0x8c6bd932e3288425,
+                0xf3ab723f0a0c86d1,
+                0x4e7d425467582402,
+            ]),
+            BlsScalar::from_raw([
+                0x4cdcfa0fb390ea65,
+                0xffa2cfcbb64ca56d,
+                0x6e870dd6f278bae1,
+                0x120a54568c366964,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0x0cb2050058618cf3,
+                0x9e8eacc6eaec84e7,
+                0x3a623f7b4927ea8b,
+                0x5c04fb190826f2c4,
+            ]),
+            BlsScalar::from_raw([
+                0xf4b633c64622faa5,
+                0x9aeba1cf6db2c547,
+                0x3ee41cc1d9710d89,
+                0x6193d76c552fa2b7,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0xbd5fd1e9219ec531,
+                0xa1e65965cacb65dd,
+                0x866da36a2483210e,
+                0x247e39c4befa9263,
+            ]),
+            BlsScalar::from_raw([
+                0xf3b953b148bd62d1,
+                0x1f192911bbea4ceb,
+                0x2abe79f6d597ed15,
+                0x6332c7decb0a3db5,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0x74e15f48954bee49,
+                0xdbc599df4a23bed9,
+                0x21fca588d6d4182f,
+                0x002160769e6465d3,
+            ]),
+            BlsScalar::from_raw([
+                0x394a6b3ccfc0980a,
+                0xdddfe9a168c04a9b,
+                0x8ff99f12927785ee,
+                0x1aac94899aa2b76e,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0x7ff490adb40ba97d,
+                0x2a32b8ed8d642f73,
+                0xd7a2c9554d1e9a18,
+                0x5be1df023c2e9ebc,
+            ]),
+            BlsScalar::from_raw([
+                0xcf055597585b43c9,
+                0x162678c9a2a59420,
+                0x22950cabc4638536,
+                0x69626e011cd0ef5d,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0xf7d457cd47928d7d,
+                0x3536e4483edc880b,
+                0xc90545deb13d03ae,
+                0x4ed30131f493d0c0,
+            ]),
+            BlsScalar::from_raw([
+                0xf824758eb7f3e7c0,
+                0xf891212b51e9da5f,
+                0x9fa61985dce763a4,
+                0x28dbd377c5faa8a8,
+            ]),
+        )
+        .to_niels(),
+    ],
+    [
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0x572030f31c51879f,
+                0xfa111e36bbc25f9d,
+                0x7c46d4a6744c836e,
+                0x043a4c5c8fe9ba6c,
+            ]),
+            BlsScalar::from_raw([
+                0x0372cbce40bf32f8,
+                0x220d49a34e07cac5,
+                0x6585af5fcc34b3ee,
+                0x1ba873886ec93657,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0x84280933760f2e68,
+                0xd186f209504d0262,
+                0xc8101c3edd547fa8,
+                0x3862007078ee358f,
+            ]),
+            BlsScalar::from_raw([
+                0xafefe986b3d070c0,
+                0xdb73b3a3fdf013aa,
+                0xa2182d6464293b40,
+                0x3d2506f723bef3dc,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0x644dd7f998f1815a,
+                0x396b3735e5fa21b4,
+                0x29006e7d4252141e,
+                0x21b4dbe7c0afc8ca,
+            ]),
+            BlsScalar::from_raw([
+                0x3a5e134a4b271e40,
+                0x6d9f271470d3516b,
+                0xb28134dc58963662,
+                0x58a2f9d620f73678,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0x1afa459d63222512,
+                0xb1feae47218e8b7f,
+                0x77004e007e24fa1b,
+                0x09d1fe87702cc608,
+            ]),
+            BlsScalar::from_raw([
+                0x7f5da2af75020e9b,
+                0xe1019d1b236a669a,
+                0x8cb9498c11177505,
+                0x639bdb1f31576880,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0x81015693517a16d2,
+                0x4255256f6a0784cf,
+                0xcd53c5437f731289,
+                0x495e3624cf9bddd5,
+            ]),
+            BlsScalar::from_raw([
+                0xb278d7750bf0cce4,
+                0xaa8f7715a4b54ca0,
+                0x175c907f0eb5490e,
+                0x73405a26a9fa4554,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0xf21f8e445c35fc7b,
+                0xad9cf48b55b77d37,
+                0x242bdab141728301,
+                0x00efc98f0f6abbef,
+            ]),
+            BlsScalar::from_raw([
+                0x667ad099271797e9,
+                0x43c89118041013a1,
+                0xdf3599e2ec9d5478,
+                0x311bb80c72b0e7a9,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0x312b3c5a429e905f,
+                0xc8e0f9b1b11e2eaf,
+                0x4687a213da6908c8,
+                0x3ed90eed41a14675,
+            ]),
+            BlsScalar::from_raw([
+                0xdc0994b8b8ac6ecb,
+                0x67bd83235ce6d40c,
+                0x736c7a4b7e6ae40a,
+                0x6f1d10526a620824,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0x6b5be4384a15371b,
+                0xe1b1b191cfdf0140,
+                0x48b25bbba06815d2,
+                0x297cfd30d8a4c09a,
+            ]),
+            BlsScalar::from_raw([
+                0x007018f22e9ff2a9,
+                0x3c1f4b772bd1abd9,
+                0xc4d1af8d283cd458,
+                0x261633fa57e883ed,
+            ]),
+        )
+        .to_niels(),
+    ],
+    [
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0x5732946513c9b409,
+                0x6f6877cd96d7e108,
+                0x77089ede78e384f5,
+                0x2d7c464dea5c00eb,
+            ]),
+            BlsScalar::from_raw([
+                0x036e0a0866a09e19,
+                0xcdc05de9181b5796,
+                0x12d01f3104682ade,
+                0x6938fbd8c898c713,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0xdf8045553ee99693,
+                0x9c06e2dcf467f695,
+                0xc3bad48e948a914f,
+                0x2244f2a712079ebd,
+            ]),
+            BlsScalar::from_raw([
+                0x5a409a11fda8f5b1,
+                0x60cb7027fdc241a1,
+                0xde177306f7d15a36,
+                0x0c2b5b61206b0253,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0x951e6f905bb6a785,
+                0xf2e7214b5ed9e9fb,
+                0xee52e0f7f069312b,
+                0x36a8295300de4035,
+            ]),
+            BlsScalar::from_raw([
+                0x6a1fcbe50ff7a6de,
+                0xbd303a55a5a0edaf,
+                0x2a02c6054617d373,
+                0x644b3a0df81fe0bf,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0x65740c99d512b802,
+                0xc18e23acb0f13972,
+                0x9521a1e5e3415ef3,
+                0x2a92467066b97f66,
+            ]),
+            BlsScalar::from_raw([
+                0x38e6c0b5d2413cbb,
+                0xa55c3a7a37eafc26,
+                0x93205adc7d04d60a,
+                0x43fe3c555e555714,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0xb1f4a809bb20485c,
+                0xa33d84bf400150d6,
+                0x0e34ffae549c11c1,
+                0x337c3c1ff92d8274,
+            ]),
+            BlsScalar::from_raw([
+                0x93ac89665b41a972,
+                0x9c2cbaed7cae48e5,
+                0xd77d10ba02949bd8,
+                0x06e03953ae03a5aa,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0x275e843928090fd2,
+                0x92d39b7babae9d4b,
+                0xe14171d0be9f6fa2,
+                0x33dd9ef785a97274,
+            ]),
+            BlsScalar::from_raw([
+                0x3135a7b2c9db1367,
+                0xd8ee7cc6d53a1bcd,
+                0x7bacfa978c6192eb,
+                0x3a37ee258263b2b7,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0x361275550cb5053a,
+                0xe42634b6a20240a8,
+                0xc1a572ba3af23b7a,
+                0x5b64ce907c11adb0,
+            ]),
+            BlsScalar::from_raw([
+                0x05fdf81bb6b6a82a,
+                0x8089d4daf66dd678,
+                0x23cb09b744bfbfaa,
+                0x1edd5cbdf9f308af,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0x0f76310268ff203a,
+                0xb615a000095968d6,
+                0x9e5468354a48d030,
+                0x0b176d418c97dc0f,
+            ]),
+            BlsScalar::from_raw([
+                0xbf3368a9f41962fe,
+                0xd3f5329f42eeb3e8,
+                0xcdfdb1a1473ec017,
+                0x04494cba81ce67ab,
+            ]),
+        )
+        .to_niels(),
+    ],
+    [
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0xa246abe49ef8e9d5,
+                0x22f973ed4a2601a2,
+                0x91a73f1a8634510b,
+                0x33a215f3782ecc85,
+            ]),
+            BlsScalar::from_raw([
+                0xa3a6469c1def2f9e,
+                0xae5bfd28834cb469,
+                0xc3ad4b25eead6813,
+                0x3680d7e9774690da,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0xc17cf296c91cc232,
+                0xf0ec66d4dcec2574,
+                0x762bc993f41216fb,
+                0x623334235c112c78,
+            ]),
+            BlsScalar::from_raw([
+                0x4ba61d8852846ac0,
+                0xc4826ee1e05182e8,
+                0x3de897d4731fc0e3,
+                0x31e393ed415e4d4e,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0x3bfc831f0714737a,
+                0x1bba91faff14e184,
+                0xd4cd61789b58540c,
+                0x25fe5b801553b457,
+            ]),
+            BlsScalar::from_raw([
+                0x60f4d95caddf1fda,
+                0xbeb58c57c142105c,
+                0x459f9aacbc91fb24,
+                0x0ce29300634e8414,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0xb2805f87381ac10d,
+                0x5e3a11b5286b6249,
+                0x8cc5ad80275fc790,
+                0x44c71ca8c94d6187,
+            ]),
+            BlsScalar::from_raw([
+                0x6286b0a44149e6c1,
+                0x6b742ac538073138,
+                0xd8738ed1746bfdd4,
+                0x2eaf437fbbf3424f,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0x81474bae9ec3fe78,
+                0xece01872540dfef6,
+                0xd7511a52ab049710,
+                0x338e3089ebfca945,
+            ]),
+            BlsScalar::from_raw([
+                0x688e1accc8bc82be,
+                0x0a41329d67d65c1c,
+                0x92e829df6518d47b,
+                0x0848125cb055d730,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0xc8dd1edfc649eb3a,
+                0x371bbc4e284d0327,
+                0x9a62ffc73c7b650e,
+                0x598578f8062c292c,
+            ]),
+            BlsScalar::from_raw([
+                0x1e20901194309257,
+                0x33aa55795c5191fe,
+                0xa4da9e32e38a6c5d,
+                0x6398dc43aac68422,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0xb893c7197778a58b,
+                0x7fd6d6c166bc031c,
+                0x10e6f365c70ea55f,
+                0x72753f6834e8fb9c,
+            ]),
+            BlsScalar::from_raw([
+                0xf07c95bea8fb1efa,
+                0xf8944c6db2e72cbd,
+                0x14b59c357b02ffb7,
+                0x4cd23c316186835d,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0x83ef2ae529c55165,
+                0x131830048393acd6,
+                0xbf81bca0f1813d1e,
+                0x5ffa9cf9e735ddc2,
+            ]),
+            BlsScalar::from_raw([
+                0x0212e36766b27c8e,
+                0x1ac981df320ff0da,
+                0x3365c7b044462589,
+                0x64d95f5cd0a4b904,
+            ]),
+        )
+        .to_niels(),
+    ],
+    [
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0x7a936bde3fe9b4e7,
+                0x8695cc959f327d51,
+                0x7d819d35097e7d8b,
+                0x0cbcaf497b3fa268,
+            ]),
+            BlsScalar::from_raw([
+                0xfd29cd87747a3e73,
+                0xb3abcda724b82f20,
+                0xd8cec79cf66507d3,
+                0x0260909b61900879,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0x36da31ca85b7d3bd,
+                0xfc0a44ad5151cf45,
+                0x3360d4f66fbd1198,
+                0x08eca409fc123f13,
+            ]),
+            BlsScalar::from_raw([
+                0x9217a8c5d2e0e1a4,
+                0x7c2b8108f61ca257,
+                0xed1a480396e7da82,
+                0x6fd71bfb92d27ba4,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0x992294fe70e7fe2d,
+                0x7f6bfc15889c3d38,
+                0xf952c29a8e46c88e,
+                0x663bfedb0f6fd0e0,
+            ]),
+            BlsScalar::from_raw([
+                0xdf48bf1ac2e3581c,
+                0xab06fe9c04fe8e9a,
+                0x97413ff18e7979c4,
+                0x6ef77c5b6d19b0a1,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0x8fffbcc3665f3877,
+                0x6d4bc02e804192c0,
+                0x0a0d66206ed5aa74,
+                0x08b3fcabf1022395,
+            ]),
+            BlsScalar::from_raw([
+                0x4c4db6cb083ed208,
+                0x72848ac4ec671fc4,
+                0x11ae6f65bd2dfd1a,
+                0x254aaf226b429a7b,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0xaf4a166e7ea42514,
+                0xc1c77e426dc09cb3,
+                0xa0c70fe6382cc8da,
+                0x5f4dfdfb46ee2bfe,
+            ]),
+            BlsScalar::from_raw([
+                0xd0484db3922496ed,
+                0xa09c17e9c8bbf14a,
+                0x8f330132f27cad77,
+                0x64f4c5a8c54ca7b5,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0x357b337078b3db68,
+                0xedbbad504bd2fd13,
+                0xbcf11fbbe225c549,
+                0x317e4d640a2a5d4b,
+            ]),
+            BlsScalar::from_raw([
+                0xcedbcd3d0c9d82b5,
+                0x22799f13fe5550b8,
+                0x36e04c5e0c1df6bd,
+                0x2fc611542060ae15,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0x06d3e4c0aa3953ab,
+                0xfd7f37f9535cd765,
+                0x4e76c3615be7f11e,
+                0x6f93ea3cb115df3d,
+            ]),
+            BlsScalar::from_raw([
+                0x3deeb9dbb3379398,
+                0x5631f9228aefdf45,
+                0x1b67c11e5e905d01,
+                0x6a4aebe6d3834cb5,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0x7e91c1c4be68522d,
+                0x04629f4ee8734ff2,
+                0xf46229ae988436d0,
+                0x11c6d9c6f6b4f51c,
+            ]),
+            BlsScalar::from_raw([
+                0xe14473909f3b40f2,
+                0x7d3fa71f6386788a,
+                0x5093768f82a72523,
+                0x3cb5b4e42223be95,
+            ]),
+        )
+        .to_niels(),
+    ],
+    [
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0xc8e0fdc2e4a8e2e4,
+                0xd105d94672160c55,
+                0xc5ec1215f6cba56c,
+                0x2297bac78a6f14e5,
+            ]),
+            BlsScalar::from_raw([
+                0x1a9a3ef60030021d,
+                0x4cb945959a8c8257,
+                0x1cb38239e40ffb74,
+                0x4dff1bc7c5ac4667,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0xc7e2b5531a1002fa,
+                0x96d7affa631c40b2,
+                0xd123091d64d2cbaa,
+                0x2ca7bee880332f8a,
+            ]),
+            BlsScalar::from_raw([
+                0x7f7a166371b09979,
+                0xdce9eec9154ede16,
+                0x7c229ea309c96f0d,
+                0x0bf4818e76f55e8e,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0x534beaf1d1665825,
+                0xf4715755acd6dd94,
+                0xf5ba0f2a19e3be26,
+                0x0a9c39295210a97e,
+            ]),
+            BlsScalar::from_raw([
+                0x659c71235bb84c58,
+                0xafe55a511517c895,
+                0xfb9d9847516dd9f6,
+                0x23c5506470191340,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0xec911674c313b1ff,
+                0xf907217f9d921bda,
+                0x6650c02f213660f1,
+                0x2d7492a4041365fa,
+            ]),
+            BlsScalar::from_raw([
+                0xcad840e73ba96c74,
+                0xf86f0356c27aa256,
+                0x7ee559c557af0317,
+                0x0e64e1110948d18a,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0x9b3974faa15b3683,
+                0x30b93bc23f807f10,
+                0x8ef5bed23cc7aa78,
+                0x5782a71aa856ff98,
+            ]),
+            BlsScalar::from_raw([
+                0x0d4bb44fdfc50e90,
+                0x1592e225d334c0a4,
+                0xa570e93d99a16e02,
+                0x6c4b615253b32305,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0x8777348381f23c07,
+                0x500f5d6b6ab72b48,
+                0xb6832f9da841597d,
+                0x0b9149c1300c519e,
+            ]),
+            BlsScalar::from_raw([
+                0xbb07904b203ce026,
+                0x1407c3185d275783,
+                0x7ed96deaca2f8fbd,
+                0x08a6367f0e27d225,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0x23a06f53beb6c950,
+                0xa4258d3ef39753c1,
+                0x02f6a71284a4dc2d,
+                0x56dedc98818bd522,
+            ]),
+            BlsScalar::from_raw([
+                0xd0c88ba890e78f99,
+                0x41d423f792eea878,
+                0x07deeb0e0957124c,
+                0x702025038cc1cbc5,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0x8bf0991cc78aafb3,
+                0x0fb3f4350d33541f,
+                0xf99d10fc21ce1079,
+                0x45c90408bb8165f7,
+            ]),
+            BlsScalar::from_raw([
+                0x280f7ba99011037c,
+                0x0dfb8b32ba1e5dab,
+                0x9c644b2d6e82ad0c,
+                0x6f62b72de1ef457e,
+            ]),
+        )
+        .to_niels(),
+    ],
+    [
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0x1216020a6521a7c3,
+                0xa92ae04d673fbea1,
+                0x9b7027fa5d7c39dc,
+                0x4ea40cf2b7d73847,
+            ]),
+            BlsScalar::from_raw([
+                0xbf1a128ceb17c400,
+                0xbec2c382a5d2a3b5,
+                0x47cf2766143107c5,
+                0x0781abddc7b65b17,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0x34d9551ab6f7f562,
+                0x8b09adf1e710eed0,
+                0xc65329e68f0e85f7,
+                0x6938ea4eb138eb12,
+            ]),
+            BlsScalar::from_raw([
+                0x6264973bc006201d,
+                0x35ebfa7677e52b7f,
+                0x315bdb29ae95803b,
+                0x4962da8e88648320,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0x3893a7997cbbacd1,
+                0x8ee973c559121a0b,
+                0x1385cdc70428cc74,
+                0x1512ca70bcf0d57e,
+            ]),
+            BlsScalar::from_raw([
+                0xaf1afbbeeb284caa,
+                0xceabaefebc58dce3,
+                0xe0a58f1110c45490,
+                0x266f0deef339c89f,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0xa9f0e14bf1052b61,
+                0x44f1e4f0ff138f12,
+                0x1da2e0ea6ba3c80f,
+                0x200c284d981d30ec,
+            ]),
+            BlsScalar::from_raw([
+                0x681f10b22d7fd1b5,
+                0xd67358dbc9abd454,
+                0xceaa2bdb17a63b19,
+                0x1484276ef0911304,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0x233c5f953cfe33bc,
+                0x3fad0bad3db58dbb,
+                0xb96532c1ac408b53,
+                0x1587d17b12193416,
+            ]),
+            BlsScalar::from_raw([
+                0xbc9f2adbb317f876,
+                0x78a3fbd2ce701067,
+                0xb773a0252649c8e9,
+                0x1842ed3ad3262500,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0xefb5f765e0ef2fc7,
+                0x42c0cd379124cde3,
+                0x5918a5588cfa0d2b,
+                0x4b65e585922f0ed8,
+            ]),
+            BlsScalar::from_raw([
+                0x891e11034d90755b,
+                0x287682fc2b962f5c,
+                0xa12bcc80fec69de0,
+                0x14fbacd45b6c0391,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0x0019fb04d71e06da,
+                0x0ac99aa7e7157014,
+                0x5e0d0afdf26601af,
+                0x3ca49be424561174,
+            ]),
+            BlsScalar::from_raw([
+                0xc6a41d8ca942e8fb,
+                0x107ee5003d5859f4,
+                0xba2f41eb2793a828,
+                0x3614988fdc194514,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0x7f6f8115f0a1999e,
+                0x03884ece94b73cc6,
+                0xa2ab6d58efd62fdb,
+                0x6417b4bc274cdd95,
+            ]),
+            BlsScalar::from_raw([
+                0x4d955e1ec8afd2dd,
+                0xcb813c3bf740b98e,
+                0xdd2430ccd139db9d,
+                0x68fdb11f1f656260,
+            ]),
+        )
+        .to_niels(),
+    ],
+    [
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0xe1d5f7cebb24ae02,
+                0xbaee9a21949dc8c5,
+                0x68267f4f8ecb12f8,
+                0x28ba7e39587ffb64,
+            ]),
+            BlsScalar::from_raw([
+                0xdbae09da2ab253bd,
+                0xefb556cfa29aea2c,
+                0x0e71fe4fd53d8b81,
+                0x016f5ec12949889b,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0x8cf8b8e9e7c762f5,
+                0xee06f57a2a4b69e3,
+                0x76d01caca7c52368,
+                0x4eecf924e10ce3a6,
+            ]),
+            BlsScalar::from_raw([
+                0x6ffb518a6b90b5c8,
+                0xde28065bdd2c537d,
+                0xd659498d3ebb1709,
+                0x57f66928fd6cabdf,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0x4844f8eba88485c9,
+                0xa714bd493f27769b,
+                0xccdbe803f473d3c9,
+                0x13234d49efca5092,
+            ]),
+            BlsScalar::from_raw([
+                0xa2b82f3e0264720d,
+                0xf171805d1aeb72b2,
+                0x48bbc3d47b80d2fe,
+                0x59f87cadd0da15f0,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0x9080f5123d7069d1,
+                0xfee0f15e5abee103,
+                0xe8c783b459a759a8,
+                0x34d8ad0346d0b78a,
+            ]),
+            BlsScalar::from_raw([
+                0x50533112f228a6af,
+                0x79f5de91a70c9596,
+                0x5631bef200ea7a58,
+                0x5bc43fcea95a0552,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0x04dfb8bece066c18,
+                0x8c87cd21fe20cfd6,
+                0x82237adad1cc6763,
+                0x06033fd87c6bd70c,
+            ]),
+            BlsScalar::from_raw([
+                0xc139329ba172ef25,
+                0xe9411030dab2f8c0,
+                0x8640f5c89e70546d,
+                0x5668afc78df3bbd7,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0x965ff53bedc78896,
+                0xc793c7391124c3d1,
+                0x717ecc802556c611,
+                0x422186f1ed100707,
+            ]),
+            BlsScalar::from_raw([
+                0xe852a1a0c99341f4,
+                0x00e439c37ef54bdd,
+                0x324236bf7e07d255,
+                0x2e8922eeb769f030,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0x0c207fdf1e23ae55,
+                0xb5f3e91ffcd58288,
+                0xbd7b5df46fe9d895,
+                0x2dbc3a85f5946c88,
+            ]),
+            BlsScalar::from_raw([
+                0xf6edf20ad4ef793f,
+                0x55b82502f2a013d4,
+                0x8edb85c08bcb0832,
+                0x59b9ad4bf32d0cdf,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0xec0bb8e02ec3bbfe,
+                0x5176666f1ee6bcb6,
+                0x8e2774999d9738d1,
+                0x39f51246500e2292,
+            ]),
+            BlsScalar::from_raw([
+                0x5fffe4d8dca2a6fc,
+                0x675ae216742c37fe,
+                0x70faa7a025af1f2f,
+                0x6bf6b5112f38ea84,
+            ]),
+        )
+        .to_niels(),
+    ],
+    [
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0x8a85362dd9464d6b,
+                0x08694ed0c0ac7a36,
+                0x856c7ac7f4a0f56b,
+                0x1a1a60ac726f3313,
+            ]),
+            BlsScalar::from_raw([
+                0x15574d2be2f3889e,
+                0xbf1b50570cc49e0b,
+                0xa7a6c741867969c0,
+                0x55ff86e93182b08b,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0x30311adf72adb7fe,
+                0xb470ce39dad9ada4,
+                0xd250cf9d496d40fe,
+                0x4985ba83c18012c2,
+            ]),
+            BlsScalar::from_raw([
+                0x8c0379a33d7715af,
+                0xa8bb74360dd7bc07,
+                0xcb8c6a013a92d812,
+                0x42ec3180e55359ab,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0x7ff32f1c7751513d,
+                0xf8bb3a8d684a495f,
+                0x04be71433d4febe0,
+                0x159e876f3ae92510,
+            ]),
+            BlsScalar::from_raw([
+                0x2b233e5b0eda71dd,
+                0xeac98359c7a355de,
+                0x8c273e77549a5556,
+                0x08e6f9580cc84660,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0x399c6282d04b6b56,
+                0x666f6e4f4e6a30e2,
+                0xee20d3fa20cb8f17,
+                0x559b2e1e482c9bbe,
+            ]),
+            BlsScalar::from_raw([
+                0x99f893e56936cfd2,
+                0x565963c3a7e3bb49,
+                0x535a292ded3eb2de,
+                0x34a9de3c86bae296,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0x647a81b7c354862b,
+                0x2f33a7c7fbfbe140,
+                0x7d2d615dd623f8a1,
+                0x2ec45f330bf68f63,
+            ]),
+            BlsScalar::from_raw([
+                0x1f1c28f606430449,
+                0xb619601e884fb3ab,
+                0xe817c221e377ada8,
+                0x5958dd177bf4956e,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0x997ecbdbbb881bfc,
+                0x8b26c505e3d0bf41,
+                0xc09492991edb19d0,
+                0x49884822146e3877,
+            ]),
+            BlsScalar::from_raw([
+                0x352e2d2cc042f8d5,
+                0xdc591af883bd8dd4,
+                0x7646ca79fa022814,
+                0x392997b13d326f04,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0x169e82355321b430,
+                0x1149a37a536c8586,
+                0xb52b5b2c8f9c9ee0,
+                0x4ae4b7e55c1957ef,
+            ]),
+            BlsScalar::from_raw([
+                0xbb9796081cc6dcc5,
+                0xcbafd7d75e2684a9,
+                0xb2ef3a3e819ead5b,
+                0x05b1c6e5d567fa17,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0x4e50c34f053f28fe,
+                0xd500063fe92f6e52,
+                0x4285277c87fef5bc,
+                0x19fa58a1452d77c8,
+            ]),
+            BlsScalar::from_raw([
+                0x7a5cf0f5b28a30dd,
+                0x22fd9e158d015e6c,
+                0x872c52e32bb9ba1c,
+                0x22ee8e59b1440e06,
+            ]),
+        )
+        .to_niels(),
+    ],
+    [
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0x6890b99a4b341007,
+                0xce35b116daf25201,
+                0x2c7f584946938afb,
+                0x22dab2885662d0a6,
+            ]),
+            BlsScalar::from_raw([
+                0x7cf6d6b535c03682,
+                0x47a560e0d0f81366,
+                0x3db73ea31504d9e9,
+                0x024527be29e8a682,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0x366e3117b2d73d2c,
+                0xfeae0139882b184e,
+                0x2cd37b947727455b,
+                0x20ee6895789ca5f4,
+            ]),
+            BlsScalar::from_raw([
+                0x3ef5fac6c6ad06a7,
+                0xa2d7ce23b2eb0b37,
+                0xfa793fe253ca4f38,
+                0x2c24773f6157c3ea,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0x128e82e41a23c410,
+                0x245d98faab2de371,
+                0xd2ef87a1208a0d70,
+                0x5498a62d11050ba0,
+            ]),
+            BlsScalar::from_raw([
+                0x2060c7a5e07804ce,
+                0xe12e540aa5d7e426,
+                0x92b41fb6c572ef10,
+                0x0833232f8c7d4c02,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0xc62ddcdf35b742ee,
+                0x17fe1fbd591983ee,
+                0xa188ebfc04567935,
+                0x668c3027d8ce9630,
+            ]),
+            BlsScalar::from_raw([
+                0x9d62f6b45ef76b67,
+                0x4d3dd07608dd6aaf,
+                0x7de4f6ac3ee8ee20,
+                0x6a382313238f976c,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0xc46272c6f5368e94,
+                0x108de2df2f31b9d2,
+                0x7f4a754606352d36,
+                0x6906f5e67ba8f119,
+            ]),
+            BlsScalar::from_raw([
+                0x7a1b4fdbb3602344,
+                0xcb9afee07230c963,
+                0xd78a1418e30fdd95,
+                0x4809733a274135f8,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0x736416d7af714918,
+                0x431a1d557fdaa517,
+                0x0643f9f1f83b8fb5,
+                0x4e067a971950f689,
+            ]),
+            BlsScalar::from_raw([
+                0xd998817cbd62564e,
+                0xf0e71bb1ba87c272,
+                0x138a2f12f82e6f3f,
+                0x4648d99140243c09,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0x7ecc966af2d60c57,
+                0x9320838fc0876281,
+                0x5ec62d9abaa3ddd4,
+                0x59e54e5783cb1f2b,
+            ]),
+            BlsScalar::from_raw([
+                0x8f006a95d405eacc,
+                0x9496418712fb3094,
+                0xb4d1e31343250b20,
+                0x730a36fea95d809d,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0x04cd26b6ab27240d,
+                0x63702a9158541f9d,
+                0x17ca7a8695c934df,
+                0x6912d199bb5fcd60,
+            ]),
+            BlsScalar::from_raw([
+                0x32e0d5e5a39c9ff0,
+                0xf79cf08c00dc886a,
+                0x644cc1d2af40a7d3,
+                0x1456e20575010cb0,
+            ]),
+        )
+        .to_niels(),
+    ],
+    [
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0x6f84121951fd9c27,
+                0x332fc94a868b4216,
+                0x4a4e7d45ca4599c5,
+                0x3eaa47ebf3b44f92,
+            ]),
+            BlsScalar::from_raw([
+                0x06ce2d45f7ef5526,
+                0x933519030b3fa56f,
+                0xd662c026906d2bb4,
+                0x1664d641ba6c85c6,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0x112e4c341b448492,
+                0x767595f70b45a1a6,
+                0x1b9916905cafe9b4,
+                0x2f183f711ce0d250,
+            ]),
+            BlsScalar::from_raw([
+                0xacfd769e8d18309f,
+                0xdf606cc10610bd38,
+                0x51a0478d6f55c8d2,
+                0x1f7b862dfa34bb1b,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0xe0fc2ad7d531251e,
+                0x092a3e7fb0c438c2,
+                0x938fb020315a61c2,
+                0x0adff0b52b79a5a0,
+            ]),
+            BlsScalar::from_raw([
+                0x370755a3ae9dd5c0,
+                0x190e2cfd94dafc0a,
+                0xf40a5dbe1efdc57b,
+                0x089db2ac374fce79,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0x1f9527fcd32933f9,
+                0x36bf1ded5c2071bf,
+                0xcf77fef9a7ad111f,
+                0x0e1da7e400c81f63,
+            ]),
+            BlsScalar::from_raw([
+                0x3be8c4b9ecbafdc0,
+                0x98f948170a6d61bf,
+                0xd5f3b344169f8b18,
+                0x512792a05e3dd3e7,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0x1cf6f671979cb3eb,
+                0x07a5adfae3a72bd1,
+                0x386d4d285f256166,
+                0x0917e48924333d90,
+            ]),
+            BlsScalar::from_raw([
+                0xc6c1ee0ec48f7db2,
+                0x9b196ebcf7cae543,
+                0x6ded9fcdcdf9e6b8,
+                0x6fda1f40b1cf80a3,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0xea162b7c362870b5,
+                0x34290069d6320f5a,
+                0x843539ff24753a73,
+                0x157190f2e619fb10,
+            ]),
+            BlsScalar::from_raw([
+                0x52f214f868004dbe,
+                0xc993cb327cadd38b,
+                0x7744e4974208f5b2,
+                0x5a356c7beec15afe,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0x656d31759e955693,
+                0xbf3d6faa5592608a,
+                0x18ea9d1ef92b61af,
+                0x0d012fdfb7a75b31,
+            ]),
+            BlsScalar::from_raw([
+                0x32266cce9f96616e,
+                0xf3f7943212ffda63,
+                0xcef0eab9f213e58b,
+                0x00b76776736330d9,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0xafda238ee6b39126,
+                0x63f27e491690344f,
+                0xc20074ec86c9cd36,
+                0x5fea025f3aa01e6e,
+            ]),
+            BlsScalar::from_raw([
+                0x65510892c1145ab7,
+                0x5172e47806b0cde0,
+                0x913878470e8b5ed2,
+                0x405993b98046ef49,
+            ]),
+        )
+        .to_niels(),
+    ],
+    [
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0x2af351193fbd0fbc,
+                0x3dd9c8125fbfe6b5,
+                0xabd4b24127b55bc8,
+                0x2b2928d04ccb29b6,
+            ]),
+            BlsScalar::from_raw([
+                0x884d9499be22f9d2,
+                0x78dbd7abf724e7af,
+                0x548616647fe1d14d,
+                0x294e12142a027531,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0x01eca496c3498180,
+                0x3bffec67725d2340,
+                0x7a6332cde8c7825f,
+                0x41bf6f0fe2d4663b,
+            ]),
+            BlsScalar::from_raw([
+                0x8849d67300c63a57,
+                0x390df14a742de38b,
+                0x98deaf5724c65a88,
+                0x05779315d7cf1714,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0xad4b2db50b5393cd,
+                0x639dddbd0f589c68,
+                0xd68208c2516d597d,
+                0x4f7f40b9de782918,
+            ]),
+            BlsScalar::from_raw([
+                0x272f261e913d2710,
+                0x498974d0f53f8f46,
+                0x9623fae04fba1af2,
+                0x41504deb13d1637b,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0x9d73cf2cc1f81d59,
+                0xfbfe119e636a5945,
+                0x025af292af803e81,
+                0x0c8b184b7ce37072,
+            ]),
+            BlsScalar::from_raw([
+                0x14b4a143a4fe3dd1,
+                0xae3d8080a3f6b0c4,
+                0x1b927fdadf1ca19d,
+                0x2ae28e2ebbb7a324,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0xe796b839537b4692,
+                0x42ebe88d89c5b6c5,
+                0x1b99f37fd20ebe0d,
+                0x3c66ade1a97777ed,
+            ]),
+            BlsScalar::from_raw([
+                0x0574002f476759dd,
+                0xab9c918b05854573,
+                0x72cc0c4d6815365a,
+                0x147170ec514481bb,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0xf917199f80033513,
+                0xf1b07de9a96e0bfd,
+                0xb7e3326ac8aeb565,
+                0x537397007669e4d9,
+            ]),
+            BlsScalar::from_raw([
+                0x21fa8a6d7686e668,
+                0x8a07c250e0b41d6c,
+                0x9f73dad97110bec5,
+                0x19a436a62f43c104,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0xa2ad5c132a96ce25,
+                0x97b7bb984654772c,
+                0xe109251b78d356a6,
+                0x1c351fd1bf77d588,
+            ]),
+            BlsScalar::from_raw([
+                0x8fb379415e0257b3,
+                0xd86b8c4efeadd567,
+                0xb29d0eb14e067d1f,
+                0x4a258df7e97527d1,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0x7a8911e3a6146676,
+                0x34ff6448525e598d,
+                0x98a9fabc78fb771e,
+                0x4c863bd5c584e285,
+            ]),
+            BlsScalar::from_raw([
+                0x942f8089cb1d3d87,
+                0xbbda33e5e2568ddd,
+                0x9198f56282b74325,
+                0x1f9c082ceb541053,
+            ]),
+        )
+        .to_niels(),
+    ],
+    [
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0xb4f5618c2343811d,
+                0xabfbbc0fc30e5b54,
+                0xd736005fff6f3e32,
+                0x673adc9471d2ddfd,
+            ]),
+            BlsScalar::from_raw([
+                0x71997270aaa7e8be,
+                0xe9abbd0844c00c46,
+                0xad98b5583580cc9e,
+                0x24551d3e5f5551e4,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0x871e3d7d27395187,
+                0xcb76aa60d862977d,
+                0xb117f1c04ac649e3,
+                0x57c5396a6ce4120b,
+            ]),
+            BlsScalar::from_raw([
+                0x6782716de76a3de2,
+                0x8c34294771f81c3b,
+                0x7acac0becce94930,
+                0x68b2d124bb0dae53,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0x4a8f37322782127b,
+                0xae5da93f08510d44,
+                0x695f98b63a574d33,
+                0x5e8a1f27cd3cd429,
+            ]),
+            BlsScalar::from_raw([
+                0x50dfc65a5a00cef2,
+                0x953639efe00200c5,
+                0x1d4756a9b9e056fc,
+                0x0460c0292def6430,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0xae862ceedd7e97ca,
+                0x57683e07f67da44c,
+                0x7e2fc32a2ba0c658,
+                0x68dea610de294632,
+            ]),
+            BlsScalar::from_raw([
+                0xf2b5b763551b7a7f,
+                0xe0ec110d1fb4ca06,
+                0x8b6f73c364a305ed,
+                0x349972abffa89401,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0x4f5721bbce28184c,
+                0xb8bf325ed940b027,
+                0x392dca2e6de29018,
+                0x6e0631706fbb9b57,
+            ]),
+            BlsScalar::from_raw([
+                0x123a3eeae422cfb7,
+                0x88a0dcdb5b470fec,
+                0xfa9e5137562d3310,
+                0x2fc385f14e17df43,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0x9deff9ad95e7f45e,
+                0x99eefd13dcce3f89,
+                0xb29ca8e930ba76b6,
+                0x64b71d0973016421,
+            ]),
+            BlsScalar::from_raw([
+                0x3f24a88fce06234e,
+                0xe561c6ac5f8c998d,
+                0x2a893f221ab8013f,
+                0x51276de0d4df0812,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0xc5fba41f514f62cc,
+                0x743e16df7a8b6fda,
+                0x3f02973b7db15c3d,
+                0x33ef2581f9e207d6,
+            ]),
+            BlsScalar::from_raw([
+                0x552b487cde6a33b0,
+                0xacbd441d9bfbb201,
+                0xf8bd43c0fd0c48f1,
+                0x5ca535f43ce50e1d,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0xc5dd4c409b0f4e3f,
+                0x8af34cbb591f4fe4,
+                0x6a7d51fe3ab0b400,
+                0x1a1f0beb8e3ce1d3,
+            ]),
+            BlsScalar::from_raw([
+                0x86bdad5a7a371f55,
+                0x0bdda682c780652c,
+                0x06bef09b5dc98ba7,
+                0x66eedf806de8ac27,
+            ]),
+        )
+        .to_niels(),
+    ],
+    [
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0xcf9a26158abef08e,
+                0xfbe48fa12e036376,
+                0xc9677aba1d3c6fe8,
+                0x7056e27785b24bc2,
+            ]),
+            BlsScalar::from_raw([
+                0x56a144794ab22692,
+                0x0bd82902d0093617,
+                0xcd9faa3c9bdd7005,
+                0x2c2b5003b204dc81,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0x135fa82de90e578e,
+                0xc2cd08437af8bbee,
+                0x49adac998d7be55a,
+                0x59ee4a0bc9ddf321,
+            ]),
+            BlsScalar::from_raw([
+                0x91e203f42ee6af58,
+                0xd2a79fc010e3d19a,
+                0x1f3599d7682b5930,
+                0x3961ac47a8aa95e6,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0x5988f284420e4407,
+                0x43cc36ac462e62f2,
+                0xef13f46e0c46f938,
+                0x3af3e07ef5ba8b3c,
+            ]),
+            BlsScalar::from_raw([
+                0x393f3178cde49ded,
+                0x136281e404902b11,
+                0x5d0736af1da186b8,
+                0x5c3dcea1f7c02976,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0x73f77c7814385e50,
+                0x76200ecad8704173,
+                0xcb4b5d9c3d95f5ce,
+                0x45f0ce6c76470cd9,
+            ]),
+            BlsScalar::from_raw([
+                0xd62eaaccd866b60e,
+                0xea891fee1d87077e,
+                0x0adc87afc5d53ac9,
+                0x6ecff3cae9108265,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0x3ebb003b3c504a22,
+                0xaf197417cae4bb7c,
+                0xd0924910e57d038a,
+                0x15f160ca1d4a82cc,
+            ]),
+            BlsScalar::from_raw([
+                0x616fd91b4d6aa6e2,
+                0x716d2ec3956b3509,
+                0x7e11368618185449,
+                0x44a64d0bfc542cfe,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0x63a075475f3076e9,
+                0x86226045064713ff,
+                0xde75f46bb95c7f53,
+                0x359b594be52e141c,
+            ]),
+            BlsScalar::from_raw([
+                0x45d23ff3acfb34d0,
+                0x19677599cc2fd937,
+                0x63c7e6c3ef2317ca,
+                0x2c8171a744c9b11d,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0xabd2920b571107c3,
+                0x36ac604c50244e39,
+                0x3b0d8c08e5acb41d,
+                0x46190642ec396eae,
+            ]),
+            BlsScalar::from_raw([
+                0xd66a5fdec18ca5df,
+                0x604eb7f9e262c1d2,
+                0xa8fa68f2997a9603,
+                0x543da41604e5a02b,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0x87d0d74dcd5e5f96,
+                0xd4d6266b5716d77a,
+                0x9e71588219c992bc,
+                0x1c530b66d8581e8e,
+            ]),
+            BlsScalar::from_raw([
+                0xd4659de625bfb147,
+                0xe1d91fa3abe9118b,
+                0x456ccd600a516454,
+                0x13b266f14464d871,
+            ]),
+        )
+        .to_niels(),
+    ],
+    [
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0x8124c9efb22660ed,
+                0x91360e698aaf62e9,
+                0xe854de7dbe8b056e,
+                0x53925d0253aba61c,
+            ]),
+            BlsScalar::from_raw([
+                0xdc385362563db184,
+                0x9dcf647e4a0a03ed,
+                0x3b23f186ae5d648a,
+                0x31772fdd3aac69a2,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0xdfa0caecfab63e63,
+                0xb6d66d6dcd5d3dbe,
+                0x573165606b041944,
+                0x3fa58258ff36f725,
+            ]),
+            BlsScalar::from_raw([
+                0x3984d76a27352f41,
+                0xaaaa21f693d050e7,
+                0xcefcefb93420f288,
+                0x5fe33ce3e96d9983,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0x453915b652df82f6,
+                0x27f00222526a9d7d,
+                0x8d20ff3701d1f702,
+                0x05a4b8dc38dcf61a,
+            ]),
+            BlsScalar::from_raw([
+                0xd6c3253bed9e037f,
+                0xd7f1e4f791be2437,
+                0x41b825e17bcb6ed1,
+                0x2496fef8f3bf32d7,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0x5cc2b6da18e8847c,
+                0x2faf6ea9cf38a543,
+                0x65e2046a8efd8d73,
+                0x3dee14cbc20c175c,
+            ]),
+            BlsScalar::from_raw([
+                0x5c9992708e1a13ac,
+                0x36cdd4cdbead2d9c,
+                0x0089b00073d2ef50,
+                0x191700bcd864b715,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0xe828f37515f8aea8,
+                0xbed44b4877c4bbb4,
+                0x61e25e95aca79205,
+                0x1252bc55ea7350e9,
+            ]),
+            BlsScalar::from_raw([
+                0x52a990c05c0464a3,
+                0x4c96bec2847cbf4e,
+                0x1536fa50a027009a,
+                0x5910a4a5af04290a,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0x556e691793be85a8,
+                0x6b1372ac1ca183a2,
+                0xac99890cf219165c,
+                0x448df86a6b2712c6,
+            ]),
+            BlsScalar::from_raw([
+                0x4efcbca619efdceb,
+                0x857a45c9481982b6,
+                0xde8ee3fa8fc789c0,
+                0x6b914b0c743bf1a7,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0xf12695c56dcb025a,
+                0xa0d1b32b0dcb3c27,
+                0x13dcf0012431216f,
+                0x5fe9763f267819ae,
+            ]),
+            BlsScalar::from_raw([
+                0x5bc3efb14f9c3faf,
+                0xe380d4995b0631e2,
+                0x0ad2952874c26f4d,
+                0x27c738f940b86bf3,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0xcf247abecd57684b,
+                0xf62d48337b37ec15,
+                0x69ea71da8cda7279,
+                0x0c48774866383dbe,
+            ]),
+            BlsScalar::from_raw([
+                0x49f1a41e3bba098d,
+                0x481c062c3626a85b,
+                0x27db023ddeb81a8d,
+                0x016f43c24f05d19d,
+            ]),
+        )
+        .to_niels(),
+    ],
+    [
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0x3c81e13134a8dfd5,
+                0x7f065ac8b6abb4a3,
+                0x2041acd1635215f1,
+                0x114e1cc8f8699f4d,
+            ]),
+            BlsScalar::from_raw([
+                0xe77bda15deb4a322,
+                0xac468390812dfea6,
+                0x544170ede88652c0,
+                0x34adbf6d9e296126,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0x55d1e784bced005b,
+                0xeff20ebde4360bfa,
+                0x3a59d8c3def66e60,
+                0x2b849f81db1f1105,
+            ]),
+            BlsScalar::from_raw([
+                0x3a269db296c9455c,
+                0xbf0215a1e2f0c389,
+                0xc1928fb4b8e24a7a,
+                0x3dec1ccc78af7043,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0x09b8eae5260c9523,
+                0x1b9663f655a120cc,
+                0x0b0816357dd96f6d,
+                0x58d3a30f5b1b8a3f,
+            ]),
+            BlsScalar::from_raw([
+                0x881da3ab7570a3e3,
+                0xdb77069c614af5ac,
+                0xbb8630581207fd37,
+                0x0d9c2c3165cf50d2,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0xcff904ac55f347f0,
+                0x51173c03201eb428,
+                0x342e8f97446a8222,
+                0x394177bba8e7b8f3,
+            ]),
+            BlsScalar::from_raw([
+                0x4eef09245583104a,
+                0x1efa24a5893cc30d,
+                0x6c006dc47ffe3ee8,
+                0x1cd1ee47f3edca6d,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0x46f2fcccdb61bbbf,
+                0x22f73e2c572f3a19,
+                0xd8ce1d974715768c,
+                0x32c0339abdc7a8b7,
+            ]),
+            BlsScalar::from_raw([
+                0x135bb3249f9157b3,
+                0x885a7a8eff3670e7,
+                0x02e297c6a392b888,
+                0x067d35599f462778,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0xfc36137fd7db6dca,
+                0xf0e4c099bd45fbbb,
+                0xd27befa519caf5dd,
+                0x0f88e994a1e1ab0f,
+            ]),
+            BlsScalar::from_raw([
+                0x1a4f98c20ac073fa,
+                0xa764a4c4145582b2,
+                0xed18f98f2921859a,
+                0x1f0091a6491a0f82,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0x9ca88be0eeca880d,
+                0xfcad8cbad2e461b3,
+                0xccdaa0feb2f6af3c,
+                0x06c6059af8c36e19,
+            ]),
+            BlsScalar::from_raw([
+                0x176a425cfae8814f,
+                0x90e7864271baf3d9,
+                0xb5e24bd68a2ec073,
+                0x66a781e382f1b2ab,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0xd20afa8268b6bea4,
+                0xbbbfd5d37c01f710,
+                0xc99613adb6508ca8,
+                0x06602f03634bd8e2,
+            ]),
+            BlsScalar::from_raw([
+                0x8f68b7a2071d41ad,
+                0xb0f71a0186ac8311,
+                0xb25469968a6045f1,
+                0x53ba45f7584815ce,
+            ]),
+        )
+        .to_niels(),
+    ],
+    [
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0xa9e9ba9b21164311,
+                0x6d1b570a8bb02bcb,
+                0xf516aec026e25484,
+                0x081ed7b396ed12b2,
+            ]),
+            BlsScalar::from_raw([
+                0xd36dbea62df934a6,
+                0x5afe8e0deb87d60a,
+                0xe24b7a69a0348c7f,
+                0x09e52ed91288ff22,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0x1eb3549ab1e7d47b,
+                0x67e14473c0f539a1,
+                0xc13ccb08a02d1039,
+                0x371e581ec89dbb9d,
+            ]),
+            BlsScalar::from_raw([
+                0xd4d4dbb3c8c0d544,
+                0xe89244acf62d7dde,
+                0x90dbc0f6a0418cc2,
+                0x1de03e61bcbc5d22,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0x6049d949a71064b8,
+                0xcbecb8a564b0f769,
+                0xc06e2282b5360ed6,
+                0x6ad6971574393e9d,
+            ]),
+            BlsScalar::from_raw([
+                0x57feb38c79376eac,
+                0x9daff187568fef5c,
+                0x922ed1664028157e,
+                0x647bff40a72215a2,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0x3943019e28f4c243,
+                0x98ec44e3127309f5,
+                0xfd49e7eb063c9b51,
+                0x29dff744a331cc0f,
+            ]),
+            BlsScalar::from_raw([
+                0xe7f3aa9e0dbcf329,
+                0x1d20383fb963baac,
+                0xbddbba329a03e027,
+                0x68909a9202e5a5a1,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0x72c6271e817dcbb1,
+                0x8ee3c9848ae6aca8,
+                0x28486af6ec755d3f,
+                0x38e1113d6118e209,
+            ]),
+            BlsScalar::from_raw([
+                0xd66e8cb44e58c5de,
+                0xa374b76be59b9514,
+                0x8a92c1e445ea16ba,
+                0x5cdc4ba5d6b9cd4d,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0xbde671591dc5e22b,
+                0xc127f2590fb201aa,
+                0x16455016d2259a04,
+                0x1e5f530ff94a9206,
+            ]),
+            BlsScalar::from_raw([
+                0x8525e4444d602bd8,
+                0x656feb83a79aa909,
+                0x3047e222fa2cc910,
+                0x6b101d85a2211460,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0xae3af15abb393bfc,
+                0x2fee113ded1c2a07,
+                0x3abe4cb365a78d42,
+                0x56fd4732cad42958,
+            ]),
+            BlsScalar::from_raw([
+                0x413f1ec493dc3130,
+                0xf87f01188e0e4f97,
+                0x45d7d51a52606cf9,
+                0x1fda3aff8b840a79,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0x853489030af71afc,
+                0xbbee21572cce1fce,
+                0x13d030723860fc81,
+                0x09eceb7e6af32728,
+            ]),
+            BlsScalar::from_raw([
+                0xb555732230f72de8,
+                0xf3f8d301fbd26d07,
+                0x1568009deda2b0fa,
+                0x33e83045cee588d9,
+            ]),
+        )
+        .to_niels(),
+    ],
+    [
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0x810f77fa1144f5bf,
+                0x784580d868732754,
+                0x8ee303414cb83ca6,
+                0x29d5cbca5c089406,
+            ]),
+            BlsScalar::from_raw([
+                0x800b1ec34873a57b,
+                0x0137f44a8f38d5e7,
+                0x21c87cec33bf1e2c,
+                0x69e7b7632dc9c352,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0x0490cfd1ae5b16a5,
+                0x6491893ad119b667,
+                0x4a555dff1e551f75,
+                0x3a120e1057f13280,
+            ]),
+            BlsScalar::from_raw([
+                0xff18bcfdff7b5a41,
+                0xf0ff1afa6d24f6fe,
+                0x59c057ef66fa07e3,
+                0x737b3f7b78a8b835,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0x1605ea37362bb180,
+                0x65e0328818139354,
+                0xb1244ff0d5447b09,
+                0x50ffd03ce0a26508,
+            ]),
+            BlsScalar::from_raw([
+                0x08356772d47ab499,
+                0x513e688d9037b7ef,
+                0x7f1c21ab2e4cea57,
+                0x0328f0df3dd8347a,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0x7f07e250d738fdf5,
+                0x322a89f4b45339dd,
+                0xc74fbc487af6e5f4,
+                0x0fd8a8e65c8f9def,
+            ]),
+            BlsScalar::from_raw([
+                0x300681b772a0a3e2,
+                0xcd3018e6b08fca9f,
+                0x4adae854919bcce2,
+                0x1a97fe6ae0c1fdd5,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0x3a5e736b15f269b8,
+                0x1c49e3d4e4d92eda,
+                0xbbeb5e466bfeaedd,
+                0x6cd7560543f1e9e8,
+            ]),
+            BlsScalar::from_raw([
+                0x52c7bd7e1c44db02,
+                0xb0d3af35e553537e,
+                0x43a2cdcecc0412b2,
+                0x6a4003dd05d9a212,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0xb4705bec8bf2e57e,
+                0x550727cfa58d549d,
+                0xa7e4a3e23aaceaa4,
+                0x1cb15b0929a2c8fd,
+            ]),
+            BlsScalar::from_raw([
+                0x984fb05ff60a970e,
+                0x9ef57aac7cbcec94,
+                0x8c07fb7a24214416,
+                0x2016dfd91587bc03,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0xe790a35576025d12,
+                0x04bda4b4a6a8a649,
+                0xaaa380211402d2d0,
+                0x0d6c410daf76f1b5,
+            ]),
+            BlsScalar::from_raw([
+                0x5000018ca2695e13,
+                0x476e9a05561f3b70,
+                0x7213e0b7d8c36d71,
+                0x1d6e3ad8b2b395e7,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0x6967043bd1e3a3d0,
+                0x449ff6dcdacf4c14,
+                0xf3c75fc8c228140a,
+                0x346ec55b09aac37f,
+            ]),
+            BlsScalar::from_raw([
+                0x9e7d06ffbc171d01,
+                0xac0dc95b23a4cb83,
+                0x34f7c9d11bbfc4ed,
+                0x4d6d05cee55f9d6a,
+            ]),
+        )
+        .to_niels(),
+    ],
+    [
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0x40f55f97c402e88d,
+                0x7999d0424bae2562,
+                0xfb4729daf279e7ae,
+                0x18055a0cd2ee5fd7,
+            ]),
+            BlsScalar::from_raw([
+                0x350f9d675c7ed232,
+                0x40b4fd0664340c9e,
+                0xc7230b1363d12f5f,
+                0x1c67e26876da0b9f,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0xcef3094affff7bbb,
+                0xddb58561b7b22f94,
+                0x9167706a73bfba9b,
+                0x395e6c5ad4e032aa,
+            ]),
+            BlsScalar::from_raw([
+                0x69e617146a9f808e,
+                0xa3fb684ebf927adc,
+                0xdfc091899c3ed46b,
+                0x699709f7569abfd9,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0xf46db06a3b3277a7,
+                0xd8b7f79e736bbe15,
+                0x3fca2e9986ec1a08,
+                0x3b0d85171faebe0f,
+            ]),
+            BlsScalar::from_raw([
+                0x95fb64613b3880fb,
+                0x8a8d1db662915846,
+                0x6cb60b478d6f0e3f,
+                0x2f0f1bd8994c555a,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0xcf60dba40813443b,
+                0x4fcbb083c4e16b11,
+                0x96f57feff95bcbf3,
+                0x566cdd2fab32fd45,
+            ]),
+            BlsScalar::from_raw([
+                0x187da754f7ab1856,
+                0x4aa20e9758862f5b,
+                0xd2517f832e8d374b,
+                0x5d124488deb8b31d,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0xf8199897a0407304,
+                0xd333f5aa15459946,
+                0x62341afefd61d714,
+                0x040c1d8d4da26ebe,
+            ]),
+            BlsScalar::from_raw([
+                0x053cc09fda54c571,
+                0xdbd69bb04510eb88,
+                0xa3924d120c9078db,
+                0x4fccd4de74a21cda,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0xc41308d459dfa372,
+                0x45b2c197e73fbede,
+                0xec72e02d373f4aed,
+                0x0f517455ee6480ee,
+            ]),
+            BlsScalar::from_raw([
+                0xfbff75457f99e154,
+                0xb665e4e851163181,
+                0x551f326162f23efc,
+                0x28fb76fe3486399e,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0xcffca2c3889bd5f4,
+                0xff7d7dfae9b9da90,
+                0xd5690811f636bd9e,
+                0x05a9bfe3c01c6275,
+            ]),
+            BlsScalar::from_raw([
+                0xa304a9c06f17b5e2,
+                0x082e1f71162ad68a,
+                0x693a4c9320811f97,
+                0x16800f73f5bb38d5,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0x4b5d94d9e6ce7ec8,
+                0xf935109d550bd7e7,
+                0xd9a3c23c00970c4e,
+                0x2f0350e7fc974d18,
+            ]),
+            BlsScalar::from_raw([
+                0xeddd79df75d9d148,
+                0x77ade106862c1e53,
+                0x93a879fe813acf1a,
+                0x2a8f88bcd3b1c8bd,
+            ]),
+        )
+        .to_niels(),
+    ],
+    [
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0x2f76b1d2a13abadf,
+                0xf5e84419ea8c4840,
+                0x1f4711ec95643354,
+                0x18f81e57dec19685,
+            ]),
+            BlsScalar::from_raw([
+                0x5d843e316e1bde7e,
+                0x159eb83911f5906b,
+                0x2efd1b0888f9db83,
+                0x01c05471908ab8ef,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0x1714be0d2ed755e0,
+                0x1de5969438577075,
+                0x1b5c0ef2708dfae2,
+                0x0f601d7e71eed4e2,
+            ]),
+            BlsScalar::from_raw([
+                0xb0acaa013db6011e,
+                0x3542bb56259cce2d,
+                0x4122bdc51ad76900,
+                0x4ba84d366d9cedb6,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0x9f5d5382b8210a99,
+                0x7dbf3434cf4ab41f,
+                0x52f1d05ed8acd3d0,
+                0x16ba1ee4c53a7922,
+            ]),
+            BlsScalar::from_raw([
+                0xd0c90b45fa5ac8be,
+                0x3caf93f07580c8a5,
+                0xe0bc9dc415f20189,
+                0x64a11a9b32fe98d3,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0x166229ea1712afe7,
+                0x0402bc15d0a5281d,
+                0x7651135de406a081,
+                0x248aea0646374675,
+            ]),
+            BlsScalar::from_raw([
+                0x34e458a15ad85054,
+                0x0d1389a6f5414b50,
+                0xa4bafa5befc111f2,
+                0x443772c943bd1a4b,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0x7ab6e4786377ede3,
+                0x2fe696cb688d80cc,
+                0x680785dd2b0a313f,
+                0x11ac57a08f5d322c,
+            ]),
+            BlsScalar::from_raw([
+                0x67666d1a8246efa5,
+                0xee3707aa7c6a2933,
+                0xf7e6dc5ad17e5c94,
+                0x3f842501d609421f,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0xa3ae07dd60f52e56,
+                0xfc58602418a953cb,
+                0x172ada9ea5ed3979,
+                0x439574909d58ca14,
+            ]),
+            BlsScalar::from_raw([
+                0x336fa83ac64fcbdf,
+                0x043408ee16da17b2,
+                0x7ef060f4e5da7f7a,
+                0x3adc741d2c6d7a92,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0x8cefcbd1faeb12d0,
+                0x3fe55fec573337b2,
+                0x6e22f0ff81d1a020,
+                0x5104b8b2311ff1c4,
+            ]),
+            BlsScalar::from_raw([
+                0x804a10f8230c88ff,
+                0xe720902547abe7f5,
+                0xffc1f35fc36dcc35,
+                0x62e023eb1e2ef333,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0x0e58df2acee5e085,
+                0x47fb96a6c981601f,
+                0xbd89f44814933385,
+                0x00219802b68f7133,
+            ]),
+            BlsScalar::from_raw([
+                0x9acfa09bc9935392,
+                0x841bc40669f9d31d,
+                0xc1ac1431b0159836,
+                0x44c522f2804c03ad,
+            ]),
+        )
+        .to_niels(),
+    ],
+    [
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0x08249d65e2660c9e,
+                0x05bccb2c1d941dbc,
+                0x118f971270197a65,
+                0x03954c74c3fd8dae,
+            ]),
+            BlsScalar::from_raw([
+                0xfcab3e355d651de8,
+                0x09ea70d209d300cd,
+                0x0dfeb69acd775fde,
+                0x4373bd06e51e4e43,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0xf5585ee9664efa4e,
+                0xa62d9bed004add09,
+                0x1e41d77e1bf6d294,
+                0x1604cd9ebdb4efc5,
+            ]),
+            BlsScalar::from_raw([
+                0x439dacbd10d89a86,
+                0x544dea3e925bca70,
+                0xdfaa6fc942b73d27,
+                0x4ee1fe34d0827b4a,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0x53ec7e0b775e2fe5,
+                0x8be8daa4cf2919e9,
+                0x2c19e0d057638135,
+                0x6a8ddb9e892f9e72,
+            ]),
+            BlsScalar::from_raw([
+                0xd956dad1c4986125,
+                0x4d644c1fb4ce1a1e,
+                0xed17e79947ceade6,
+                0x340ccfa32cd4fb9d,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0x1ef7153e990d292e,
+                0xcbcbcb3355ec3075,
+                0x20b1ce0ba2e5b8ab,
+                0x1a456fab907a7594,
+            ]),
+            BlsScalar::from_raw([
+                0xd64d427acba2f558,
+                0xd2c44667c439e0fb,
+                0xc1d0438a13ced336,
+                0x2b89593947001c11,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0x3481e5118f2f439b,
+                0x284337e3109fa9c6,
+                0x0f758b8eb21f6185,
+                0x015b4c8981a8f126,
+            ]),
+            BlsScalar::from_raw([
+                0x1e1b13a79b305ba7,
+                0xf6b4769251b44d7c,
+                0xac358f480ebfcdf1,
+                0x4c771f1b0c308261,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0x63420eb888e18089,
+                0xfa87fa33a76a985b,
+                0xf4c11021d10c6b5e,
+                0x2d9a31c0c794fdf7,
+            ]),
+            BlsScalar::from_raw([
+                0xe99f4b727f9b3ea0,
+                0x90ecb1078bcf388d,
+                0x04dbaceabeaf8852,
+                0x37585598b6df8526,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0xd8711ab9744ba01c,
+                0x612936aa5780b165,
+                0xdb7e1d8ac76362da,
+                0x0190dbdfd09cdad9,
+            ]),
+            BlsScalar::from_raw([
+                0x6e9838d988e839e0,
+                0x58fb78d6ae7a83da,
+                0x0d578a0de7614a5c,
+                0x2cc999a9ec1e3546,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0x3f8412d44ba444e7,
+                0xa2bae0a4de2bd421,
+                0x952f567c8f0f3cb8,
+                0x16f3440a5305e418,
+            ]),
+            BlsScalar::from_raw([
+                0x07d888ce1f55f0da,
+                0xad45705532e518de,
+                0x85b3343741d778a9,
+                0x43eb0f4a8647d0f0,
+            ]),
+        )
+        .to_niels(),
+    ],
+    [
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0x0f980296ed6b9456,
+                0x6bd786c146a6f0f5,
+                0x75edbcd0e8af2cb5,
+                0x6ef0eb475ad04b77,
+            ]),
+            BlsScalar::from_raw([
+                0x990abd1c11354baa,
+                0x341c1b5d261e428a,
+                0x2eaf40c0c893b0ae,
+                0x4bbb64b82070685f,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0x31008be66a1ac8db,
+                0xe9c0d5ebffb7ff62,
+                0x9656524d1f7fb87c,
+                0x50775123a461d897,
+            ]),
+            BlsScalar::from_raw([
+                0x4e95609b420a0be6,
+                0x3db03fd211f88d45,
+                0x1f607276af9a66f4,
+                0x10e69710c809f74d,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0xbcf787fcbb789007,
+                0xccf2db11b0f45090,
+                0x893a264462eb4b5f,
+                0x20ab34a0337739df,
+            ]),
+            BlsScalar::from_raw([
+                0x853a262688da4fa8,
+                0xe511ede8432c9c59,
+                0x141fbd14e9fb8a8f,
+                0x58ace9f2dd814063,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0xe716cf402a45dae8,
+                0xf80a6d553d0be582,
+                0xe03641e461105438,
+                0x3976b194b102bd5d,
+            ]),
+            BlsScalar::from_raw([
+                0x3963718fc5979602,
+                0xeed7e3a9190f621a,
+                0xf06759e552814cb9,
+                0x46343df444d31f9a,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0x8a28d79ec1604c6e,
+                0x1c623b3bf240f511,
+                0xe2310a7cfa7fc89f,
+                0x5af16a507eeabceb,
+            ]),
+            BlsScalar::from_raw([
+                0x29541107e3854f99,
+                0xfc9c86f2951a315e,
+                0x00b395e0a7f0c273,
+                0x23a679b8cff4e4c3,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0x4d437758b208f4e2,
+                0xa04ddc5cf8f2df77,
+                0x672eead2e1bb3143,
+                0x04b9b73bed109b79,
+            ]),
+            BlsScalar::from_raw([
+                0xa6206cac99c364b9,
+                0x59d0baf6b532f4df,
+                0xefd14f1ebe80ca30,
+                0x3f98369ce73911b0,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0xebecb2aaf633f601,
+                0x9a11b7f2ea2bf266,
+                0x97be731f934bb9c9,
+                0x24084203dbbb62c1,
+            ]),
+            BlsScalar::from_raw([
+                0xa2c54e706053f5b6,
+                0x221a7e42931ca3ff,
+                0x6df463bed72ea839,
+                0x1218eb97f5bb3b7b,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0xc8c39a8052fa9a68,
+                0xb9aa1a4b4a18eda8,
+                0x3e06aefffdf9e4d6,
+                0x48a95ab47a1dd4c6,
+            ]),
+            BlsScalar::from_raw([
+                0xe3693b7842828f04,
+                0x8d38308bf24d2e41,
+                0xff0f03f022cfb0ec,
+                0x3d424d8bbbcfb2b0,
+            ]),
+        )
+        .to_niels(),
+    ],
+    [
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0x3f63f15bb442ef3e,
+                0x9001b9f6f8fd0f36,
+                0x993bf22cb1de95b3,
+                0x5edc75bbffead51b,
+            ]),
+            BlsScalar::from_raw([
+                0x258a625b7511ce6b,
+                0xa91ed558772beab2,
+                0x8b0368fe6bce75c6,
+                0x15bcd31d05c21ce1,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0xcca5dca673a29e29,
+                0xa4a7789dc991b142,
+                0xfd504a0de5998a43,
+                0x466f00365564179e,
+            ]),
+            BlsScalar::from_raw([
+                0xba283b307f8c1a9c,
+                0x9c1f91b8a91f6c43,
+                0x2c6970d601e8c28f,
+                0x18a18fbcb07d2a90,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0x931439f59ebaab28,
+                0x93b6bbdb734ce68f,
+                0x4f4d62ff0abcf5fc,
+                0x57a07a70a220b36c,
+            ]),
+            BlsScalar::from_raw([
+                0x65b5dab96f7f2e2f,
+                0x89026eb79fc52745,
+                0xcc3ae4a31342b4e3,
+                0x37db569e81953e80,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0xdb2c66f661cfab88,
+                0x9411ec8cbbe5342f,
+                0xc990f7cfcc065ed8,
+                0x326ab9de5077b410,
+            ]),
+            BlsScalar::from_raw([
+                0xdf3a9f8a47f6dae9,
+                0x5a66937bc9b15a7d,
+                0xb69a4c38f9198446,
+                0x50481dfcd42371a7,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0x0a50a9c06d193afa,
+                0x657e10d139b9bd2b,
+                0x297b1630eda59462,
+                0x1cd6331b1277f728,
+            ]),
+            BlsScalar::from_raw([
+                0xfea44412fc545a5b,
+                0xc7c5498e8cad2603,
+                0x9bc9ba65afad929c,
+                0x4baf547bfee154ee,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0xc5f401db24fd4160,
+                0x48a9d316019a7a90,
+                0x987817e7c5c9383d,
+                0x2c49544a95d47826,
+            ]),
+            BlsScalar::from_raw([
+                0x3c99f9f71a10c228,
+                0x25dc10d02d3abf12,
+                0x7fd0ce4cdc1c0af9,
+                0x5d39ec700a44d77f,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0xef1d116c7138f553,
+                0x88f4a8c9b26a141d,
+                0x1f3c16194b61babf,
+                0x400a8504660b1242,
+            ]),
+            BlsScalar::from_raw([
+                0xbda1943e305e7ab8,
+                0x4913cb3d365e3aa8,
+                0x5838d97a23eac32a,
+                0x5240f5105458b5ac,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0x7aa9259075f92fd2,
+                0xf1884e138f5f84b0,
+                0x87b009c1752a5193,
+                0x0db682860b21bbe8,
+            ]),
+            BlsScalar::from_raw([
+                0x3940fbdcd31a90fb,
+                0x7e8ee5e8f0a8d48d,
+                0xba7e616520a70b75,
+                0x0c5e9527c2428f67,
+            ]),
+        )
+        .to_niels(),
+    ],
+    [
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0x335fe0e8e576c26b,
+                0x20938a4497585dd3,
+                0xb01a2872432fe06e,
+                0x400ed5e7df90a91f,
+            ]),
+            BlsScalar::from_raw([
+                0xbd6fcd64cbc5894f,
+                0xeadf5dadca4154e7,
+                0x27ae248a1d1cef2a,
+                0x7357e737f4175f3e,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0x27b5ac478bf0f8a8,
+                0xf37039c4cfba5eaa,
+                0x6c0fffe07c7df444,
+                0x14d3a269a02a74f9,
+            ]),
+            BlsScalar::from_raw([
+                0x235ed3d2fefec5bc,
+                0xb82d385945048f45,
+                0xb31995d97e049a8a,
+                0x09054fe4cff974da,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0x9ec456237db241ed,
+                0x8c17326763bdf95c,
+                0x909ed923e7fdf214,
+                0x40c70e9301e5a967,
+            ]),
+            BlsScalar::from_raw([
+                0xae38c363e34a521b,
+                0x808c25d738a2808e,
+                0x279f6bfe28190350,
+                0x64a231b8ad37489c,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0xaeecbe466da182aa,
+                0x47362f649baab889,
+                0x097c712223261e29,
+                0x330b06b3bbca8782,
+            ]),
+            BlsScalar::from_raw([
+                0xe96324cd88b111a7,
+                0xb38ad7215e102df1,
+                0x23aec5688eeec59d,
+                0x316f99559a5b158a,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0xdd0c21c980ed1454,
+                0xc62edc6d15ec4b79,
+                0x05695cf5c2401d68,
+                0x3b699ddaac50af1d,
+            ]),
+            BlsScalar::from_raw([
+                0xa0e7a907980f0292,
+                0x4c6875684bc4e16d,
+                0x1b119839c3e3ec10,
+                0x4e987f99431d075a,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0xfea513b7f67caa14,
+                0x5897e41013e749c7,
+                0xe48aea874366c8f7,
+                0x1ff92c7f4216db6c,
+            ]),
+            BlsScalar::from_raw([
+                0x65eee8813e36ac3a,
+                0x438896ccd59222d3,
+                0x9773f32321cae434,
+                0x028acc5b3170c4df,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0x535472836dc31068,
+                0xe10ecaab52387a4c,
+                0x5bee18c8922f90e4,
+                0x4bcccbe073ce2cc7,
+            ]),
+            BlsScalar::from_raw([
+                0x78680847d1c14078,
+                0xb3de1c29b1bd4617,
+                0xc69181eb8c7da394,
+                0x29c720375544e3b9,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0x3a3e88ea03bdad28,
+                0xda7edde987f13c55,
+                0x49c377cd79d8f684,
+                0x23400f43b6e121ee,
+            ]),
+            BlsScalar::from_raw([
+                0x2e29bf24a66b60b6,
+                0x8a3d7cc395ab4bd2,
+                0xa8501fe4492f452d,
+                0x63a50c2514418f0b,
+            ]),
+        )
+        .to_niels(),
+    ],
+    [
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0xaedfdcb0fda5c790,
+                0xe1056b0d7576cc58,
+                0x6c9d8c5f6cf429f3,
+                0x045f4bef3b957670,
+            ]),
+            BlsScalar::from_raw([
+                0xeee3db524fc484ee,
+                0x72c79c2050732699,
+                0x281444334eba20b2,
+                0x5b9f5c6021542115,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0xbf85094be9da04e9,
+                0x67d537ba3e147ea0,
+                0xe0546dffdd65a811,
+                0x10a6957a6711cef3,
+            ]),
+            BlsScalar::from_raw([
+                0x5d9f2f8003712ae3,
+                0xcbf543bd5de3a4e4,
+                0xbb9e8c2ca69daefc,
+                0x5bd43361db609d56,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0xf24baa3c4aa10de2,
+                0x0be65227fcd54451,
+                0xd15a0d7c3a0ab882,
+                0x5da017f40068bc3b,
+            ]),
+            BlsScalar::from_raw([
+                0x20992fe1656166d0,
+                0x2cbf7fcb51227b62,
+                0x30d9b103940e26a2,
+                0x39fcb66317a620fd,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0x594393efcc3ddb3a,
+                0xc7ab67a6116e5e60,
+                0x15086a5fe9242a16,
+                0x738e6b1ded1443ad,
+            ]),
+            BlsScalar::from_raw([
+                0x0854ecea49953924,
+                0x6b17da41609c1306,
+                0x995e40e910dddd23,
+                0x6493f6c60042a1e5,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0x112eb06f26ebb176,
+                0x4b7364e237364cd2,
+                0x4070dd013f2c22ad,
+                0x088f29b045d879ea,
+            ]),
+            BlsScalar::from_raw([
+                0xb9a2418477b9569c,
+                0x4a33f58fd421d2c2,
+                0xf8aaf87deee53c74,
+                0x391f8af873543bf7,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0xef496ca0ae4ac9c4,
+                0xa4fb4e62d06e2e8b,
+                0xc9865e51c735ac23,
+                0x2771b9d962068fb7,
+            ]),
+            BlsScalar::from_raw([
+                0x868bc8787e82195a,
+                0xf713156371d5c4c5,
+                0xec1a404938dbf08e,
+                0x3bd5e096740d0395,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0xcd58bbebff289698,
+                0x4c69dd31446c9df8,
+                0xd94da4743475d10b,
+                0x48f3dcf3569d536f,
+            ]),
+            BlsScalar::from_raw([
+                0x4631aa5fdb7bde34,
+                0x95e442111c1f3efb,
+                0x5c0047b9a059df88,
+                0x6849465c7d0968d3,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0x823ff8850fe1ec61,
+                0xd144bd0ed37caded,
+                0x35e239544a447c0f,
+                0x2a29d58f78bfa9a3,
+            ]),
+            BlsScalar::from_raw([
+                0xc81895ab4dbb7d30,
+                0x24964cd0b958e034,
+                0x281a063fc63f615b,
+                0x094d5075a6350f5a,
+            ]),
+        )
+        .to_niels(),
+    ],
+    [
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0x5b2138917dc2e9bd,
+                0x1263a2225cd4ac08,
+                0x358f26c9a55eb761,
+                0x697ff45a59fc3a57,
+            ]),
+            BlsScalar::from_raw([
+                0x9d9f35dfbc4e1081,
+                0x9b07130364a76dd8,
+                0xcc44787e9d45833f,
+                0x6ae8a444129d151f,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0x5937a906df25f510,
+                0x901dfcb924d389e2,
+                0x23599d4969e7595a,
+                0x1a41efa8decf59c5,
+            ]),
+            BlsScalar::from_raw([
+                0x81291c32a2e852ad,
+                0xe9868731ecc9f056,
+                0xffe3d2d423ae0643,
+                0x08769c2c9e5668e3,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0x8c779de4d4189bbe,
+                0xaa997db8c42c0551,
+                0x7835cd1b56c64b94,
+                0x683287dcfd44d0f3,
+            ]),
+            BlsScalar::from_raw([
+                0x8cce742474edfaed,
+                0x1453285f1b78759d,
+                0x583a45aa2b1ece14,
+                0x44b1cab62b3f920c,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0x180e14d682df1050,
+                0xa7cd99a1c43b4a84,
+                0x916692aadb33f78a,
+                0x1e011c1cbdf2c40c,
+            ]),
+            BlsScalar::from_raw([
+                0x2c50c3c7194bffdb,
+                0xc69cd5e50410bcc8,
+                0xd160c67f292369e8,
+                0x45ac8c63f8404a19,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0xfff02d1735c49145,
+                0x68bc927251459e34,
+                0xd2c2870bc8943c95,
+                0x55c5c97fab6bcb6c,
+            ]),
+            BlsScalar::from_raw([
+                0xe8609aafc1a79edb,
+                0x9188c0995518f83c,
+                0x75dda6bfd7075256,
+                0x0cc661ad56b74675,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0x7a160893f59341ef,
+                0xe7593490b5538cd7,
+                0xff847f79bb75298d,
+                0x4b252a3d076e0e9b,
+            ]),
+            BlsScalar::from_raw([
+                0x2e39cce7ba76630f,
+                0x7e571e7619378f94,
+                0x60b61e4b76049c5f,
+                0x4977a3dad4ac8b0e,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0xc5edf5a819f796e6,
+                0xc9a43b4818eb5915,
+                0x0eb410988b27c333,
+                0x0e5fca795391c683,
+            ]),
+            BlsScalar::from_raw([
+                0x6ccd3e9cf4774b8e,
+                0x230ea62962d0c4b6,
+                0xe32413a4a56de402,
+                0x456bbe9b5d8f9cf2,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0xb9a902023de72e2a,
+                0x5495d25faf477129,
+                0x180e7224588591f9,
+                0x3582d8879910dd55,
+            ]),
+            BlsScalar::from_raw([
+                0x020d36c653aaa663,
+                0x8c948015b2985115,
+                0xdb35ad53f0533cb7,
+                0x5418a39f93b9029c,
+            ]),
+        )
+        .to_niels(),
+    ],
+    [
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0x3defcc76d1605f39,
+                0x382dc7bf402b299e,
+                0x141a33addc0395d4,
+                0x3ad69b3c13e5034d,
+            ]),
+            BlsScalar::from_raw([
+                0xd02746553e85e72e,
+                0x00aa954f75fcf9df,
+                0x7e667c7cc9e9f8bd,
+                0x484723a41559a1ac,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0xf51ea258081cceda,
+                0xa2ee1fce2d9116a9,
+                0xb101fd8cabc24e71,
+                0x72179c2848cdefe2,
+            ]),
+            BlsScalar::from_raw([
+                0x0d2df7a8600f1ca5,
+                0x47db08ad1409f040,
+                0xb8fcb9431e8bc2b1,
+                0x2e209f3751f4f1ce,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0xcd38fd672ed857e1,
+                0x3ddf205eaccaec32,
+                0x2eaf959e5ec31fd6,
+                0x478ea9b34384ed2d,
+            ]),
+            BlsScalar::from_raw([
+                0xcd8620d62a03eb7f,
+                0x37d978fa95d3683c,
+                0x4bd3d66cf8bc1771,
+                0x6245782fe3aa7ddd,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0x374646040e28efc2,
+                0x5c322bd67d4e1b24,
+                0x5f66d663f819d57f,
+                0x2547c9913607f99f,
+            ]),
+            BlsScalar::from_raw([
+                0xed165f87f010d3b0,
+                0xffb93c549f45e883,
+                0xece021da07353470,
+                0x4b58047bc08f4b65,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0xe19b009495f9b069,
+                0x58f616d6cb0a8820,
+                0xd537a2ed539f20ed,
+                0x5f83f71fa2744f81,
+            ]),
+            BlsScalar::from_raw([
+                0x158abc1ab5c4b205,
+                0xac37bcd4a092badf,
+                0x5f58789846c89537,
+                0x5b9d67e93d51a8da,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0xa25d4c9efe437c73,
+                0xa68c8df1ecfe1261,
+                0xaaec2e20553d83a7,
+                0x67d84a3c13e8e521,
+            ]),
+            BlsScalar::from_raw([
+                0x22604c296649187e,
+                0xe787b10529b1d0aa,
+                0x1279ea2b212e38cd,
+                0x3e07a3c77991d97c,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0x5357c87eb59bb374,
+                0x491bd9934a358fc1,
+                0x4b77ef976bf6cc8c,
+                0x64fd5e7efb1c8ccf,
+            ]),
+            BlsScalar::from_raw([
+                0xd4fb6ed996dbfb77,
+                0x812b88a4c4d532e2,
+                0x8c2e77ba9c77468e,
+                0x1f622bd34a013941,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0xcf5d30dd9dbbe6d5,
+                0x51a8f4bf0ce13240,
+                0x4ee9e3390a3807d6,
+                0x472df460b7de00f6,
+            ]),
+            BlsScalar::from_raw([
+                0xbdfd5efcfa4a2cdd,
+                0x015cb1d6b26b7635,
+                0xfcf8bef6e68176d9,
+                0x6cdd28c87e38f4b1,
+            ]),
+        )
+        .to_niels(),
+    ],
+    [
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0xda7be722c5eda4b4,
+                0x3dc42a9d5db211f0,
+                0x6c862e978da50d28,
+                0x33e0578df45350cb,
+            ]),
+            BlsScalar::from_raw([
+                0x33bc639f4df659e3,
+                0x0b9ee76584cf58d6,
+                0xb0ee747729047bb9,
+                0x567b5cd82420a841,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0x9d4a4c178d5a129a,
+                0xbc02a2ddadc2707e,
+                0x21acf4c9e8af7490,
+                0x00a1222e24bdad1e,
+            ]),
+            BlsScalar::from_raw([
+                0x2657271a18779b97,
+                0xc85e322103236b8b,
+                0x9df1c6463ba06437,
+                0x0c783df5be771daf,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0x6ebdb4b0efc27801,
+                0x9c380dcd57caf595,
+                0x9c8d18a6ec3e7c15,
+                0x31f7938be379031e,
+            ]),
+            BlsScalar::from_raw([
+                0x7ee3e09bd5c086b5,
+                0xadcc2e4419d42208,
+                0x7b8e04cc322ad61b,
+                0x1122e30de2e46345,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0xde6766af445dc64c,
+                0xe68d2ad87314001b,
+                0x9003ce360c53969d,
+                0x19fc444f71e88329,
+            ]),
+            BlsScalar::from_raw([
+                0xab76da9069cad7f4,
+                0xe502b87381c53679,
+                0xf981ed943ff8b1da,
+                0x4506f2c09ad48e67,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0xf896e33a5ac58e18,
+                0xdf59f43fdc722263,
+                0x71dd93d8ea31ee6b,
+                0x1e1179eeeb8b520f,
+            ]),
+            BlsScalar::from_raw([
+                0xa20f0eabba563e0f,
+                0xd4e567151a932de3,
+                0x382ac6d69ca88fff,
+                0x035b99e920bdfbf9,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0x84c1e56f720b3619,
+                0xeb3227faef945d5f,
+                0xf8aed7f23cbe1dd3,
+                0x4e2fb4452ba041c0,
+            ]),
+            BlsScalar::from_raw([
+                0xdc07f8d755657273,
+                0xe20f72bace92a745,
+                0x0b971ec017092b70,
+                0x0dd4a67676ef7855,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0xd47dc6f3bddc1653,
+                0x3bb901638882d858,
+                0xdbe60e9ae5106b53,
+                0x151091173193b4e0,
+            ]),
+            BlsScalar::from_raw([
+                0x5e451ea0b5337e57,
+                0xcabee55e021caff3,
+                0x7a6d762c4cc5fd97,
+                0x70f64a7a25d71ff0,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0x9a92a299242a65f8,
+                0x3e85c49eb00d6089,
+                0x32c6bc4d62eeaf5c,
+                0x5b8b328eec0bd858,
+            ]),
+            BlsScalar::from_raw([
+                0xfb11fd28d501f291,
+                0xafd7345cd33364c9,
+                0xdcbbdd4cd6f958b5,
+                0x0621cc5db30e967c,
+            ]),
+        )
+        .to_niels(),
+    ],
+    [
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0xd6de47ab227c0e2e,
+                0x3b126ed148ed2ee5,
+                0xe8724133e3fbdf2f,
+                0x5b2f2eee34ad8602,
+            ]),
+            BlsScalar::from_raw([
+                0x38b8b977893c5dfd,
+                0xc1b72041bbfcff54,
+                0xaa12b90f963485a4,
+                0x54b86e5a58f40ae2,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0x1e8aecbbb361a8f3,
+                0x4ba8b1c2c63f66f2,
+                0xeb56a875172211cb,
+                0x268bc72fe360987b,
+            ]),
+            BlsScalar::from_raw([
+                0x5352c89dca8fb87e,
+                0xb50272e3631984bf,
+                0x97bb3667fe300123,
+                0x2a04fce50e3676dc,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0xf3a82e07d1c38776,
+                0x29bc4839ac95dcbe,
+                0x40381a37490f5479,
+                0x1b2eeb389c1cf769,
+            ]),
+            BlsScalar::from_raw([
+                0x122c0adf8e41a326,
+                0x218909d12e97d25b,
+                0x522607e44025391e,
+                0x296e818f24fc8387,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0xc6cc82e96fbb5ed0,
+                0xb79451131179f220,
+                0xdc468b3253e4911c,
+                0x444bc089c9f94af8,
+            ]),
+            BlsScalar::from_raw([
+                0xd04e684ae79c5da4,
+                0x74349437a3cf6806,
+                0x9c87c6cf943496dc,
+                0x2824150479365c50,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0x5c073794a7f586cc,
+                0x90ab99f60df8794b,
+                0x2ecbbc2899fb54f1,
+                0x13656c15cdd894a6,
+            ]),
+            BlsScalar::from_raw([
+                0x8aab4a499622663e,
+                0xaab997f3acdfa934,
+                0x8f64650d19968a8f,
+                0x468064b1b6767eb5,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0xb4f883a3b61f7c79,
+                0x8d2b71f83dee8774,
+                0xca90736a1c28b338,
+                0x102995d96c180223,
+            ]),
+            BlsScalar::from_raw([
+                0x57bcbf927a3a7fa7,
+                0x613b78f94aa1e4e9,
+                0x26eba8e312ac1b60,
+                0x4f82db6c26f371aa,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0xadde1901bcbac656,
+                0x26f20f9dc266ade1,
+                0x24844256c00a5373,
+                0x5c2e58e6a09c7e25,
+            ]),
+            BlsScalar::from_raw([
+                0x9d0ee0bb1ddc6a73,
+                0xabc854b08ca7df62,
+                0xb847b68e996c1ea7,
+                0x39963ea09b73efd0,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0x4aaf9a523725285b,
+                0xdc9620c44633a3e2,
+                0xd5a7e6679e3ab1ae,
+                0x376f9f1f8a6dd040,
+            ]),
+            BlsScalar::from_raw([
+                0x5a5ffce4238cb598,
+                0x23f6c33913f645b0,
+                0x7b76ef95bfeb9d96,
+                0x12b02c2070ea4558,
+            ]),
+        )
+        .to_niels(),
+    ],
+    [
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0xc343ccec569a42a4,
+                0xdf61ceaa17f77268,
+                0x0d45b8c1c31c013d,
+                0x056892bfbdc57dd6,
+            ]),
+            BlsScalar::from_raw([
+                0x407005c69fcf9f4f,
+                0x514316dbc131713a,
+                0x08b8fd53c1419b5e,
+                0x56f73249ff84b039,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0x09df41110faa91cd,
+                0x2911371bba1979ce,
+                0xb3b4d04fa85e17b0,
+                0x06e6e8a09d1fc2c6,
+            ]),
+            BlsScalar::from_raw([
+                0xfbbbc17a2a9934a2,
+                0x3733bd2936e0d2c8,
+                0xd63fa14441cefeeb,
+                0x5b8aa49c879951fd,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0xf19d58a0fad1e1aa,
+                0x4aa07f5da81893cf,
+                0x060169e2fbf4bb2a,
+                0x2f172f7e9a2c0544,
+            ]),
+            BlsScalar::from_raw([
+                0x05323604f259da27,
+                0x8478f9d2ed7713c0,
+                0x9749a94aec1ac1c0,
+                0x60b351c6bebf88c8,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0xba97d488a085293d,
+                0x3b17b65977b1f337,
+                0x91051508b0e878ff,
+                0x05bf474269caf613,
+            ]),
+            BlsScalar::from_raw([
+                0x5c1a9fadebd2e4e0,
+                0x3d632165aa55a202,
+                0x9c338e5450b35da8,
+                0x5bcfa093d1d15544,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0x91c1f85b11e21949,
+                0x7f13c4d96c3e2013,
+                0xca1b478e2cf5a497,
+                0x43c1009baa81f1d7,
+            ]),
+            BlsScalar::from_raw([
+                0x804b057cba8505d2,
+                0x3a0ec3b77dc06661,
+                0xc95f82c2f81aea0f,
+                0x6ada712aaf310e98,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0x0e74d7647c1f63ed,
+                0xb4613c62dd92aa43,
+                0x607d5bbfb5700f6d,
+                0x22d310bd48d799e4,
+            ]),
+            BlsScalar::from_raw([
+                0x150d48603e4111af,
+                0xdee48dec4f9abba7,
+                0x82dc966ab3ef2991,
+                0x730ae32148b3212a,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0x64e1a53e78715458,
+                0x99818f194bb9a412,
+                0xe7fe499da81ff5f2,
+                0x027b140dd3537e84,
+            ]),
+            BlsScalar::from_raw([
+                0x0bda5066c61132a1,
+                0x8cbc256c28316203,
+                0xb2859d5f4ff14039,
+                0x68ca74a568976819,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0x4f06cafb029bbafc,
+                0x1bf20523744156b1,
+                0xc6afea7af038eef8,
+                0x6aba60c3e7cc4328,
+            ]),
+            BlsScalar::from_raw([
+                0x4e56cbe135e59b5e,
+                0x955527dd1cdabbcb,
+                0x90fbc4c9b94d476a,
+                0x3c20a370ef43de80,
+            ]),
+        )
+        .to_niels(),
+    ],
+    [
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0xfb51b454af443433,
+                0x33efa03edf4a8eab,
+                0xf542b558cfbc2467,
+                0x025808509d042440,
+            ]),
+            BlsScalar::from_raw([
+                0xdd352872f487eb90,
+                0x9bbe8ca0894c78b6,
+                0x48f8dc23bacfedb1,
+                0x5267a410442f2fd3,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0x3e6b247e410e8e58,
+                0x7b9f3d6d38bfac8c,
+                0xed635241891b0077,
+                0x24fd404cf002d1be,
+            ]),
+            BlsScalar::from_raw([
+                0x14882ca004af4e4b,
+                0x9aa82301c12603f2,
+                0x0873b4b54d01de7b,
+                0x39d6294a3eeee3d5,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0x35a60b28cd47fca5,
+                0x554689981d38fd64,
+                0xbdfb0a93f48df462,
+                0x010534b6b58b24b3,
+            ]),
+            BlsScalar::from_raw([
+                0x688c533d4708a67a,
+                0xda07816ccec1b4f2,
+                0x2633fa3f8e1c9362,
+                0x2000abba4295e3d8,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0x84e97c2bd3f62c3c,
+                0x9c8d3e649d5c08a1,
+                0x14f2485ddc37800b,
+                0x3afb3def1a5320fb,
+            ]),
+            BlsScalar::from_raw([
+                0x51d60c670c71fade,
+                0x52d1d96f15b8c8b1,
+                0xf8d813d1d7b2fad5,
+                0x2fd422b82d4855c0,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0xe00bac40dd0393b5,
+                0x8bbc50c46d563da2,
+                0x325ee8898093aa6a,
+                0x4b6c48fef0a0e8de,
+            ]),
+            BlsScalar::from_raw([
+                0x5522829398a47eb0,
+                0x078209e2c27bc43b,
+                0xf1a3ff4a0df0b00a,
+                0x5c4460f116583eca,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0xd8ae1befb750354b,
+                0x21b0c9bfad0aecc7,
+                0x1670b063da7cb769,
+                0x3196f3e9e0d361c3,
+            ]),
+            BlsScalar::from_raw([
+                0x73558bc799fe5cd7,
+                0x0ccf3e4b27211da4,
+                0x689bd30d6ea81a23,
+                0x130e6621d67a2d35,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0xcc6c182ff05c3ff9,
+                0x3cc61e44cea71ffe,
+                0xf292db8aacf22257,
+                0x42506fdd5c079728,
+            ]),
+            BlsScalar::from_raw([
+                0xb5e9c27177541c8e,
+                0xc6dcaaa8818e45ad,
+                0xfd8357965e725d77,
+                0x28bf580947506a7e,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0xccf11423f9083173,
+                0x4116cb52bd739c45,
+                0x9990a450681b39b4,
+                0x5bd77cfa1e7cd635,
+            ]),
+            BlsScalar::from_raw([
+                0x4e90e9a371fb0415,
+                0xaf0b4a0420260a0a,
+                0xa908f5a1ddcd31c4,
+                0x3ebe78b4685df3d1,
+            ]),
+        )
+        .to_niels(),
+    ],
+    [
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0x2d6e70dc3f8c1918,
+                0x1e95fb429f8c8cc6,
+                0xa79e93a93834b464,
+                0x28444f5f1ad2c541,
+            ]),
+            BlsScalar::from_raw([
+                0xa9bc54aa0f6b69bb,
+                0xe4aa5ad0e94ca235,
+                0x7c221edb95413488,
+                0x5e5e50a268470bc2,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0xb7bcde6f5155b56b,
+                0x7eb14ea3c8efd5ef,
+                0xf30ecbdc137fb14f,
+                0x532f2a957a09c555,
+            ]),
+            BlsScalar::from_raw([
+                0x502a316916127016,
+                0x6687d82e471c2f8b,
+                0x335acd609c5f87ae,
+                0x5f955a0268b4d9ea,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0x46bfe4aa636a733d,
+                0x20e9335ba0a312f5,
+                0xdadd37b501fe33f4,
+                0x19fe0426226490e8,
+            ]),
+            BlsScalar::from_raw([
+                0x70f694bc178506fb,
+                0xcce34b35c49aea12,
+                0xc1fb5334b545a9c5,
+                0x487d00e4d3632395,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0x4ce19e62597aadbd,
+                0xbe9359687a093419,
+                0xdf7c06831f5fb957,
+                0x6d3b9bbfbe46a165,
+            ]),
+            BlsScalar::from_raw([
+                0xe3b9e95d2c6fafb7,
+                0x9acc8922ce104296,
+                0x3ccf08c05d4e33e6,
+                0x1dd4cf163c147c98,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0xe3833deb77050477,
+                0x10782484b784a27e,
+                0xa87371f8a6b176c4,
+                0x6f5932f6ecb879e3,
+            ]),
+            BlsScalar::from_raw([
+                0x446535da3b6dbc64,
+                0xb9732c91a215522a,
+                0xc17cf5e70ee22833,
+                0x5c948415ce581ad9,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0xeb65a4c932cd2950,
+                0x945ec507710e5b69,
+                0xfe592fc1e699d8a3,
+                0x18aee7292336ad67,
+            ]),
+            BlsScalar::from_raw([
+                0x625ed6de731e815a,
+                0xe1584ce7fc157c44,
+                0x6c1006a97178207b,
+                0x1e7f56c6122ad67b,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0x7e5501a88ba67851,
+                0xc30585e5609cae53,
+                0x51127ef35c505abe,
+                0x57b39d91f6150072,
+            ]),
+            BlsScalar::from_raw([
+                0x15d06f1868f5734d,
+                0x5d97608cdf85665d,
+                0x0a4049236a0c7289,
+                0x3dae05c3f49374fc,
+            ]),
+        )
+        .to_niels(),
+        JubJubAffine::from_raw_unchecked(
+            BlsScalar::from_raw([
+                0xf8f553facf4e206a,
+                0xe2f2fdec3efbeba5,
+                0xa8a48ce9f643f931,
+                0x50cc11cefc2111ad,
+            ]),
+            BlsScalar::from_raw([
+                0x2a723d4c17f37663,
+                0x1e905c8c26eb2565,
+                0x11e5a35bf7b3ac0e,
+                0x3268ee5499df4576,
+            ]),
+        )
+        .to_niels(),
+    ],
+];
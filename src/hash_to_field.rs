@@ -0,0 +1,296 @@
+//! Hashing of arbitrary messages to field elements, following
+//! [RFC 9380](https://www.rfc-editor.org/rfc/rfc9380.html).
+//!
+//! The field elements are produced with `expand_message_xmd`, and are
+//! suitable as inputs to hash-to-curve constructions and challenges.
+
+use crate::{BlsScalar, JubJubScalar};
+
+use alloc::vec;
+use alloc::vec::Vec;
+use digest::generic_array::typenum::Unsigned;
+use digest::{BlockInput, Digest};
+
+/// Length, in bytes, of the output keying material consumed per field element.
+///
+/// Being `k = 128` the target security level, this is
+/// `L = ceil((ceil(log2(p)) + k) / 8) = 48` for both [`BlsScalar`] and
+/// [`JubJubScalar`].
+pub const OKM_SIZE: usize = 48;
+
+/// Types that can be built from uniformly random output keying material.
+pub trait FromOkm: Sized {
+    /// Interpret `okm` as a big-endian integer and reduce it into a field
+    /// element.
+    fn from_okm(okm: &[u8; OKM_SIZE]) -> Self;
+}
+
+/// Convert the big-endian `okm` into a 512-bit little-endian integer.
+fn okm_to_wide(okm: &[u8; OKM_SIZE]) -> [u8; 64] {
+    let mut wide = [0u8; 64];
+    wide[..OKM_SIZE].copy_from_slice(okm);
+    wide[..OKM_SIZE].reverse();
+    wide
+}
+
+impl FromOkm for BlsScalar {
+    fn from_okm(okm: &[u8; OKM_SIZE]) -> Self {
+        BlsScalar::from_bytes_wide(&okm_to_wide(okm))
+    }
+}
+
+impl FromOkm for JubJubScalar {
+    fn from_okm(okm: &[u8; OKM_SIZE]) -> Self {
+        JubJubScalar::from_bytes_wide(&okm_to_wide(okm))
+    }
+}
+
+/// Expand `msg` into `len_in_bytes` uniformly random bytes, with the domain
+/// separation tag `dst`, as defined by `expand_message_xmd` in RFC 9380.
+///
+/// Tags longer than 255 bytes are hashed as mandated by the RFC.
+///
+/// # Panics
+///
+/// If `len_in_bytes` is greater than `65535` or than `255` times the output
+/// size of `H`.
+pub fn expand_message_xmd<H>(
+    msg: &[u8],
+    dst: &[u8],
+    len_in_bytes: usize,
+) -> Vec<u8>
+where
+    H: Digest + BlockInput,
+{
+    let b_in_bytes = H::output_size();
+    let s_in_bytes = H::BlockSize::to_usize();
+
+    let ell = len_in_bytes.div_ceil(b_in_bytes);
+    assert!(
+        ell <= 255 && len_in_bytes <= 65535,
+        "Requested length is too big"
+    );
+
+    let oversize;
+    let dst = if dst.len() > 255 {
+        oversize = H::new().chain(b"H2C-OVERSIZE-DST-").chain(dst).finalize();
+        &oversize[..]
+    } else {
+        dst
+    };
+    let dst_len = [dst.len() as u8];
+
+    let b_0 = H::new()
+        .chain(vec![0u8; s_in_bytes])
+        .chain(msg)
+        .chain((len_in_bytes as u16).to_be_bytes())
+        .chain([0u8])
+        .chain(dst)
+        .chain(dst_len)
+        .finalize();
+
+    let mut b_i = H::new()
+        .chain(&b_0)
+        .chain([1u8])
+        .chain(dst)
+        .chain(dst_len)
+        .finalize();
+
+    let mut uniform_bytes = Vec::with_capacity(ell * b_in_bytes);
+    uniform_bytes.extend_from_slice(&b_i);
+
+    for i in 2..=ell {
+        let xor: Vec<u8> =
+            b_0.iter().zip(b_i.iter()).map(|(a, b)| a ^ b).collect();
+
+        b_i = H::new()
+            .chain(xor)
+            .chain([i as u8])
+            .chain(dst)
+            .chain(dst_len)
+            .finalize();
+
+        uniform_bytes.extend_from_slice(&b_i);
+    }
+
+    uniform_bytes.truncate(len_in_bytes);
+    uniform_bytes
+}
+
+/// Hash `msg` into `count` field elements, with the domain separation tag
+/// `dst`, as defined by `hash_to_field` in RFC 9380 using
+/// [`expand_message_xmd`].
+///
+/// # Panics
+///
+/// If `count · 48` is greater than `65535` or than `255` times the output
+/// size of `H`, i.e. if `count` is greater than `255 · H::output_size() / 48`:
+/// `170` for SHA-256 and `340` for SHA-512.
+pub fn hash_to_field<F, H>(msg: &[u8], dst: &[u8], count: usize) -> Vec<F>
+where
+    F: FromOkm,
+    H: Digest + BlockInput,
+{
+    let len_in_bytes = count
+        .checked_mul(OKM_SIZE)
+        .expect("Requested length is too big");
+    let uniform_bytes = expand_message_xmd::<H>(msg, dst, len_in_bytes);
+
+    let mut okm = [0u8; OKM_SIZE];
+    uniform_bytes
+        .chunks_exact(OKM_SIZE)
+        .map(|chunk| {
+            okm.copy_from_slice(chunk);
+            F::from_okm(&okm)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sha2::Sha256;
+
+    const DST: &[u8] = b"QUUX-V01-CS02-with-expander-SHA256-128";
+
+    fn unhex(s: &str) -> Vec<u8> {
+        (0..s.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&s[i..i + 2], 16).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn expand_message_xmd_sha256() {
+        let q128 = format!("q128_{}", "q".repeat(128));
+        let a512 = format!("a512_{}", "a".repeat(512));
+
+        let vectors: [(&str, usize, &str); 7] = [
+            (
+                "",
+                0x20,
+                "68a985b87eb6b46952128911f2a4412bbc302a9d759667f87f7a21d803f07235",
+            ),
+            (
+                "abc",
+                0x20,
+                "d8ccab23b5985ccea865c6c97b6e5b8350e794e603b4b97902f53a8a0d605615",
+            ),
+            (
+                "abcdef0123456789",
+                0x20,
+                "eff31487c770a893cfb36f912fbfcbff40d5661771ca4b2cb4eafe524333f5c1",
+            ),
+            (
+                &q128,
+                0x20,
+                "b23a1d2b4d97b2ef7785562a7e8bac7eed54ed6e97e29aa51bfe3f12ddad1ff9",
+            ),
+            (
+                &a512,
+                0x20,
+                "4623227bcc01293b8c130bf771da8c298dede7383243dc0993d2d94823958c4c",
+            ),
+            (
+                "",
+                0x80,
+                "af84c27ccfd45d41914fdff5df25293e221afc53d8ad2ac06d5e3e29485dadbe\
+                 e0d121587713a3e0dd4d5e69e93eb7cd4f5df4cd103e188cf60cb02edc3edf18\
+                 eda8576c412b18ffb658e3dd6ec849469b979d444cf7b26911a08e63cf31f9dc\
+                 c541708d3491184472c2c29bb749d4286b004ceb5ee6b9a7fa5b646c993f0ced",
+            ),
+            (
+                "abc",
+                0x80,
+                "abba86a6129e366fc877aab32fc4ffc70120d8996c88aee2fe4b32d6c7b6437a\
+                 647e6c3163d40b76a73cf6a5674ef1d890f95b664ee0afa5359a5c4e07985635\
+                 bbecbac65d747d3d2da7ec2b8221b17b0ca9dc8a1ac1c07ea6a1e60583e2cb00\
+                 058e77b7b72a298425cd1b941ad4ec65e8afc50303a22c0f99b0509b4c895f40",
+            ),
+        ];
+
+        for (msg, len, expected) in vectors.iter() {
+            let uniform_bytes =
+                expand_message_xmd::<Sha256>(msg.as_bytes(), DST, *len);
+            assert_eq!(uniform_bytes, unhex(expected));
+        }
+    }
+
+    #[test]
+    fn expand_message_xmd_oversize_dst() {
+        let long_dst = [0x42u8; 256];
+        let hashed_dst = Sha256::new()
+            .chain(b"H2C-OVERSIZE-DST-")
+            .chain(&long_dst[..])
+            .finalize();
+
+        assert_eq!(
+            expand_message_xmd::<Sha256>(b"abc", &long_dst, 0x20),
+            expand_message_xmd::<Sha256>(b"abc", &hashed_dst, 0x20)
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn expand_message_xmd_too_long() {
+        expand_message_xmd::<Sha256>(b"abc", DST, 256 * 32);
+    }
+
+    #[test]
+    fn hash_to_field_count() {
+        let a: Vec<BlsScalar> = hash_to_field::<_, Sha256>(b"abc", DST, 2);
+        let b: Vec<BlsScalar> = hash_to_field::<_, Sha256>(b"abc", DST, 2);
+        let c: Vec<BlsScalar> = hash_to_field::<_, Sha256>(b"abd", DST, 2);
+
+        assert_eq!(a.len(), 2);
+        assert_eq!(a, b);
+        assert_ne!(a[0], a[1]);
+        assert_ne!(a, c);
+
+        let uniform_bytes = expand_message_xmd::<Sha256>(b"abc", DST, 96);
+        let mut okm = [0u8; OKM_SIZE];
+        okm.copy_from_slice(&uniform_bytes[OKM_SIZE..]);
+        assert_eq!(a[1], BlsScalar::from_okm(&okm));
+
+        let s: Vec<JubJubScalar> = hash_to_field::<_, Sha256>(b"abc", DST, 3);
+        assert_eq!(s.len(), 3);
+        assert!(
+            hash_to_field::<JubJubScalar, Sha256>(b"abc", DST, 0).is_empty()
+        );
+    }
+
+    #[test]
+    fn hash_to_field_max_count() {
+        let s: Vec<JubJubScalar> = hash_to_field::<_, Sha256>(b"abc", DST, 170);
+        assert_eq!(s.len(), 170);
+    }
+
+    #[test]
+    #[should_panic]
+    fn hash_to_field_too_many() {
+        hash_to_field::<JubJubScalar, Sha256>(b"abc", DST, 171);
+    }
+
+    #[test]
+    #[should_panic]
+    fn hash_to_field_count_overflow() {
+        hash_to_field::<JubJubScalar, Sha256>(b"abc", DST, usize::MAX);
+    }
+
+    #[test]
+    fn from_okm_reduces() {
+        // 2^383 - 1 is reduced modulo the field order
+        let okm = [0xffu8; OKM_SIZE];
+        let mut wide = [0u8; 64];
+        wide[..OKM_SIZE].copy_from_slice(&okm);
+
+        assert_eq!(
+            BlsScalar::from_okm(&okm),
+            BlsScalar::from_bytes_wide(&wide)
+        );
+
+        let mut okm = [0u8; OKM_SIZE];
+        okm[OKM_SIZE - 1] = 1;
+        assert_eq!(JubJubScalar::from_okm(&okm), JubJubScalar::one());
+    }
+}
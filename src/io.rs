@@ -0,0 +1,100 @@
+//! Streaming serialization of points and scalars over [`std::io`].
+//!
+//! Every item is read and written in its canonical fixed-length encoding, as
+//! defined by [`Serializable`], so records can be decoded one after the other
+//! from a reader.
+
+use crate::{JubJubAffine, JubJubScalar};
+
+use dusk_bytes::Serializable;
+use std::io::{self, Read, Write};
+
+fn invalid_data<E: core::fmt::Debug>(e: E) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, format!("{:?}", e))
+}
+
+/// Read a compressed point from `r`.
+///
+/// Fails with [`io::ErrorKind::InvalidData`] if the bytes don't encode a
+/// point of the curve.
+pub fn read_point<R: Read>(r: &mut R) -> io::Result<JubJubAffine> {
+    let mut bytes = [0u8; JubJubAffine::SIZE];
+    r.read_exact(&mut bytes)?;
+
+    JubJubAffine::from_bytes(&bytes).map_err(invalid_data)
+}
+
+/// Read a scalar from `r`.
+///
+/// Fails with [`io::ErrorKind::InvalidData`] if the bytes don't encode a
+/// canonical scalar.
+pub fn read_scalar<R: Read>(r: &mut R) -> io::Result<JubJubScalar> {
+    let mut bytes = [0u8; JubJubScalar::SIZE];
+    r.read_exact(&mut bytes)?;
+
+    JubJubScalar::from_bytes(&bytes).map_err(invalid_data)
+}
+
+/// Write the compressed form of `point` to `w`.
+pub fn write_point<W: Write>(
+    w: &mut W,
+    point: &JubJubAffine,
+) -> io::Result<()> {
+    w.write_all(&point.to_bytes())
+}
+
+/// Write `scalar` to `w`.
+pub fn write_scalar<W: Write>(
+    w: &mut W,
+    scalar: &JubJubScalar,
+) -> io::Result<()> {
+    w.write_all(&scalar.to_bytes())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{read_point, read_scalar, write_point, write_scalar};
+    use crate::{JubJubAffine, JubJubScalar, GENERATOR, GENERATOR_NUMS};
+    use dusk_bytes::Serializable;
+    use rand_core::OsRng;
+    use std::io::{Cursor, ErrorKind};
+
+    #[test]
+    fn round_trip() {
+        let s = JubJubScalar::random(&mut OsRng);
+        let t = JubJubScalar::random(&mut OsRng);
+
+        let mut buf = Vec::new();
+        write_point(&mut buf, &GENERATOR).unwrap();
+        write_scalar(&mut buf, &s).unwrap();
+        write_point(&mut buf, &GENERATOR_NUMS).unwrap();
+        write_scalar(&mut buf, &t).unwrap();
+        assert_eq!(buf.len(), 128);
+
+        let mut r = Cursor::new(buf);
+        assert_eq!(read_point(&mut r).unwrap(), GENERATOR);
+        assert_eq!(read_scalar(&mut r).unwrap(), s);
+        assert_eq!(read_point(&mut r).unwrap(), GENERATOR_NUMS);
+        assert_eq!(read_scalar(&mut r).unwrap(), t);
+
+        let err = read_scalar(&mut r).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::UnexpectedEof);
+    }
+
+    #[test]
+    fn malformed() {
+        let mut r = Cursor::new([0xffu8; 32]);
+        let err = read_scalar(&mut r).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::InvalidData);
+
+        // 2 is not the y-coordinate of any point of the curve
+        let mut bytes = [0u8; 32];
+        bytes[0] = 2;
+        let mut r = Cursor::new(bytes);
+        let err = read_point(&mut r).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::InvalidData);
+
+        let mut r = Cursor::new(JubJubAffine::identity().to_bytes());
+        assert_eq!(read_point(&mut r).unwrap(), JubJubAffine::identity());
+    }
+}
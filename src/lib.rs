@@ -13,6 +13,32 @@
 //! * `Fr`, which is the scalar field of Jubjub
 //! * `batch_normalize` for converting many `JubJubExtended`s into
 //!   `JubJubAffine`s efficiently.
+//! * `dh::SharedSecret` for Diffie-Hellman key exchange.
+//! * `montgomery::MontgomeryPoint` for the Montgomery form of Jubjub.
+//! * `pedersen::PedersenGens` for Pedersen commitments.
+//! * `stealth` for one-time keys of stealth addresses.
+//! * `transcript::Transcript` for Fiat-Shamir challenges.
+//!
+//! # Base field
+//!
+//! The base field `BlsScalar` is the scalar field of BLS12-381, re-exported
+//! from `dusk-bls12_381` with its whole public API, so it can be used directly
+//! to implement decompression, hash-to-curve or small field extensions:
+//!
+//! ```
+//! use dusk_jubjub::{BlsScalar, EDWARDS_D, GENERATOR};
+//!
+//! let two = BlsScalar::one().double();
+//! assert_eq!(two, BlsScalar::from(2u64));
+//! assert_eq!(two.square() - two * two, BlsScalar::zero());
+//! assert_eq!(-two + two, BlsScalar::zero());
+//! assert_eq!(two * two.invert().unwrap(), BlsScalar::one());
+//!
+//! // The curve equation -x² + y² = 1 + d·x²·y²
+//! let x2 = GENERATOR.get_x().square();
+//! let y2 = GENERATOR.get_y().square();
+//! assert_eq!(y2 - x2, BlsScalar::one() + EDWARDS_D * x2 * y2);
+//! ```
 //!
 //! # Constant Time
 //!
@@ -38,24 +64,63 @@
 #[macro_use]
 extern crate std;
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
 #[cfg(feature = "canon")]
 use canonical_derive::Canon;
+use core::iter::Sum;
 use core::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
 use dusk_bytes::{Error as BytesError, Serializable};
-use subtle::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption};
+use rand_core::{CryptoRng, RngCore};
+use subtle::{
+    Choice, ConditionallyNegatable, ConditionallySelectable, ConstantTimeEq,
+    CtOption,
+};
+
+#[cfg(feature = "alloc")]
+use alloc::vec;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
 
 #[macro_use]
 mod util;
 mod fr;
+mod generator_table;
+
+pub mod dh;
 
 /// Implementation of ElGamal encryption scheme with JubJub
 pub mod elgamal;
 
+#[cfg(feature = "alloc")]
+pub mod hash_to_field;
+
+#[cfg(feature = "std")]
+pub mod io;
+
+pub mod montgomery;
+
+pub mod pedersen;
+pub mod stealth;
+pub mod transcript;
+
+#[cfg(feature = "ff")]
+mod traits;
+
+#[cfg(feature = "serde")]
+mod serde_impl;
+
+#[cfg(feature = "arbitrary")]
+mod arbitrary_impl;
+
 pub use dusk_bls12_381::BlsScalar;
 pub use fr::Fr as JubJubScalar;
 
 pub(crate) use fr::Fr;
 
+use generator_table::GENERATOR_TABLE;
+
 /// A better name than Fr.
 pub type Scalar = Fr;
 
@@ -64,6 +129,24 @@ const FR_MODULUS_BYTES: [u8; 32] = [
     0, 59, 52, 1, 1, 59, 103, 6, 169, 175, 51, 101, 234, 180, 125, 14,
 ];
 
+/// Largest window of [`JubJubExtended::multiscalar_mul_mixed`] without
+/// `alloc`, bounding the buckets it keeps on the stack.
+#[cfg(any(test, not(feature = "alloc")))]
+const MSM_MAX_WINDOW: usize = 4;
+
+/// Largest window of [`JubJubExtended::multiscalar_mul_mixed`] with `alloc`,
+/// where the buckets are kept on the heap.
+#[cfg(feature = "alloc")]
+const MSM_MAX_HEAP_WINDOW: usize = 16;
+
+/// `8^-1 mod r`, the inverse of the cofactor in the scalar field.
+const COFACTOR_INV: Fr = Fr::from_raw([
+    0x5a12e1cbdadee597,
+    0x14cd041279990210,
+    0x20cce76020268760,
+    0x01cfb69d4ca675f5,
+]);
+
 /// This represents a Jubjub point in the affine `(x, y)`
 /// coordinates.
 #[derive(Clone, Copy, Debug)]
@@ -99,6 +182,8 @@ impl PartialEq for JubJubAffine {
     }
 }
 
+impl Eq for JubJubAffine {}
+
 impl ConditionallySelectable for JubJubAffine {
     fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
         JubJubAffine {
@@ -229,6 +314,8 @@ impl PartialEq for JubJubExtended {
     }
 }
 
+impl Eq for JubJubExtended {}
+
 impl Neg for JubJubExtended {
     type Output = JubJubExtended;
 
@@ -296,6 +383,30 @@ pub struct AffineNielsPoint {
 }
 
 impl AffineNielsPoint {
+    /// Returns `digit · P` from the table of multiples `[P, 2P, ..., 8P]`,
+    /// for a `digit` in `[-8, 8]`, scanning the whole table so the access
+    /// pattern and timing are independent of `digit`.
+    pub(crate) fn lookup_signed(table: &[Self; 8], digit: i8) -> Self {
+        let negative = Choice::from((digit as u8) >> 7);
+        let mask = digit >> 7;
+        let abs = ((digit ^ mask) - mask) as u8;
+
+        let mut entry = AffineNielsPoint::identity();
+        for (j, p) in table.iter().enumerate() {
+            entry.conditional_assign(p, (j as u8 + 1).ct_eq(&abs));
+        }
+
+        // The negation of a Niels point swaps `y + x` and `y - x`
+        let negated = AffineNielsPoint {
+            y_plus_x: entry.y_minus_x,
+            y_minus_x: entry.y_plus_x,
+            t2d: -entry.t2d,
+        };
+        entry.conditional_assign(&negated, negative);
+
+        entry
+    }
+
     /// Constructs this point from the neutral element `(0, 1)`.
     pub const fn identity() -> Self {
         AffineNielsPoint {
@@ -407,6 +518,31 @@ impl ExtendedNielsPoint {
         }
     }
 
+    /// Returns `digit · P` from the table of multiples `[P, 2P, ..., 8P]`,
+    /// for a `digit` in `[-8, 8]`, in constant time as
+    /// [`AffineNielsPoint::lookup_signed`] does.
+    pub(crate) fn lookup_signed(table: &[Self; 8], digit: i8) -> Self {
+        let negative = Choice::from((digit as u8) >> 7);
+        let mask = digit >> 7;
+        let abs = ((digit ^ mask) - mask) as u8;
+
+        let mut entry = ExtendedNielsPoint::identity();
+        for (j, p) in table.iter().enumerate() {
+            entry.conditional_assign(p, (j as u8 + 1).ct_eq(&abs));
+        }
+
+        // The negation of a Niels point swaps `y + x` and `y - x`
+        let negated = ExtendedNielsPoint {
+            y_plus_x: entry.y_minus_x,
+            y_minus_x: entry.y_plus_x,
+            z: entry.z,
+            t2d: -entry.t2d,
+        };
+        entry.conditional_assign(&negated, negative);
+
+        entry
+    }
+
     #[inline]
     fn multiply(&self, by: &[u8; 32]) -> JubJubExtended {
         let zero = ExtendedNielsPoint::identity();
@@ -451,7 +587,11 @@ impl<'a, 'b> Mul<&'b Fr> for &'a ExtendedNielsPoint {
 
 impl_binops_multiplicative_mixed!(ExtendedNielsPoint, Fr, JubJubExtended);
 
-/// `d = -(10240/10241)`
+/// `d = -(10240/10241)`, the coefficient of the twisted Edwards form
+/// `-x^2 + y^2 = 1 + d · x^2 · y^2` of Jubjub.
+///
+/// Equal to
+/// `0x2a9318e74bfa2b48f5fd9207e6bd7fd4292d7f6d37579d2601065fd6d6343eb1`.
 pub const EDWARDS_D: BlsScalar = BlsScalar::from_raw([
     0x01065fd6d6343eb1,
     0x292d7f6d37579d26,
@@ -467,17 +607,57 @@ pub const EDWARDS_D2: BlsScalar = BlsScalar::from_raw([
     0x552631ce97f45691,
 ]);
 
+/// `A = 40962`, the coefficient of the Montgomery form
+/// `B · v^2 = u^3 + A · u^2 + u` birationally equivalent to Jubjub.
+///
+/// Being `a = -1` and `d` = [`EDWARDS_D`] the twisted Edwards coefficients,
+/// `A = 2 · (a + d) / (a - d)` and `B = 4 / (a - d)`. The birational map is
+///
+/// * `(x, y) -> (u, v) = ((1 + y) / (1 - y), u / x)`
+/// * `(u, v) -> (x, y) = (u / v, (u - 1) / (u + 1))`
+///
+/// undefined for the points with `x = 0` or `y = 1`, and their images.
+pub const MONTGOMERY_A: BlsScalar = BlsScalar::from_raw([40962, 0, 0, 0]);
+
+/// `B = -40964`, the coefficient of the Montgomery form
+/// `B · v^2 = u^3 + A · u^2 + u` birationally equivalent to Jubjub.
+///
+/// See [`MONTGOMERY_A`] for the birational map.
+pub const MONTGOMERY_B: BlsScalar = BlsScalar::from_raw([
+    0xfffffffeffff5ffd,
+    0x53bda402fffe5bfe,
+    0x3339d80809a1d805,
+    0x73eda753299d7d48,
+]);
+
+/// Parameters of the Jubjub curve, as returned by
+/// [`JubJubExtended::curve_info`].
+///
+/// The moduli and the subgroup order are little endian, as the other byte
+/// representations of the crate.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct CurveInfo {
+    /// Cofactor `h`, such that the order of the curve is `h · r`.
+    pub cofactor: u64,
+    /// Order `r` of the prime order subgroup.
+    pub subgroup_order_bytes: [u8; 32],
+    /// Modulus `q` of the base field [`BlsScalar`].
+    pub base_field_modulus: [u8; 32],
+    /// Modulus of the scalar field [`JubJubScalar`], which is the order `r`
+    /// of the prime order subgroup.
+    pub scalar_field_modulus: [u8; 32],
+}
+
 impl Serializable<32> for JubJubAffine {
     type Error = BytesError;
 
     /// Converts this element into its byte representation.
     fn to_bytes(&self) -> [u8; Self::SIZE] {
         let mut tmp = self.y.to_bytes();
-        let x = self.x.to_bytes();
 
         // Encode the sign of the x-coordinate in the most
         // significant bit.
-        tmp[31] |= x[0] << 7;
+        tmp[31] |= self.sign_bit().unwrap_u8() << 7;
 
         tmp
     }
@@ -489,7 +669,7 @@ impl Serializable<32> for JubJubAffine {
         let mut b = b.clone();
 
         // Grab the sign bit from the representation
-        let sign = b[31] >> 7;
+        let sign = Choice::from(b[31] >> 7);
 
         // Mask away the sign bit
         b[31] &= 0b0111_1111;
@@ -497,6 +677,36 @@ impl Serializable<32> for JubJubAffine {
         // Interpret what remains as the y-coordinate
         let y = BlsScalar::from_bytes(&b)?;
 
+        let point = JubJubAffine::from_y_and_sign(&y, sign).and_then(|p| {
+            // A zero x-coordinate has a single encoding, with the sign unset
+            let canonical = !(p.x.ct_eq(&BlsScalar::zero()) & sign);
+            CtOption::new(p, canonical)
+        });
+
+        Option::from(point).ok_or(BytesError::InvalidData)
+    }
+}
+
+impl JubJubAffine {
+    /// Constructs the neutral element `(0, 1)`.
+    pub const fn identity() -> Self {
+        JubJubAffine {
+            x: BlsScalar::zero(),
+            y: BlsScalar::one(),
+        }
+    }
+
+    /// Returns the sign of the x-coordinate, i.e. the parity of its
+    /// canonical representation, as encoded by [`JubJubAffine::to_bytes`].
+    pub fn sign_bit(&self) -> Choice {
+        Choice::from(self.x.to_bytes()[0] & 1)
+    }
+
+    /// Attempts to recover the point with the given y-coordinate and sign of
+    /// the x-coordinate, as decoded by [`JubJubAffine::from_bytes`].
+    ///
+    /// Fails if there is no point on the curve with the given y-coordinate.
+    pub fn from_y_and_sign(y: &BlsScalar, sign: Choice) -> CtOption<Self> {
         // -x^2 + y^2 = 1 + d.x^2.y^2
         // -x^2 = 1 + d.x^2.y^2 - y^2    (rearrange)
         // -x^2 - d.x^2.y^2 = 1 - y^2    (rearrange)
@@ -510,33 +720,102 @@ impl Serializable<32> for JubJubAffine {
 
         let y2 = y.square();
 
-        Option::from(
-            ((y2 - BlsScalar::one())
-                * ((BlsScalar::one() + EDWARDS_D * &y2)
-                    .invert()
-                    .unwrap_or(BlsScalar::zero())))
-            .sqrt()
-            .and_then(|x| {
-                // Fix the sign of `x` if necessary
-                let flip_sign = Choice::from((x.to_bytes()[0] ^ sign) & 1);
-                let x_negated = -x;
-                let final_x =
-                    BlsScalar::conditional_select(&x, &x_negated, flip_sign);
-
-                CtOption::new(JubJubAffine { x: final_x, y }, Choice::from(1u8))
-            }),
-        )
-        .ok_or(BytesError::InvalidData)
+        ((y2 - BlsScalar::one())
+            * ((BlsScalar::one() + EDWARDS_D * y2)
+                .invert()
+                .unwrap_or(BlsScalar::zero())))
+        .sqrt()
+        .map(|x| {
+            // Fix the sign of `x` if necessary
+            let flip_sign = Choice::from(x.to_bytes()[0] & 1) ^ sign;
+            let x_negated = -x;
+            let x = BlsScalar::conditional_select(&x, &x_negated, flip_sign);
+
+            JubJubAffine { x, y: *y }
+        })
     }
-}
 
-impl JubJubAffine {
-    /// Constructs the neutral element `(0, 1)`.
-    pub const fn identity() -> Self {
-        JubJubAffine {
-            x: BlsScalar::zero(),
-            y: BlsScalar::one(),
+    /// Recovers both points with the given y-coordinate, `(x, y)` and its
+    /// negation `(-x, y)`, with the first one having the even x-coordinate.
+    ///
+    /// Fails if there is no point on the curve with the given y-coordinate.
+    /// For `y = ±1` the x-coordinate is zero, so both points are the same.
+    pub fn decompress_both(y: &BlsScalar) -> CtOption<(Self, Self)> {
+        JubJubAffine::from_y_and_sign(y, Choice::from(0)).map(|p| (p, -p))
+    }
+
+    /// Interprets a byte representation of an affine point as
+    /// [`JubJubAffine::from_bytes`] does, but reducing the `y`-coordinate
+    /// modulo the field modulus instead of rejecting non-canonical
+    /// encodings. The sign bit is also accepted when the x-coordinate is
+    /// zero, which only happens for `y = ±1`.
+    ///
+    /// This is only meant for interoperability with producers that emit
+    /// unreduced coordinates. Every point gets more than one accepted
+    /// encoding, so the encoding is malleable and must not be relied upon
+    /// for uniqueness, such as when hashing or deduplicating points.
+    pub fn from_bytes_lenient(b: &[u8; 32]) -> Result<Self, BytesError> {
+        let sign = Choice::from(b[31] >> 7);
+
+        let mut wide = [0u8; 64];
+        wide[..32].copy_from_slice(b);
+        wide[31] &= 0b0111_1111;
+
+        let y = BlsScalar::from_bytes_wide(&wide);
+
+        Option::from(JubJubAffine::from_y_and_sign(&y, sign))
+            .ok_or(BytesError::InvalidData)
+    }
+
+    /// Encodes the compressed representation of this point, as given by
+    /// [`JubJubAffine::to_bytes`], as a lowercase hexadecimal string.
+    #[cfg(feature = "alloc")]
+    pub fn to_hex(&self) -> alloc::string::String {
+        use core::fmt::Write;
+
+        let mut s = alloc::string::String::with_capacity(64);
+        for b in self.to_bytes().iter() {
+            write!(s, "{:02x}", b).expect("Writing to a String can't fail");
+        }
+        s
+    }
+
+    /// Decodes a point from the hexadecimal representation of its
+    /// compressed encoding, as produced by [`JubJubAffine::to_hex`].
+    ///
+    /// An optional `0x` prefix is accepted, and both lowercase and uppercase
+    /// digits are. The decoded bytes must be a valid, canonical encoding as
+    /// required by [`JubJubAffine::from_bytes`].
+    pub fn from_hex(s: &str) -> Result<Self, BytesError> {
+        let offset = match s.starts_with("0x") {
+            true => 2,
+            false => 0,
+        };
+        let digits = &s.as_bytes()[offset..];
+
+        if digits.len() != 64 {
+            return Err(BytesError::BadLength {
+                found: digits.len(),
+                expected: 64,
+            });
+        }
+
+        let mut bytes = [0u8; 32];
+        for (i, d) in digits.iter().enumerate() {
+            let n = match *d {
+                b'0'..=b'9' => d - b'0',
+                b'a'..=b'f' => d - b'a' + 10,
+                b'A'..=b'F' => d - b'A' + 10,
+                _ => {
+                    let index = offset + i;
+                    let ch = s[index..].chars().next().unwrap_or_default();
+                    return Err(BytesError::InvalidChar { ch, index });
+                }
+            };
+            bytes[i / 2] |= n << (4 * (1 - i % 2));
         }
+
+        JubJubAffine::from_bytes(&bytes)
     }
 
     /// Multiplies this point by the cofactor, producing an
@@ -545,6 +824,12 @@ impl JubJubAffine {
         JubJubExtended::from(*self).mul_by_cofactor()
     }
 
+    /// Negates this point in place if `choice` is set, leaving it untouched
+    /// otherwise, without branching on `choice`.
+    pub fn conditional_negate(&mut self, choice: Choice) {
+        self.x.conditional_negate(choice);
+    }
+
     /// Determines if this point is of small order.
     pub fn is_small_order(&self) -> Choice {
         JubJubExtended::from(*self).is_small_order()
@@ -594,6 +879,23 @@ impl JubJubAffine {
         JubJubAffine { x, y }
     }
 
+    /// Constructs an JubJubAffine given `x` and `y`, checking in constant
+    /// time that the point is on the curve and in the prime order subgroup.
+    ///
+    /// Use [`JubJubAffine::from_raw_unchecked`] for trusted coordinates, and
+    /// [`JubJubAffine::get_x`] and [`JubJubAffine::get_y`] to read them back.
+    pub fn from_coordinates(x: BlsScalar, y: BlsScalar) -> CtOption<Self> {
+        let x2 = x.square();
+        let y2 = y.square();
+        let on_curve =
+            (y2 - x2).ct_eq(&(BlsScalar::one() + EDWARDS_D * x2 * y2));
+
+        let p = JubJubAffine { x, y };
+        let torsion_free = JubJubExtended::from(p).is_torsion_free();
+
+        CtOption::new(p, on_curve & torsion_free)
+    }
+
     /// This is only for debugging purposes and not
     /// exposed in the public API. Checks that this
     /// point is on the curve.
@@ -632,6 +934,23 @@ impl JubJubExtended {
         self.t2
     }
 
+    /// Constructs an extended point from its raw coordinates, without
+    /// checking that it is on the curve or that `t1 · t2 = x · y / z`.
+    ///
+    /// This is meant for precomputed tables embedded as constants. Passing
+    /// coordinates that don't represent a valid point is a logic error, and
+    /// the results of every following operation on the point are
+    /// unspecified.
+    pub const fn from_raw_unchecked(
+        x: BlsScalar,
+        y: BlsScalar,
+        z: BlsScalar,
+        t1: BlsScalar,
+        t2: BlsScalar,
+    ) -> JubJubExtended {
+        JubJubExtended { x, y, z, t1, t2 }
+    }
+
     /// Constructs an extended point from the neutral element `(0, 1)`.
     pub const fn identity() -> Self {
         JubJubExtended {
@@ -674,11 +993,149 @@ impl JubJubExtended {
         self.is_torsion_free() & (!self.is_identity())
     }
 
+    /// Determines if this point is equal to the affine point `other`,
+    /// without converting either side.
+    ///
+    /// (x/z, y/z) = (x', y') is implied by (x = x'z) and (y = y'z), so the
+    /// comparison costs two multiplications rather than an inversion.
+    pub fn eq_affine(&self, other: &JubJubAffine) -> Choice {
+        self.x.ct_eq(&(other.x * self.z)) & self.y.ct_eq(&(other.y * self.z))
+    }
+
+    /// Generate a uniformly random point of the prime order subgroup,
+    /// computed as [`GENERATOR_EXTENDED`] times a random [`Fr`] with
+    /// [`mul_by_generator`].
+    pub fn random<R: RngCore + CryptoRng>(rng: &mut R) -> Self {
+        mul_by_generator(&Fr::random(rng))
+    }
+
+    /// Returns the 8 points of the torsion subgroup, which are the points
+    /// sent to the identity by [`JubJubExtended::mul_by_cofactor`].
+    ///
+    /// The `i`-th point is `T · (i + 1)` for a generator `T` of the
+    /// subgroup, so the last point is the identity. Adding any of them to
+    /// a point leaves its prime order component untouched, which makes them
+    /// useful to build malicious inputs in negative tests.
+    pub fn torsion_subgroup() -> [JubJubExtended; 8] {
+        let mut points = [JubJubExtended::identity(); 8];
+        points
+            .iter_mut()
+            .zip(EIGHT_TORSION.iter())
+            .for_each(|(p, t)| *p = JubJubExtended::from(*t));
+
+        points
+    }
+
+    /// Adds a point of the torsion subgroup, picked at random among the
+    /// [`JubJubExtended::torsion_subgroup`], to this point.
+    ///
+    /// The result is on the curve but, unless the identity was picked, not
+    /// in the prime order subgroup, while [`JubJubExtended::mul_by_cofactor`]
+    /// stays unchanged. This is meant to fuzz the validation of untrusted
+    /// points, and is only available with the `test-utils` feature.
+    #[cfg(feature = "test-utils")]
+    pub fn add_random_torsion<R: RngCore>(&self, rng: &mut R) -> Self {
+        let index = (rng.next_u32() % 8) as usize;
+        self + JubJubExtended::from(EIGHT_TORSION[index])
+    }
+
     /// Multiplies this element by the cofactor `8`.
     pub fn mul_by_cofactor(&self) -> JubJubExtended {
         self.double().double().double()
     }
 
+    /// Multiplies this element by `8^-1 mod r`, the inverse of the cofactor
+    /// in the scalar field.
+    ///
+    /// On the prime order subgroup, this is the inverse of
+    /// [`JubJubExtended::mul_by_cofactor`], so
+    /// `p.mul_by_cofactor().mul_by_cofactor_inv() == p`. For any other point,
+    /// `mul_by_cofactor` clears the torsion component first, so the same
+    /// composition returns the prime order component of the point.
+    pub fn mul_by_cofactor_inv(&self) -> JubJubExtended {
+        self * COFACTOR_INV
+    }
+
+    /// Multiplies this point by `scalar`, or returns `None` if the point is
+    /// of small order, including the identity.
+    ///
+    /// The product of such a point is confined to the torsion subgroup, so
+    /// it can take at most 8 values and hides nothing about `scalar`. This
+    /// guards the protocols that multiply an untrusted base by a secret.
+    pub fn mul_checked(&self, scalar: &Fr) -> Option<JubJubExtended> {
+        if bool::from(self.is_small_order()) {
+            None
+        } else {
+            Some(self * scalar)
+        }
+    }
+
+    /// Returns the parameters of the curve: its cofactor, the order of the
+    /// prime order subgroup and the moduli of both fields.
+    pub const fn curve_info() -> CurveInfo {
+        CurveInfo {
+            cofactor: 8,
+            subgroup_order_bytes: FR_MODULUS_BYTES,
+            base_field_modulus: [
+                1, 0, 0, 0, 255, 255, 255, 255, 254, 91, 254, 255, 2, 164, 189,
+                83, 5, 216, 161, 9, 8, 216, 57, 51, 72, 125, 157, 41, 83, 167,
+                237, 115,
+            ],
+            scalar_field_modulus: FR_MODULUS_BYTES,
+        }
+    }
+
+    /// Returns `table[index]`, or the identity if `index` is out of bounds,
+    /// scanning every entry with [`ConditionallySelectable`] so the access
+    /// pattern and timing are independent of `index`.
+    ///
+    /// This is [`lookup`] for tables of any size, rather than the windowed
+    /// tables of at most 256 entries indexed by a digit.
+    pub fn ct_select(table: &[JubJubExtended], index: u32) -> JubJubExtended {
+        let index = index as u64;
+
+        table.iter().enumerate().fold(
+            JubJubExtended::identity(),
+            |acc, (i, p)| {
+                JubJubExtended::conditional_select(
+                    &acc,
+                    p,
+                    (i as u64).ct_eq(&index),
+                )
+            },
+        )
+    }
+
+    /// Assigns every element of `src` to the element of `dst` at the same
+    /// position if `choice` is set, leaving `dst` untouched otherwise.
+    ///
+    /// Every element is written with [`ConditionallySelectable`], so the
+    /// timing and memory access pattern are independent of `choice`.
+    ///
+    /// # Panics
+    ///
+    /// If `dst` and `src` have different lengths.
+    pub fn conditional_assign_slice(
+        dst: &mut [JubJubExtended],
+        src: &[JubJubExtended],
+        choice: Choice,
+    ) {
+        assert_eq!(dst.len(), src.len(), "Slices must have the same length");
+
+        dst.iter_mut()
+            .zip(src.iter())
+            .for_each(|(d, s)| d.conditional_assign(s, choice));
+    }
+
+    /// Negates this point in place if `choice` is set, leaving it untouched
+    /// otherwise, without branching on `choice`.
+    ///
+    /// As with [`Neg`], only `X` and `T1` are negated.
+    pub fn conditional_negate(&mut self, choice: Choice) {
+        self.x.conditional_negate(choice);
+        self.t1.conditional_negate(choice);
+    }
+
     /// Performs a pre-processing step that produces an `ExtendedNielsPoint`
     /// for use in multiple additions.
     pub fn to_niels(&self) -> ExtendedNielsPoint {
@@ -690,6 +1147,384 @@ impl JubJubExtended {
         }
     }
 
+    /// Dumps the internal representation of the extended coordinates
+    /// `(X, Y, Z, T)` without normalizing the point, avoiding the inversion
+    /// required by [`JubJubAffine::to_bytes`].
+    ///
+    /// This is meant only for trusted internal caches: the encoding is not
+    /// canonical, since the same point has many projective representations,
+    /// and it is not interoperable with any other serialization.
+    pub fn to_bytes_projective(&self) -> [u8; 128] {
+        let t = self.t1 * self.t2;
+
+        let mut bytes = [0u8; 128];
+        [self.x, self.y, self.z, t]
+            .iter()
+            .zip(bytes.chunks_exact_mut(32))
+            .for_each(|(c, chunk)| {
+                c.internal_repr()
+                    .iter()
+                    .zip(chunk.chunks_exact_mut(8))
+                    .for_each(|(l, b)| b.copy_from_slice(&l.to_le_bytes()));
+            });
+
+        bytes
+    }
+
+    /// Loads a point from a [`JubJubExtended::to_bytes_projective`] dump.
+    ///
+    /// No validation is performed: the bytes must come from a trusted
+    /// source, or the result is an arbitrary, possibly invalid, point.
+    pub fn from_bytes_projective_unchecked(bytes: &[u8; 128]) -> Self {
+        let mut coordinates = [BlsScalar::zero(); 4];
+        coordinates.iter_mut().zip(bytes.chunks_exact(32)).for_each(
+            |(c, chunk)| {
+                let mut limbs = [0u64; 4];
+                limbs.iter_mut().zip(chunk.chunks_exact(8)).for_each(
+                    |(l, b)| {
+                        let mut buf = [0u8; 8];
+                        buf.copy_from_slice(b);
+                        *l = u64::from_le_bytes(buf);
+                    },
+                );
+                *c = BlsScalar(limbs);
+            },
+        );
+
+        JubJubExtended {
+            x: coordinates[0],
+            y: coordinates[1],
+            z: coordinates[2],
+            t1: coordinates[3],
+            t2: BlsScalar::one(),
+        }
+    }
+
+    /// Dumps the five extended coordinates `(X, Y, Z, T1, T2)`, each as the
+    /// 32 little endian bytes of [`BlsScalar::to_bytes`].
+    ///
+    /// This is meant for debugging and comparing intermediate values with
+    /// other implementations. The encoding is not canonical, since the same
+    /// point has many projective representations and `T = T1 · T2` can be
+    /// split in many ways; use [`JubJubAffine::to_bytes`] to serialize
+    /// points.
+    pub fn to_raw_bytes(&self) -> [u8; 160] {
+        let mut bytes = [0u8; 160];
+        [self.x, self.y, self.z, self.t1, self.t2]
+            .iter()
+            .zip(bytes.chunks_exact_mut(32))
+            .for_each(|(c, chunk)| chunk.copy_from_slice(&c.to_bytes()));
+
+        bytes
+    }
+
+    /// Loads a point from a [`JubJubExtended::to_raw_bytes`] dump.
+    ///
+    /// Fails with [`BytesError::InvalidData`] if a coordinate isn't
+    /// canonical, `Z` is zero, or the coordinates don't satisfy the curve
+    /// equations `-X^2 + Y^2 = Z^2 + d · T^2` and `X · Y = Z · T`. The point
+    /// is not checked to be in the prime order subgroup.
+    pub fn from_raw_bytes(bytes: &[u8; 160]) -> Result<Self, BytesError> {
+        let mut coordinates = [BlsScalar::zero(); 5];
+        for (c, chunk) in coordinates.iter_mut().zip(bytes.chunks_exact(32)) {
+            let mut buf = [0u8; 32];
+            buf.copy_from_slice(chunk);
+            *c = BlsScalar::from_bytes(&buf)?;
+        }
+
+        let [x, y, z, t1, t2] = coordinates;
+        let t = t1 * t2;
+
+        let xx = x.square();
+        let yy = y.square();
+        let zz = z.square();
+        let on_curve = (yy - xx).ct_eq(&(zz + EDWARDS_D * t.square()))
+            & (x * y).ct_eq(&(z * t))
+            & !z.ct_eq(&BlsScalar::zero());
+
+        if bool::from(on_curve) {
+            Ok(JubJubExtended { x, y, z, t1, t2 })
+        } else {
+            Err(BytesError::InvalidData)
+        }
+    }
+
+    /// Computes `scalar_g · G - scalar_p · point`, being `G` the
+    /// [`GENERATOR_EXTENDED`], as found in verification equations such as
+    /// `R = s · G - c · A`.
+    ///
+    /// Both multiplications are interleaved with Straus' (Shamir's) trick,
+    /// sharing a single chain of doublings over a table of
+    /// `{0, G, -point, G - point}`.
+    pub fn sg_minus(
+        scalar_g: &Fr,
+        point: &JubJubExtended,
+        scalar_p: &Fr,
+    ) -> JubJubExtended {
+        let neg_p = -*point;
+        let table = [
+            ExtendedNielsPoint::identity(),
+            GENERATOR_EXTENDED.to_niels(),
+            neg_p.to_niels(),
+            (GENERATOR_EXTENDED + neg_p).to_niels(),
+        ];
+
+        let bits = |scalar: &Fr| {
+            let bytes = scalar.to_bytes();
+            (0..256).rev().map(move |i| (bytes[i / 8] >> (i % 8)) & 1)
+        };
+
+        let mut acc = JubJubExtended::identity();
+
+        // We skip the leading four bits because they're always
+        // unset for Fr.
+        for (bg, bp) in bits(scalar_g).zip(bits(scalar_p)).skip(4) {
+            let index = bg | (bp << 1);
+
+            let mut entry = table[0];
+            for (i, p) in table.iter().enumerate().skip(1) {
+                entry.conditional_assign(p, (i as u8).ct_eq(&index));
+            }
+
+            acc = acc.double();
+            acc += entry;
+        }
+
+        acc
+    }
+
+    /// Computes `a · base1 + b · base2`, interleaving both multiplications
+    /// over the width-5 NAF of the scalars (Straus' method), so they share a
+    /// single chain of doublings.
+    ///
+    /// This function is variable time, and must be used only with public
+    /// scalars, such as in signature verification.
+    pub fn mul_double_vartime(
+        a: &Fr,
+        base1: &JubJubExtended,
+        b: &Fr,
+        base2: &JubJubExtended,
+    ) -> JubJubExtended {
+        const WIDTH: u8 = 5;
+
+        // Odd multiples `[P, 3P, 5P, ..., 15P]`
+        let odd_multiples = |p: &JubJubExtended| {
+            let p2 = p.double();
+            let mut table = [p.to_niels(); 1 << (WIDTH - 2)];
+            let mut acc = *p;
+            for entry in table.iter_mut().skip(1) {
+                acc += p2;
+                *entry = acc.to_niels();
+            }
+            table
+        };
+
+        let table1 = odd_multiples(base1);
+        let table2 = odd_multiples(base2);
+
+        let naf1 = a.compute_windowed_naf(WIDTH);
+        let naf2 = b.compute_windowed_naf(WIDTH);
+
+        let mut acc = JubJubExtended::identity();
+        for (d1, d2) in naf1.iter().zip(naf2.iter()).rev() {
+            acc = acc.double();
+
+            for (d, table) in [(*d1, &table1), (*d2, &table2)].iter() {
+                match d.signum() {
+                    1 => acc += table[(d / 2) as usize],
+                    -1 => acc -= table[(-d / 2) as usize],
+                    _ => (),
+                }
+            }
+        }
+
+        acc
+    }
+
+    /// Computes `base · p(x)`, being `p(x) = Σ coeffs[i] · x^i`.
+    ///
+    /// The polynomial is evaluated in [`Fr`] with Horner's rule, so a single
+    /// point multiplication is performed. This is the same as
+    /// `Σ coeffs[i] · (base · x^i)` without computing the powers of `base`.
+    pub fn eval_in_exponent(
+        base: &JubJubExtended,
+        coeffs: &[Fr],
+        x: &Fr,
+    ) -> JubJubExtended {
+        let eval = coeffs.iter().rev().fold(Fr::zero(), |acc, c| acc * x + c);
+
+        base * eval
+    }
+
+    /// Multiplies this point by the integer whose big-endian binary
+    /// representation is given by `bits`, with a double-and-add over the
+    /// stream.
+    ///
+    /// The scalar is not reduced, so any number of bits may be fed. This is
+    /// intended for inputs that are not available as an [`Fr`], such as the
+    /// bit decomposition used within a circuit.
+    ///
+    /// This function is variable time, and must be used only with public
+    /// scalars.
+    pub fn mul_bits_be<I>(&self, bits: I) -> JubJubExtended
+    where
+        I: Iterator<Item = bool>,
+    {
+        let niels = self.to_niels();
+
+        bits.fold(JubJubExtended::identity(), |acc, bit| {
+            let acc = acc.double();
+            if bit {
+                acc + niels
+            } else {
+                acc
+            }
+        })
+    }
+
+    /// Computes `Σ scalars[i] · points[i]` with Pippenger's bucket method.
+    ///
+    /// The affine points are added to the buckets with mixed additions, so
+    /// they are never converted to [`JubJubExtended`].
+    ///
+    /// With `alloc` the scalar encodings and the Niels form of the points are
+    /// computed once, and the window grows up to 16 bits with the buckets on
+    /// the heap. Without it no allocation is performed: the window is capped
+    /// at 4 bits, so the at most 15 buckets of a window take about 2.4 KB of
+    /// stack.
+    ///
+    /// This function is variable time, and must be used only with public
+    /// scalars.
+    ///
+    /// # Panics
+    ///
+    /// If `scalars` and `points` have different lengths.
+    pub fn multiscalar_mul_mixed(
+        scalars: &[Fr],
+        points: &[JubJubAffine],
+    ) -> JubJubExtended {
+        assert_eq!(scalars.len(), points.len(), "Each point requires a scalar");
+
+        // Window size close to `ln(n)`, as 0.69 ≈ ln(2)
+        let log2 = (usize::BITS - points.len().leading_zeros()) as usize;
+        let c = log2 * 69 / 100 + 2;
+
+        #[cfg(feature = "alloc")]
+        {
+            let c = c.clamp(2, MSM_MAX_HEAP_WINDOW);
+
+            let bytes: Vec<[u8; 32]> =
+                scalars.iter().map(|s| s.to_bytes()).collect();
+            let niels: Vec<AffineNielsPoint> =
+                points.iter().map(|p| p.to_niels()).collect();
+            let mut buckets = vec![JubJubExtended::identity(); (1 << c) - 1];
+
+            Self::pippenger(
+                c,
+                points.len(),
+                &mut buckets,
+                |i| bytes[i],
+                |i| niels[i],
+            )
+        }
+
+        #[cfg(not(feature = "alloc"))]
+        {
+            let c = c.clamp(2, MSM_MAX_WINDOW);
+
+            let mut buckets =
+                [JubJubExtended::identity(); (1 << MSM_MAX_WINDOW) - 1];
+
+            Self::pippenger(
+                c,
+                points.len(),
+                &mut buckets[..(1 << c) - 1],
+                |i| scalars[i].to_bytes(),
+                |i| points[i].to_niels(),
+            )
+        }
+    }
+
+    /// Bucket method over windows of `c` bits, with a bucket for each of the
+    /// non-zero digits. `bytes` and `niels` provide the scalar encoding and
+    /// the point for each of the `len` terms of the sum.
+    fn pippenger<B, N>(
+        c: usize,
+        len: usize,
+        buckets: &mut [JubJubExtended],
+        bytes: B,
+        niels: N,
+    ) -> JubJubExtended
+    where
+        B: Fn(usize) -> [u8; 32],
+        N: Fn(usize) -> AffineNielsPoint,
+    {
+        debug_assert_eq!(buckets.len(), (1 << c) - 1);
+
+        let digit = |bytes: &[u8; 32], offset: usize| {
+            (offset..offset + c).rev().fold(0usize, |acc, i| {
+                let bit = bytes.get(i / 8).map_or(0, |b| (b >> (i % 8)) & 1);
+                (acc << 1) | bit as usize
+            })
+        };
+
+        let windows = 252usize.div_ceil(c);
+        let mut acc = JubJubExtended::identity();
+
+        for w in (0..windows).rev() {
+            for _ in 0..c {
+                acc = acc.double();
+            }
+
+            buckets
+                .iter_mut()
+                .for_each(|b| *b = JubJubExtended::identity());
+
+            for i in 0..len {
+                let d = digit(&bytes(i), w * c);
+                if d != 0 {
+                    buckets[d - 1] += niels(i);
+                }
+            }
+
+            // Σ d · bucket[d] as a running sum
+            let mut running = JubJubExtended::identity();
+            let mut sum = JubJubExtended::identity();
+            for bucket in buckets.iter().rev() {
+                running += bucket;
+                sum += running;
+            }
+
+            acc += sum;
+        }
+
+        acc
+    }
+
+    /// Compresses many points at once, returning the same encodings as
+    /// `JubJubAffine::from(p).to_bytes()` for each point.
+    ///
+    /// The points are normalized with [`batch_normalize`], so a single field
+    /// inversion is performed for the whole slice.
+    #[cfg(feature = "alloc")]
+    pub fn batch_to_bytes(points: &[JubJubExtended]) -> Vec<[u8; 32]> {
+        let mut points = points.to_vec();
+        batch_normalize(&mut points).map(|p| p.to_bytes()).collect()
+    }
+
+    /// Computes `self · scalars[i]` for every scalar, returning the same
+    /// points as multiplying one scalar at a time.
+    ///
+    /// A fixed-base table of `self` is computed once and shared across the
+    /// whole batch, so every multiplication costs 64 mixed additions and no
+    /// doublings. The cost of the table is amortized for batches larger than
+    /// a few dozens of scalars.
+    #[cfg(feature = "alloc")]
+    pub fn batch_mul(&self, scalars: &[Fr]) -> Vec<JubJubExtended> {
+        let table = FixedBaseTable::new(self);
+        scalars.iter().map(|s| table.mul(s)).collect()
+    }
+
     /// Returns two scalars suitable for hashing that represent the
     /// Extended Point.
     pub fn to_hash_inputs(&self) -> [BlsScalar; 2] {
@@ -825,6 +1660,36 @@ impl<'a, 'b> Mul<&'b Fr> for &'a JubJubExtended {
 
 impl_binops_multiplicative!(JubJubExtended, Fr);
 
+impl Mul<&JubJubExtended> for &Fr {
+    type Output = JubJubExtended;
+
+    fn mul(self, other: &JubJubExtended) -> JubJubExtended {
+        other * self
+    }
+}
+
+impl_binops_multiplicative_mixed!(Fr, JubJubExtended, JubJubExtended);
+
+impl Mul<&Fr> for &JubJubAffine {
+    type Output = JubJubExtended;
+
+    fn mul(self, other: &Fr) -> JubJubExtended {
+        self.to_niels().multiply(&other.to_bytes())
+    }
+}
+
+impl_binops_multiplicative_mixed!(JubJubAffine, Fr, JubJubExtended);
+
+impl Mul<&JubJubAffine> for &Fr {
+    type Output = JubJubExtended;
+
+    fn mul(self, other: &JubJubAffine) -> JubJubExtended {
+        other * self
+    }
+}
+
+impl_binops_multiplicative_mixed!(Fr, JubJubAffine, JubJubExtended);
+
 impl<'a, 'b> Add<&'b ExtendedNielsPoint> for &'a JubJubExtended {
     type Output = JubJubExtended;
 
@@ -974,6 +1839,30 @@ impl<'a, 'b> Sub<&'b JubJubAffine> for &'a JubJubExtended {
 
 impl_binops_additive!(JubJubExtended, JubJubAffine);
 
+impl Add<&JubJubAffine> for &JubJubAffine {
+    type Output = JubJubExtended;
+
+    #[inline]
+    fn add(self, other: &JubJubAffine) -> JubJubExtended {
+        JubJubExtended::from(*self) + other.to_niels()
+    }
+}
+
+impl Sub<&JubJubAffine> for &JubJubAffine {
+    type Output = JubJubExtended;
+
+    #[inline]
+    fn sub(self, other: &JubJubAffine) -> JubJubExtended {
+        JubJubExtended::from(*self) - other.to_niels()
+    }
+}
+
+impl_binops_additive_specify_output!(
+    JubJubAffine,
+    JubJubAffine,
+    JubJubExtended
+);
+
 /// This is a "completed" point produced during a point doubling or
 /// addition routine. These points exist in the `(X:Z, Y:T)` model
 /// of the curve. This is not exposed in the API because it is
@@ -1019,6 +1908,24 @@ impl Default for JubJubExtended {
     }
 }
 
+impl Sum for JubJubExtended {
+    fn sum<I>(iter: I) -> Self
+    where
+        I: Iterator<Item = Self>,
+    {
+        iter.fold(JubJubExtended::identity(), |acc, p| acc + p)
+    }
+}
+
+impl<'a> Sum<&'a JubJubExtended> for JubJubExtended {
+    fn sum<I>(iter: I) -> Self
+    where
+        I: Iterator<Item = &'a JubJubExtended>,
+    {
+        iter.fold(JubJubExtended::identity(), |acc, p| acc + p)
+    }
+}
+
 /// This takes a mutable slice of `JubJubExtended`s and "normalizes" them using
 /// only a single inversion for the entire batch. This normalization results in
 /// all of the points having a Z-coordinate of one. Further, an iterator is
@@ -1066,6 +1973,256 @@ pub fn batch_normalize<'a>(
     y.iter().map(|p| JubJubAffine { x: p.x, y: p.y })
 }
 
+/// Serializes a slice of `JubJubExtended`s into `out`, writing the 32-byte
+/// compressed encoding of each point in sequence.
+///
+/// Fails with [`BytesError::BadLength`] unless `out` is exactly
+/// `points.len() * 32` bytes long.
+pub fn serialize_points(
+    points: &[JubJubExtended],
+    out: &mut [u8],
+) -> Result<(), BytesError> {
+    let expected = points.len() * JubJubAffine::SIZE;
+    if out.len() != expected {
+        return Err(BytesError::BadLength {
+            found: out.len(),
+            expected,
+        });
+    }
+
+    points
+        .iter()
+        .zip(out.chunks_exact_mut(JubJubAffine::SIZE))
+        .for_each(|(p, chunk)| {
+            chunk.copy_from_slice(&JubJubAffine::from(p).to_bytes())
+        });
+
+    Ok(())
+}
+
+/// Deserializes a [`serialize_points`] construction into `out`, validating
+/// each point.
+///
+/// Fails with [`BytesError::BadLength`] unless `bytes` is exactly
+/// `out.len() * 32` bytes long, and with [`BytesError::InvalidData`] if any
+/// of the encodings is not a valid point.
+///
+/// The points are decoded in place, so on [`BytesError::InvalidData`] `out`
+/// is left partially overwritten: the points preceding the first invalid
+/// encoding are written, and the following ones are untouched.
+pub fn deserialize_points(
+    bytes: &[u8],
+    out: &mut [JubJubExtended],
+) -> Result<(), BytesError> {
+    let expected = out.len() * JubJubAffine::SIZE;
+    if bytes.len() != expected {
+        return Err(BytesError::BadLength {
+            found: bytes.len(),
+            expected,
+        });
+    }
+
+    let mut buf = [0u8; JubJubAffine::SIZE];
+    for (p, chunk) in out.iter_mut().zip(bytes.chunks_exact(JubJubAffine::SIZE))
+    {
+        buf.copy_from_slice(chunk);
+        *p = JubJubAffine::from_bytes(&buf)?.into();
+    }
+
+    Ok(())
+}
+
+/// Precomputed multiples `j · 16^i · B` of a fixed base `B`, for every 4-bit
+/// window `i` of a scalar and every digit `j`.
+///
+/// A multiplication is then a sum of one table entry per window, with no
+/// doublings.
+#[cfg(feature = "alloc")]
+pub(crate) struct FixedBaseTable(Vec<[AffineNielsPoint; 16]>);
+
+#[cfg(feature = "alloc")]
+impl FixedBaseTable {
+    const WINDOWS: usize = 64;
+
+    pub(crate) fn new(base: &JubJubExtended) -> Self {
+        let mut points = Vec::with_capacity(Self::WINDOWS * 16);
+
+        let mut window_base = *base;
+        for _ in 0..Self::WINDOWS {
+            let mut p = JubJubExtended::identity();
+            for _ in 0..16 {
+                points.push(p);
+                p += window_base;
+            }
+            window_base = p;
+        }
+
+        let mut table = Vec::with_capacity(Self::WINDOWS);
+        let mut window = [AffineNielsPoint::identity(); 16];
+        for (i, p) in batch_normalize(&mut points).enumerate() {
+            window[i % 16] = p.to_niels();
+            if i % 16 == 15 {
+                table.push(window);
+            }
+        }
+
+        Self(table)
+    }
+
+    /// Multiply the base by `scalar` in constant time
+    pub(crate) fn mul(&self, scalar: &JubJubScalar) -> JubJubExtended {
+        let bytes = scalar.to_bytes();
+
+        self.0.iter().enumerate().fold(
+            JubJubExtended::identity(),
+            |acc, (i, window)| {
+                let digit = (bytes[i / 2] >> (4 * (i % 2))) & 0x0f;
+
+                let mut entry = AffineNielsPoint::identity();
+                for (j, p) in window.iter().enumerate() {
+                    entry.conditional_assign(p, (j as u8).ct_eq(&digit));
+                }
+
+                acc + entry
+            },
+        )
+    }
+}
+
+/// Returns `table[index]`, or the identity if `index` is out of bounds,
+/// scanning the whole table with [`ConditionallySelectable`] so the access
+/// pattern and timing are independent of `index`.
+///
+/// This is the building block for constant-time windowed multiplication over
+/// a precomputed table indexed by secret digits.
+pub fn lookup(table: &[JubJubExtended], index: u8) -> JubJubExtended {
+    JubJubExtended::ct_select(table, index as u32)
+}
+
+/// Computes `GENERATOR_EXTENDED · scalar` in constant time, using a built-in
+/// fixed-base table of the generator.
+///
+/// The scalar is recoded into 64 signed radix-16 digits, and every digit
+/// selects one of the 8 precomputed multiples of its window, so the
+/// multiplication costs 64 mixed additions and 4 doublings instead of a
+/// variable-base double-and-add.
+pub fn mul_by_generator(scalar: &Fr) -> JubJubExtended {
+    let digits = scalar.to_radix_16();
+    let select = AffineNielsPoint::lookup_signed;
+
+    // Σ d_i · 16^i · G = 16 · Σ d_(2k+1) · 256^k · G + Σ d_2k · 256^k · G
+    let mut acc = JubJubExtended::identity();
+    for (k, window) in GENERATOR_TABLE.iter().enumerate() {
+        acc += select(window, digits[2 * k + 1]);
+    }
+    acc = acc.double().double().double().double();
+    for (k, window) in GENERATOR_TABLE.iter().enumerate() {
+        acc += select(window, digits[2 * k]);
+    }
+
+    acc
+}
+
+/// Precomputed schedule to check many points for membership in the prime
+/// order subgroup.
+///
+/// [`JubJubExtended::is_torsion_free`] multiplies the point by the group
+/// order `r` with a plain double-and-add. The checker stores the width-5 NAF
+/// of `r` once, so every check only needs a short table of odd multiples of
+/// the point and fewer additions.
+///
+/// The schedule depends only on the public constant `r`, so the checks are
+/// constant time with respect to the point.
+#[derive(Debug, Clone)]
+pub struct SubgroupChecker {
+    naf: [i8; 256],
+}
+
+impl Default for SubgroupChecker {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SubgroupChecker {
+    const WIDTH: u32 = 5;
+
+    /// Compute the NAF of the group order.
+    pub fn new() -> Self {
+        let mut k = [0u64; 5];
+        for (limb, bytes) in k.iter_mut().zip(FR_MODULUS_BYTES.chunks(8)) {
+            let mut buf = [0u8; 8];
+            buf.copy_from_slice(bytes);
+            *limb = u64::from_le_bytes(buf);
+        }
+
+        let radix = 1i64 << Self::WIDTH;
+        let mut naf = [0i8; 256];
+
+        let mut i = 0;
+        while k.iter().any(|limb| *limb != 0) {
+            if k[0] & 1 == 1 {
+                let mut digit = (k[0] & (radix as u64 - 1)) as i64;
+                if digit >= radix / 2 {
+                    digit -= radix;
+                }
+                naf[i] = digit as i8;
+
+                // k -= digit, propagating the borrow or carry
+                if digit > 0 {
+                    let mut borrow = digit as u64;
+                    for limb in k.iter_mut() {
+                        let (l, b) = limb.overflowing_sub(borrow);
+                        *limb = l;
+                        borrow = b as u64;
+                    }
+                } else {
+                    let mut carry = (-digit) as u64;
+                    for limb in k.iter_mut() {
+                        let (l, c) = limb.overflowing_add(carry);
+                        *limb = l;
+                        carry = c as u64;
+                    }
+                }
+            }
+
+            for j in 0..4 {
+                k[j] = (k[j] >> 1) | (k[j + 1] << 63);
+            }
+            k[4] >>= 1;
+            i += 1;
+        }
+
+        Self { naf }
+    }
+
+    /// Determines if `point` is torsion free, computing the same result as
+    /// [`JubJubExtended::is_torsion_free`].
+    pub fn is_torsion_free(&self, point: &JubJubExtended) -> Choice {
+        // Odd multiples `[P, 3P, 5P, ..., 15P]`
+        let p2 = point.double();
+        let mut table = [point.to_niels(); 1 << (Self::WIDTH - 2)];
+        let mut acc = *point;
+        for entry in table.iter_mut().skip(1) {
+            acc += p2;
+            *entry = acc.to_niels();
+        }
+
+        let mut acc = JubJubExtended::identity();
+        for d in self.naf.iter().rev() {
+            acc = acc.double();
+
+            match d.signum() {
+                1 => acc += table[(d / 2) as usize],
+                -1 => acc -= table[(-d / 2) as usize],
+                _ => (),
+            }
+        }
+
+        acc.is_identity()
+    }
+}
+
 #[test]
 fn test_is_on_curve_var() {
     assert!(JubJubAffine::identity().is_on_curve_vartime());
@@ -1233,7 +2390,8 @@ const FULL_GENERATOR: JubJubAffine = JubJubAffine::from_raw_unchecked(
     BlsScalar::from_raw([0xb, 0x0, 0x0, 0x0]),
 );
 
-#[cfg(test)]
+// The torsion subgroup of order 8, where the `i`-th point is `T · (i + 1)`
+// for a generator `T` of the subgroup.
 const EIGHT_TORSION: [JubJubAffine; 8] = [
     JubJubAffine::from_raw_unchecked(
         BlsScalar::from_raw([
@@ -1381,6 +2539,26 @@ fn test_small_order() {
     }
 }
 
+#[test]
+fn test_small_order_extended() {
+    for point in EIGHT_TORSION.iter() {
+        let point = JubJubExtended::from(*point);
+        assert!(point.is_small_order().unwrap_u8() == 1);
+    }
+
+    let mut p = GENERATOR_EXTENDED;
+    for _ in 0..20 {
+        assert!(p.is_small_order().unwrap_u8() == 0);
+        assert!(JubJubAffine::from(p).is_small_order().unwrap_u8() == 0);
+
+        // Torsion components don't change the prime-order part
+        let q = p + JubJubExtended::from(EIGHT_TORSION[3]);
+        assert!(q.is_small_order().unwrap_u8() == 0);
+
+        p = p.double() + GENERATOR_NUMS_EXTENDED;
+    }
+}
+
 #[ignore]
 #[test]
 fn second_gen_nums() {
@@ -1478,6 +2656,1224 @@ fn test_mul_consistency() {
     assert_eq!(p_affine_niels * c, (p_affine_niels * a) * b);
 }
 
+#[test]
+fn test_scalar_on_left_mul() {
+    use rand_core::OsRng;
+
+    for _ in 0..10 {
+        let s = Fr::random(&mut OsRng);
+        let p = GENERATOR_EXTENDED * Fr::random(&mut OsRng);
+        let p_affine = JubJubAffine::from(p);
+
+        let (s_ref, p_ref, p_affine_ref) = (&s, &p, &p_affine);
+
+        assert_eq!(s * p, p * s);
+        assert_eq!(s * p_ref, p * s);
+        assert_eq!(s_ref * p_ref, p * s);
+
+        assert_eq!(p_affine * s, p * s);
+        assert_eq!(s * p_affine, p * s);
+        assert_eq!(s * p_affine_ref, p * s);
+        assert_eq!(s_ref * p_affine_ref, p * s);
+    }
+}
+
+#[test]
+fn test_serialize_points() {
+    fn round_trip<const N: usize>() {
+        let mut points = [JubJubExtended::identity(); N];
+        let mut p = GENERATOR_EXTENDED;
+        for point in points.iter_mut() {
+            *point = p;
+            p = p.double();
+        }
+
+        let mut bytes = vec![0u8; N * 32];
+        serialize_points(&points, &mut bytes).unwrap();
+
+        for (point, chunk) in points.iter().zip(bytes.chunks(32)) {
+            assert_eq!(&JubJubAffine::from(point).to_bytes()[..], chunk);
+        }
+
+        let mut deserialized = [JubJubExtended::identity(); N];
+        deserialize_points(&bytes, &mut deserialized).unwrap();
+        assert_eq!(points, deserialized);
+    }
+
+    round_trip::<0>();
+    round_trip::<1>();
+    round_trip::<16>();
+
+    let points = [GENERATOR_EXTENDED; 2];
+    let mut bytes = [0u8; 63];
+    assert_eq!(
+        serialize_points(&points, &mut bytes),
+        Err(BytesError::BadLength {
+            found: 63,
+            expected: 64
+        })
+    );
+
+    let mut points = [JubJubExtended::identity(); 2];
+    assert!(deserialize_points(&bytes, &mut points).is_err());
+
+    // A y-coordinate equal to the field modulus is invalid
+    let mut bytes = [0u8; 64];
+    bytes[..32].copy_from_slice(&GENERATOR.to_bytes());
+    bytes[32..].copy_from_slice(&[
+        1, 0, 0, 0, 255, 255, 255, 255, 254, 91, 254, 255, 2, 164, 189, 83, 5,
+        216, 161, 9, 8, 216, 57, 51, 72, 125, 157, 41, 83, 167, 237, 115,
+    ]);
+    assert_eq!(
+        deserialize_points(&bytes, &mut points),
+        Err(BytesError::InvalidData)
+    );
+
+    // The points before the invalid chunk are written, the others untouched
+    assert_eq!(points, [GENERATOR_EXTENDED, JubJubExtended::identity()]);
+}
+
+#[test]
+fn test_affine_neg_and_sub() {
+    let mut p = GENERATOR_EXTENDED;
+
+    for _ in 0..10 {
+        let affine = JubJubAffine::from(p);
+        let q = JubJubAffine::from(p.double());
+
+        assert_eq!(affine + (-affine), JubJubExtended::identity());
+        assert_eq!(affine - affine, JubJubExtended::identity());
+        assert_eq!(JubJubExtended::from(-affine), -p);
+
+        assert_eq!(affine + q, p + q);
+        assert_eq!(affine - q, p - q);
+        let (affine_ref, q_ref) = (&affine, &q);
+        assert_eq!(affine_ref - q_ref, affine + (-q));
+        assert_eq!(q - affine, -(affine - q));
+
+        p += GENERATOR_NUMS_EXTENDED;
+    }
+}
+
+#[test]
+fn test_bytes_projective() {
+    let mut p = GENERATOR_EXTENDED;
+
+    for _ in 0..20 {
+        let bytes = p.to_bytes_projective();
+        let q = JubJubExtended::from_bytes_projective_unchecked(&bytes);
+
+        assert_eq!(p, q);
+        assert!(q.is_on_curve_vartime());
+        assert_eq!(q.double(), p.double());
+        assert_eq!(q + GENERATOR_EXTENDED, p + GENERATOR_EXTENDED);
+
+        p = p.double() + GENERATOR_NUMS_EXTENDED;
+    }
+
+    let id = JubJubExtended::identity().to_bytes_projective();
+    assert_eq!(
+        JubJubExtended::from_bytes_projective_unchecked(&id),
+        JubJubExtended::identity()
+    );
+}
+
+#[test]
+fn test_raw_bytes() {
+    let mut p = GENERATOR_EXTENDED;
+
+    for _ in 0..20 {
+        let bytes = p.to_raw_bytes();
+        let q = JubJubExtended::from_raw_bytes(&bytes).unwrap();
+
+        assert_eq!(q.x, p.x);
+        assert_eq!(q.y, p.y);
+        assert_eq!(q.z, p.z);
+        assert_eq!(q.t1, p.t1);
+        assert_eq!(q.t2, p.t2);
+        assert_eq!(q.to_raw_bytes(), bytes);
+
+        p = p.double() + GENERATOR_NUMS_EXTENDED;
+    }
+
+    let id = JubJubExtended::identity().to_raw_bytes();
+    assert_eq!(
+        JubJubExtended::from_raw_bytes(&id),
+        Ok(JubJubExtended::identity())
+    );
+
+    let bytes = GENERATOR_EXTENDED.to_raw_bytes();
+
+    // Any single corrupted coordinate is rejected
+    for i in 0..5 {
+        let mut corrupt = bytes;
+        corrupt[32 * i] ^= 1;
+        assert_eq!(
+            JubJubExtended::from_raw_bytes(&corrupt),
+            Err(BytesError::InvalidData)
+        );
+    }
+
+    // Zero coordinates, with `Z = 0`, satisfy the equations
+    assert_eq!(
+        JubJubExtended::from_raw_bytes(&[0u8; 160]),
+        Err(BytesError::InvalidData)
+    );
+
+    // Non-canonical coordinates are rejected
+    let mut corrupt = bytes;
+    corrupt[..32].copy_from_slice(&[0xff; 32]);
+    assert_eq!(
+        JubJubExtended::from_raw_bytes(&corrupt),
+        Err(BytesError::InvalidData)
+    );
+}
+
+#[test]
+fn test_from_coordinates() {
+    let mut p = GENERATOR_EXTENDED;
+
+    for _ in 0..20 {
+        let a = JubJubAffine::from(p);
+
+        let b = JubJubAffine::from_coordinates(a.get_x(), a.get_y()).unwrap();
+        assert_eq!(a, b);
+        assert_eq!(b.get_x(), a.get_x());
+        assert_eq!(b.get_y(), a.get_y());
+
+        // Off the curve
+        let c = JubJubAffine::from_coordinates(
+            a.get_x() + BlsScalar::one(),
+            a.get_y(),
+        );
+        assert!(bool::from(c.is_none()));
+
+        p = p.double() + GENERATOR_NUMS_EXTENDED;
+    }
+
+    let id = JubJubAffine::identity();
+    assert_eq!(
+        JubJubAffine::from_coordinates(id.get_x(), id.get_y()).unwrap(),
+        id
+    );
+
+    // On the curve, but not in the prime order subgroup
+    for t in JubJubExtended::torsion_subgroup().iter().take(7) {
+        let t = JubJubAffine::from(t);
+        assert!(t.is_on_curve_vartime());
+        assert!(bool::from(
+            JubJubAffine::from_coordinates(t.get_x(), t.get_y()).is_none()
+        ));
+    }
+    let full = FULL_GENERATOR;
+    assert!(bool::from(
+        JubJubAffine::from_coordinates(full.get_x(), full.get_y()).is_none()
+    ));
+}
+
+#[test]
+fn test_decompress_both() {
+    let mut p = GENERATOR_EXTENDED;
+
+    for _ in 0..50 {
+        let a = JubJubAffine::from(p);
+
+        let (even, odd) = JubJubAffine::decompress_both(&a.get_y()).unwrap();
+        assert_eq!(even, -odd);
+        assert!(even.is_on_curve_vartime());
+        assert!(odd.is_on_curve_vartime());
+        assert_eq!(even.sign_bit().unwrap_u8(), 0);
+        assert_eq!(odd.sign_bit().unwrap_u8(), 1);
+        assert!(a == even || a == odd);
+
+        p = p.double() + GENERATOR_NUMS_EXTENDED;
+    }
+
+    let id = JubJubAffine::identity();
+    let (even, odd) = JubJubAffine::decompress_both(&id.get_y()).unwrap();
+    assert_eq!(even, id);
+    assert_eq!(odd, id);
+
+    // y = 2 has no corresponding x-coordinate on the curve
+    let two = BlsScalar::from(2u64);
+    assert!(bool::from(JubJubAffine::decompress_both(&two).is_none()));
+}
+
+#[test]
+fn test_sign_bit() {
+    let mut p = GENERATOR_EXTENDED;
+
+    for _ in 0..50 {
+        let a = JubJubAffine::from(p);
+        let sign = a.sign_bit();
+
+        assert_eq!(sign.unwrap_u8(), a.to_bytes()[31] >> 7);
+        assert_eq!(JubJubAffine::from_y_and_sign(&a.get_y(), sign).unwrap(), a);
+        assert_eq!(
+            JubJubAffine::from_y_and_sign(&a.get_y(), !sign).unwrap(),
+            -a
+        );
+        assert_eq!((-a).sign_bit().unwrap_u8(), 1 - sign.unwrap_u8());
+
+        p = p.double() + GENERATOR_NUMS_EXTENDED;
+    }
+
+    let id = JubJubAffine::identity();
+    assert_eq!(id.sign_bit().unwrap_u8(), 0);
+    assert_eq!(
+        JubJubAffine::from_y_and_sign(&id.get_y(), 0.into()).unwrap(),
+        id
+    );
+
+    // 2 is not the y-coordinate of any point of the curve
+    let y = BlsScalar::from(2u64);
+    assert!(bool::from(
+        JubJubAffine::from_y_and_sign(&y, 0.into()).is_none()
+    ));
+}
+
+#[test]
+fn test_sg_minus() {
+    use rand_core::OsRng;
+
+    for _ in 0..20 {
+        let s = Fr::random(&mut OsRng);
+        let c = Fr::random(&mut OsRng);
+        let a = GENERATOR_NUMS_EXTENDED * Fr::random(&mut OsRng);
+
+        assert_eq!(
+            JubJubExtended::sg_minus(&s, &a, &c),
+            GENERATOR_EXTENDED * s - a * c
+        );
+    }
+
+    let a = GENERATOR_NUMS_EXTENDED;
+    let (zero, one) = (Fr::zero(), Fr::one());
+
+    assert_eq!(
+        JubJubExtended::sg_minus(&zero, &a, &zero),
+        JubJubExtended::identity()
+    );
+    assert_eq!(
+        JubJubExtended::sg_minus(&one, &a, &zero),
+        GENERATOR_EXTENDED
+    );
+    assert_eq!(JubJubExtended::sg_minus(&zero, &a, &one), -a);
+    assert_eq!(
+        JubJubExtended::sg_minus(&one, &GENERATOR_EXTENDED, &one),
+        JubJubExtended::identity()
+    );
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn test_batch_to_bytes() {
+    let mut points = Vec::with_capacity(1000);
+    let mut p = GENERATOR_EXTENDED;
+    for _ in 0..1000 {
+        points.push(p);
+        p = p.double() + GENERATOR_NUMS_EXTENDED;
+    }
+    points.push(JubJubExtended::identity());
+
+    let bytes = JubJubExtended::batch_to_bytes(&points);
+    assert_eq!(bytes.len(), points.len());
+
+    for (p, b) in points.iter().zip(bytes.iter()) {
+        assert_eq!(&JubJubAffine::from(p).to_bytes(), b);
+    }
+
+    assert!(JubJubExtended::batch_to_bytes(&[]).is_empty());
+}
+
+#[test]
+fn test_mul_double_vartime() {
+    use rand_core::OsRng;
+
+    for _ in 0..50 {
+        let a = Fr::random(&mut OsRng);
+        let b = Fr::random(&mut OsRng);
+        let p = GENERATOR_EXTENDED * Fr::random(&mut OsRng);
+        let q = GENERATOR_NUMS_EXTENDED * Fr::random(&mut OsRng);
+
+        assert_eq!(
+            JubJubExtended::mul_double_vartime(&a, &p, &b, &q),
+            p * a + q * b
+        );
+    }
+
+    let p = GENERATOR_EXTENDED;
+    let q = GENERATOR_NUMS_EXTENDED;
+    let (zero, one) = (Fr::zero(), Fr::one());
+
+    assert_eq!(
+        JubJubExtended::mul_double_vartime(&zero, &p, &zero, &q),
+        JubJubExtended::identity()
+    );
+    assert_eq!(JubJubExtended::mul_double_vartime(&one, &p, &zero, &q), p);
+    assert_eq!(
+        JubJubExtended::mul_double_vartime(&-one, &p, &one, &q),
+        q - p
+    );
+    assert_eq!(
+        JubJubExtended::mul_double_vartime(&one, &p, &-one, &p),
+        JubJubExtended::identity()
+    );
+}
+
+#[test]
+fn test_eq_affine() {
+    use rand_core::{RngCore, SeedableRng};
+    use rand_xorshift::XorShiftRng;
+
+    let mut rng = XorShiftRng::from_seed([
+        0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32,
+        0x54, 0x06, 0xbc, 0xe5,
+    ]);
+
+    let mut points = [JubJubExtended::identity(); 16];
+    for p in points.iter_mut().skip(2) {
+        let mut bytes = [0u8; 64];
+        rng.fill_bytes(&mut bytes);
+        *p = GENERATOR_EXTENDED * Fr::from_bytes_wide(&bytes);
+    }
+    points[1] = JubJubExtended::from(EIGHT_TORSION[3]);
+
+    for (i, p) in points.iter().enumerate() {
+        let affine = JubJubAffine::from(p);
+        assert_eq!(p.eq_affine(&affine).unwrap_u8(), 1);
+        // The identity and (0, -1) are their own negation
+        assert_eq!(p.eq_affine(&-affine).unwrap_u8(), (i < 2) as u8);
+
+        // Same point, different projective representation
+        let lambda = BlsScalar::from(i as u64 + 2);
+        let q = JubJubExtended {
+            x: p.x * lambda,
+            y: p.y * lambda,
+            z: p.z * lambda,
+            t1: p.t1 * lambda,
+            t2: p.t2,
+        };
+        assert_ne!(p.z, q.z);
+        assert_eq!(q.eq_affine(&affine).unwrap_u8(), 1);
+
+        for (j, other) in points.iter().enumerate() {
+            let other = JubJubAffine::from(other);
+            assert_eq!(p.eq_affine(&other).unwrap_u8(), (i == j) as u8);
+            assert_eq!(q.eq_affine(&other).unwrap_u8(), (i == j) as u8);
+        }
+    }
+
+    let identity = JubJubAffine::identity();
+    assert_eq!(GENERATOR_EXTENDED.eq_affine(&identity).unwrap_u8(), 0);
+    assert_eq!(
+        JubJubExtended::identity().eq_affine(&GENERATOR).unwrap_u8(),
+        0
+    );
+}
+
+#[test]
+fn test_ct_eq() {
+    let mut p = GENERATOR_EXTENDED;
+
+    for i in 0..20u64 {
+        // Same point, different projective representation
+        let lambda = BlsScalar::from(i + 2);
+        let q = JubJubExtended {
+            x: p.x * lambda,
+            y: p.y * lambda,
+            z: p.z * lambda,
+            t1: p.t1 * lambda,
+            t2: p.t2,
+        };
+        assert_ne!(p.z, q.z);
+        assert!(q.is_on_curve_vartime());
+
+        assert_eq!(p.ct_eq(&q).unwrap_u8(), 1);
+        assert_eq!(p == q, bool::from(p.ct_eq(&q)));
+
+        let r = p + GENERATOR_NUMS_EXTENDED;
+        assert_eq!(p.ct_eq(&r).unwrap_u8(), 0);
+        assert_eq!(q.ct_eq(&r).unwrap_u8(), 0);
+        assert_eq!(p == r, bool::from(p.ct_eq(&r)));
+
+        let (pa, qa, ra) = (
+            JubJubAffine::from(p),
+            JubJubAffine::from(q),
+            JubJubAffine::from(r),
+        );
+        assert_eq!(pa.ct_eq(&qa).unwrap_u8(), 1);
+        assert_eq!(pa.ct_eq(&ra).unwrap_u8(), 0);
+        assert_eq!(pa == ra, bool::from(pa.ct_eq(&ra)));
+        assert_eq!(pa.ct_eq(&-pa).unwrap_u8(), 0);
+
+        p = p.double() + r;
+    }
+}
+
+#[test]
+fn test_sum() {
+    let points = [GENERATOR_EXTENDED; 20];
+
+    for n in 0..points.len() {
+        let expected = GENERATOR_EXTENDED * Fr::from(n as u64);
+
+        assert_eq!(points[..n].iter().sum::<JubJubExtended>(), expected);
+        assert_eq!(
+            points[..n].iter().copied().sum::<JubJubExtended>(),
+            expected
+        );
+    }
+
+    let mixed = [
+        GENERATOR_EXTENDED,
+        GENERATOR_NUMS_EXTENDED,
+        -GENERATOR_EXTENDED,
+    ];
+    assert_eq!(
+        mixed.iter().sum::<JubJubExtended>(),
+        GENERATOR_NUMS_EXTENDED
+    );
+}
+
+#[test]
+fn test_conditional_negate() {
+    let p = GENERATOR_EXTENDED * Fr::from(42u64);
+
+    let mut q = p;
+    q.conditional_negate(Choice::from(0));
+    assert_eq!(q, p);
+    assert_eq!(q.t1, p.t1);
+
+    q.conditional_negate(Choice::from(1));
+    assert_eq!(q, -p);
+    assert_eq!(q.t1, (-p).t1);
+    assert_eq!(q + p, JubJubExtended::identity());
+
+    let p = JubJubAffine::from(p);
+
+    let mut q = p;
+    q.conditional_negate(Choice::from(0));
+    assert_eq!(q, p);
+
+    q.conditional_negate(Choice::from(1));
+    assert_eq!(q, -p);
+    assert!(q.is_on_curve_vartime());
+}
+
+#[test]
+fn test_mul_bits_be() {
+    let scalars = [
+        Fr::zero(),
+        Fr::one(),
+        Fr::from(0xdead_beefu64),
+        -Fr::one(),
+        Fr::from_raw([
+            0x2bd8_2d6c_1d1c_9e58,
+            0x8c2b_4c31_8fb2_c2a6,
+            0xdc2f_0c90_1a2d_e6b1,
+            0x0b3a_6fd1_7e24_5f9c,
+        ]),
+    ];
+
+    for scalar in scalars.iter() {
+        let bytes = scalar.to_bytes();
+        let bits = bytes
+            .iter()
+            .rev()
+            .flat_map(|byte| (0..8).rev().map(move |i| (byte >> i) & 1 == 1));
+
+        assert_eq!(
+            GENERATOR_EXTENDED.mul_bits_be(bits),
+            GENERATOR_EXTENDED * scalar
+        );
+    }
+
+    // Leading zeros don't change the result
+    let bits = [false, false, true, false, true];
+    assert_eq!(
+        GENERATOR_EXTENDED.mul_bits_be(bits.iter().copied()),
+        GENERATOR_EXTENDED * Fr::from(5u64)
+    );
+
+    assert_eq!(
+        GENERATOR_EXTENDED.mul_bits_be(core::iter::empty()),
+        JubJubExtended::identity()
+    );
+}
+
+#[test]
+fn test_subgroup_checker() {
+    let checker = SubgroupChecker::new();
+
+    // The NAF must represent the group order
+    let order = checker
+        .naf
+        .iter()
+        .rev()
+        .fold(Fr::zero(), |acc, d| acc.double() + Fr::from(*d as i64));
+    assert_eq!(order, Fr::zero());
+    assert_ne!(checker.naf, [0i8; 256]);
+
+    let torsion = JubJubExtended::from(JubJubAffine::from_raw_unchecked(
+        BlsScalar::zero(),
+        -BlsScalar::one(),
+    ));
+
+    let mut p = GENERATOR_EXTENDED;
+    for _ in 0..20 {
+        let q = p + torsion;
+
+        assert_eq!(checker.is_torsion_free(&p).unwrap_u8(), 1);
+        assert_eq!(checker.is_torsion_free(&q).unwrap_u8(), 0);
+        assert_eq!(
+            checker.is_torsion_free(&p).unwrap_u8(),
+            p.is_torsion_free().unwrap_u8()
+        );
+        assert_eq!(
+            checker.is_torsion_free(&q).unwrap_u8(),
+            q.is_torsion_free().unwrap_u8()
+        );
+
+        p = p.double() + GENERATOR_NUMS_EXTENDED;
+    }
+
+    for point in EIGHT_TORSION.iter().chain(Some(&FULL_GENERATOR)) {
+        let point = JubJubExtended::from(*point);
+        assert_eq!(
+            checker.is_torsion_free(&point).unwrap_u8(),
+            point.is_torsion_free().unwrap_u8()
+        );
+    }
+}
+
+#[test]
+fn test_conditional_assign_slice() {
+    let mut src = [JubJubExtended::identity(); 8];
+    let mut original = [JubJubExtended::identity(); 8];
+    for i in 0..8 {
+        src[i] = GENERATOR_EXTENDED * Fr::from(i as u64);
+        original[i] = GENERATOR_NUMS_EXTENDED * Fr::from(i as u64 + 1);
+    }
+
+    let mut dst = original;
+    JubJubExtended::conditional_assign_slice(&mut dst, &src, Choice::from(0));
+    assert_eq!(dst, original);
+
+    JubJubExtended::conditional_assign_slice(&mut dst, &src, Choice::from(1));
+    assert_eq!(dst, src);
+}
+
+#[test]
+#[should_panic]
+fn test_conditional_assign_slice_mismatched() {
+    let mut dst = [JubJubExtended::identity(); 2];
+    let src = [GENERATOR_EXTENDED; 3];
+
+    JubJubExtended::conditional_assign_slice(&mut dst, &src, Choice::from(1));
+}
+
+#[test]
+fn test_extended_from_raw_unchecked() {
+    const X: BlsScalar = BlsScalar::from_raw([
+        0x4df7b7ffec7beaca,
+        0x2e3ebb21fd6c54ed,
+        0xf1fbf02d0fd6cce6,
+        0x3fd2814c43ac65a6,
+    ]);
+    const Y: BlsScalar = BlsScalar::from_raw([0x12, 0, 0, 0]);
+
+    const AFFINE: JubJubAffine = JubJubAffine::from_raw_unchecked(X, Y);
+    const EXTENDED: JubJubExtended =
+        JubJubExtended::from_raw_unchecked(X, Y, BlsScalar::one(), X, Y);
+
+    // The same point with `Z = 2`
+    const TWO: BlsScalar = BlsScalar::from_raw([2, 0, 0, 0]);
+    const SCALED: JubJubExtended = JubJubExtended::from_raw_unchecked(
+        BlsScalar::mul(&X, &TWO),
+        BlsScalar::mul(&Y, &TWO),
+        TWO,
+        BlsScalar::mul(&X, &TWO),
+        Y,
+    );
+
+    assert_eq!(AFFINE, GENERATOR);
+    assert_eq!(EXTENDED, GENERATOR_EXTENDED);
+    assert_eq!(SCALED, GENERATOR_EXTENDED);
+    assert!(SCALED.is_on_curve_vartime());
+    assert_eq!(SCALED.double(), GENERATOR_EXTENDED.double());
+}
+
+#[test]
+fn test_bls_scalar_negation() {
+    let mut x = BlsScalar::from(7u64);
+    for _ in 0..100 {
+        assert_eq!(x + (-x), BlsScalar::zero());
+        assert_eq!(x + (-&x), BlsScalar::zero());
+        assert_eq!(-(-x), x);
+
+        x = x.square() + BlsScalar::one();
+    }
+
+    assert_eq!(-BlsScalar::zero(), BlsScalar::zero());
+    assert_eq!(-&BlsScalar::zero(), BlsScalar::zero());
+}
+
+#[test]
+fn test_torsion_subgroup() {
+    let torsion = JubJubExtended::torsion_subgroup();
+
+    assert_eq!(torsion[7], JubJubExtended::identity());
+
+    for (i, p) in torsion.iter().enumerate() {
+        assert_eq!(p.is_small_order().unwrap_u8(), 1);
+        assert_eq!(p.mul_by_cofactor(), JubJubExtended::identity());
+        assert_eq!(*p, torsion[0] * Fr::from(i as u64 + 1));
+
+        for q in torsion.iter() {
+            let sum = p + q;
+            assert_eq!(torsion.iter().filter(|t| **t == sum).count(), 1);
+        }
+    }
+
+    // The points are distinct
+    for (i, p) in torsion.iter().enumerate() {
+        for q in torsion[i + 1..].iter() {
+            assert_ne!(p, q);
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn test_hex() {
+    const GENERATOR_HEX: &str =
+        "1200000000000000000000000000000000000000000000000000000000000000";
+
+    assert_eq!(GENERATOR.to_hex(), GENERATOR_HEX);
+    assert_eq!(JubJubAffine::from_hex(GENERATOR_HEX), Ok(GENERATOR));
+
+    let prefixed = alloc::format!("0x{}", GENERATOR_HEX);
+    assert_eq!(JubJubAffine::from_hex(&prefixed), Ok(GENERATOR));
+
+    for point in [GENERATOR_NUMS, -GENERATOR, JubJubAffine::identity()].iter() {
+        let hex = point.to_hex();
+        assert_eq!(hex, hex.to_lowercase());
+        assert_eq!(JubJubAffine::from_hex(&hex), Ok(*point));
+        assert_eq!(JubJubAffine::from_hex(&hex.to_uppercase()), Ok(*point));
+    }
+
+    assert_eq!(
+        JubJubAffine::from_hex(&GENERATOR_HEX[1..]),
+        Err(BytesError::BadLength {
+            found: 63,
+            expected: 64
+        })
+    );
+    assert_eq!(
+        JubJubAffine::from_hex(&alloc::format!("0x1g{}", &GENERATOR_HEX[2..])),
+        Err(BytesError::InvalidChar { ch: 'g', index: 3 })
+    );
+
+    // y = 2 has no corresponding x-coordinate on the curve
+    assert_eq!(
+        JubJubAffine::from_hex(&alloc::format!("02{}", &GENERATOR_HEX[2..])),
+        Err(BytesError::InvalidData)
+    );
+}
+
+#[test]
+fn test_mul_by_generator() {
+    use rand_core::{RngCore, SeedableRng};
+    use rand_xorshift::XorShiftRng;
+
+    let mut rng = XorShiftRng::from_seed([
+        0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32,
+        0x54, 0x06, 0xbc, 0xe5,
+    ]);
+
+    let mut scalars = [Fr::zero(); 64];
+    for s in scalars.iter_mut().skip(8) {
+        let mut bytes = [0u8; 64];
+        rng.fill_bytes(&mut bytes);
+        *s = Fr::from_bytes_wide(&bytes);
+    }
+    scalars[1] = Fr::one();
+    scalars[2] = -Fr::one();
+    scalars[3] = Fr::from(8u64);
+    scalars[4] = Fr::from(0x8888_8888_8888_8888u64);
+    scalars[5] = Fr::from(0xffff_ffff_ffff_ffffu64);
+    scalars[6] = Fr::from_raw([0, 0, 0, 1 << 59]);
+    scalars[7] = Fr::from_raw([u64::MAX, u64::MAX, u64::MAX, (1 << 59) - 1]);
+
+    for s in scalars.iter() {
+        assert_eq!(mul_by_generator(s), GENERATOR_EXTENDED * s);
+    }
+}
+
+#[test]
+fn test_generator_table() {
+    let mut base = GENERATOR_EXTENDED;
+    for window in GENERATOR_TABLE.iter() {
+        let mut p = JubJubExtended::identity();
+        for entry in window.iter() {
+            p += base;
+            assert_eq!(JubJubExtended::identity() + entry, p);
+        }
+
+        base = base.double().double().double().double();
+        base = base.double().double().double().double();
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn test_batch_mul() {
+    use rand_core::{RngCore, SeedableRng};
+    use rand_xorshift::XorShiftRng;
+
+    let mut rng = XorShiftRng::from_seed([
+        0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32,
+        0x54, 0x06, 0xbc, 0xe5,
+    ]);
+    let mut random = || {
+        let mut bytes = [0u8; 64];
+        rng.fill_bytes(&mut bytes);
+        Fr::from_bytes_wide(&bytes)
+    };
+
+    let base = GENERATOR_NUMS_EXTENDED * random();
+
+    let mut scalars = [Fr::zero(); 40];
+    for s in scalars.iter_mut().skip(4) {
+        *s = random();
+    }
+    scalars[1] = Fr::one();
+    scalars[2] = -Fr::one();
+    scalars[3] = Fr::from(16u64);
+
+    let batch = base.batch_mul(&scalars);
+    assert_eq!(batch.len(), scalars.len());
+    for (p, s) in batch.iter().zip(scalars.iter()) {
+        assert_eq!(p, &(base * s));
+    }
+
+    assert!(base.batch_mul(&[]).is_empty());
+}
+
+#[test]
+fn test_from_bytes_lenient() {
+    // The BLS12-381 scalar field modulus, little endian
+    const MODULUS: [u8; 32] = [
+        1, 0, 0, 0, 255, 255, 255, 255, 254, 91, 254, 255, 2, 164, 189, 83, 5,
+        216, 161, 9, 8, 216, 57, 51, 72, 125, 157, 41, 83, 167, 237, 115,
+    ];
+
+    let mut tested = 0;
+    for point in [GENERATOR, GENERATOR_NUMS, -GENERATOR].iter() {
+        let canonical = point.to_bytes();
+
+        // Add the modulus to the y-coordinate, keeping the sign bit
+        let mut bytes = [0u8; 32];
+        let mut carry = 0u16;
+        for i in 0..32 {
+            let y = if i == 31 {
+                canonical[i] & 0b0111_1111
+            } else {
+                canonical[i]
+            };
+            let sum = y as u16 + MODULUS[i] as u16 + carry;
+            bytes[i] = sum as u8;
+            carry = sum >> 8;
+        }
+        if bytes[31] >> 7 == 1 {
+            // y + p doesn't fit in 255 bits
+            continue;
+        }
+        bytes[31] |= canonical[31] & 0b1000_0000;
+
+        assert_eq!(
+            JubJubAffine::from_bytes(&bytes),
+            Err(BytesError::InvalidData)
+        );
+        assert_eq!(JubJubAffine::from_bytes_lenient(&bytes), Ok(*point));
+        assert_eq!(JubJubAffine::from_bytes_lenient(&canonical), Ok(*point));
+        tested += 1;
+    }
+    assert!(tested > 0);
+}
+
+#[test]
+fn test_from_bytes_zero_x_sign() {
+    // The points with a zero x-coordinate, (0, 1) and (0, -1)
+    let points = [JubJubAffine::identity(), EIGHT_TORSION[3]];
+
+    for point in points.iter() {
+        assert_eq!(point.get_x(), BlsScalar::zero());
+
+        let canonical = point.to_bytes();
+        assert_eq!(canonical[31] >> 7, 0);
+        assert_eq!(JubJubAffine::from_bytes(&canonical), Ok(*point));
+
+        let mut signed = canonical;
+        signed[31] |= 0b1000_0000;
+        assert_eq!(
+            JubJubAffine::from_bytes(&signed),
+            Err(BytesError::InvalidData)
+        );
+        assert_eq!(JubJubAffine::from_bytes_lenient(&signed), Ok(*point));
+    }
+
+    // The sign bit keeps selecting the x-coordinate of any other point
+    let encoded = (-GENERATOR).to_bytes();
+    assert_eq!(encoded[31] >> 7, 1);
+    assert_eq!(JubJubAffine::from_bytes(&encoded), Ok(-GENERATOR));
+}
+
+#[test]
+#[cfg(feature = "test-utils")]
+fn test_add_random_torsion() {
+    use rand_core::SeedableRng;
+    use rand_xorshift::XorShiftRng;
+
+    let mut rng = XorShiftRng::from_seed([
+        0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32,
+        0x54, 0x06, 0xbc, 0xe5,
+    ]);
+
+    let p = GENERATOR_EXTENDED * Fr::from(1234u64);
+
+    let mut torsion_free = 0;
+    for _ in 0..32 {
+        let q = p.add_random_torsion(&mut rng);
+
+        assert!(q.is_on_curve_vartime());
+        assert_eq!(q.mul_by_cofactor(), p.mul_by_cofactor());
+        assert_eq!(q.mul_by_cofactor().is_torsion_free().unwrap_u8(), 1);
+
+        torsion_free += q.is_torsion_free().unwrap_u8();
+    }
+
+    assert!(torsion_free < 32);
+}
+
+#[test]
+fn test_eval_in_exponent() {
+    let coeffs = [
+        Fr::from(7u64),
+        -Fr::from(3u64),
+        Fr::from(0xdead_beefu64),
+        Fr::one(),
+        Fr::from(42u64),
+    ];
+
+    for x in [Fr::zero(), Fr::one(), Fr::from(5u64), -Fr::from(12u64)].iter() {
+        let mut power = GENERATOR_NUMS_EXTENDED;
+        let mut naive = JubJubExtended::identity();
+        for c in coeffs.iter() {
+            naive += power * c;
+            power *= x;
+        }
+
+        assert_eq!(
+            JubJubExtended::eval_in_exponent(
+                &GENERATOR_NUMS_EXTENDED,
+                &coeffs,
+                x
+            ),
+            naive
+        );
+    }
+
+    assert_eq!(
+        JubJubExtended::eval_in_exponent(&GENERATOR_EXTENDED, &[], &Fr::one()),
+        JubJubExtended::identity()
+    );
+}
+
+#[test]
+fn test_montgomery_constants() {
+    let a = -BlsScalar::one();
+    let d = EDWARDS_D;
+
+    assert_eq!(MONTGOMERY_A, (a + d).double() * (a - d).invert().unwrap());
+    assert_eq!(
+        MONTGOMERY_B,
+        BlsScalar::from(4u64) * (a - d).invert().unwrap()
+    );
+    assert_eq!(MONTGOMERY_B, -BlsScalar::from(40964u64));
+    assert_eq!(
+        EDWARDS_D,
+        -BlsScalar::from(10240u64)
+            * BlsScalar::from(10241u64).invert().unwrap()
+    );
+
+    for p in [GENERATOR, GENERATOR_NUMS, -GENERATOR].iter() {
+        let (x, y) = (p.get_x(), p.get_y());
+
+        let u =
+            (BlsScalar::one() + y) * (BlsScalar::one() - y).invert().unwrap();
+        let v = u * x.invert().unwrap();
+
+        // The image lies on the Montgomery curve
+        assert_eq!(
+            MONTGOMERY_B * v.square(),
+            u.square() * u + MONTGOMERY_A * u.square() + u
+        );
+
+        // And the inverse map recovers the point
+        assert_eq!(u * v.invert().unwrap(), x);
+        assert_eq!(
+            (u - BlsScalar::one()) * (u + BlsScalar::one()).invert().unwrap(),
+            y
+        );
+    }
+}
+
+#[test]
+fn test_extended_random() {
+    use rand_core::OsRng;
+
+    let p = JubJubExtended::random(&mut OsRng);
+    let q = JubJubExtended::random(&mut OsRng);
+    assert_ne!(p, q);
+
+    for _ in 0..20 {
+        let p = JubJubExtended::random(&mut OsRng);
+        assert!(p.is_on_curve_vartime());
+        assert_eq!(p.is_torsion_free().unwrap_u8(), 1);
+    }
+}
+
+#[test]
+fn test_multiscalar_mul_mixed() {
+    let mut scalars = [Fr::zero(); 100];
+    let mut points = [JubJubAffine::identity(); 100];
+
+    let mut s = Fr::from(0x1234_5678_9abc_def0u64);
+    let mut p = GENERATOR_EXTENDED;
+    for i in 0..100 {
+        s = s.square() + Fr::one();
+        p = p.double() + GENERATOR_NUMS_EXTENDED;
+
+        scalars[i] = s;
+        points[i] = JubJubAffine::from(p);
+    }
+    scalars[3] = Fr::zero();
+    scalars[7] = -Fr::one();
+
+    for n in [0, 1, 2, 5, 16, 40, 100].iter() {
+        let (scalars, points) = (&scalars[..*n], &points[..*n]);
+
+        let expected = scalars
+            .iter()
+            .zip(points.iter())
+            .fold(JubJubExtended::identity(), |acc, (s, p)| {
+                acc + JubJubExtended::from(*p) * s
+            });
+
+        assert_eq!(
+            JubJubExtended::multiscalar_mul_mixed(scalars, points),
+            expected
+        );
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn test_multiscalar_mul_mixed_stack_window() {
+    let scalars: Vec<Fr> = (1..=40u64).map(|i| Fr::from(i).square()).collect();
+    let points: Vec<JubJubAffine> = (1..=40u64)
+        .map(|i| JubJubAffine::from(GENERATOR_NUMS_EXTENDED * Fr::from(i)))
+        .collect();
+
+    // Every window of the capped stack path agrees with the heap path
+    for c in 2..=MSM_MAX_WINDOW {
+        let mut buckets =
+            [JubJubExtended::identity(); (1 << MSM_MAX_WINDOW) - 1];
+
+        assert_eq!(
+            JubJubExtended::pippenger(
+                c,
+                points.len(),
+                &mut buckets[..(1 << c) - 1],
+                |i| scalars[i].to_bytes(),
+                |i| points[i].to_niels(),
+            ),
+            JubJubExtended::multiscalar_mul_mixed(&scalars, &points)
+        );
+    }
+}
+
+#[test]
+#[should_panic]
+fn test_multiscalar_mul_mixed_mismatched() {
+    JubJubExtended::multiscalar_mul_mixed(&[Fr::one()], &[]);
+}
+
+#[test]
+fn test_lookup() {
+    let mut table = [JubJubExtended::identity(); 16];
+    for i in 1..table.len() {
+        table[i] = table[i - 1] + GENERATOR_EXTENDED;
+    }
+
+    for (i, p) in table.iter().enumerate() {
+        assert_eq!(&lookup(&table, i as u8), p);
+    }
+
+    assert_eq!(lookup(&table, 16), JubJubExtended::identity());
+    assert_eq!(lookup(&table, 255), JubJubExtended::identity());
+    assert_eq!(lookup(&[], 0), JubJubExtended::identity());
+
+    let mut table = [GENERATOR_EXTENDED; 256];
+    table[255] = GENERATOR_NUMS_EXTENDED;
+    assert_eq!(lookup(&table, 255), GENERATOR_NUMS_EXTENDED);
+}
+
+#[test]
+fn test_mul_by_cofactor_inv() {
+    assert_eq!(COFACTOR_INV * Fr::from(8u64), Fr::one());
+
+    let mut p = GENERATOR_EXTENDED;
+    for _ in 0..16 {
+        assert!(bool::from(p.is_torsion_free()));
+        assert_eq!(p.mul_by_cofactor().mul_by_cofactor_inv(), p);
+        assert_eq!(p.mul_by_cofactor_inv().mul_by_cofactor(), p);
+
+        // The torsion component is cleared by the cofactor
+        for t in JubJubExtended::torsion_subgroup().iter() {
+            assert_eq!((p + t).mul_by_cofactor().mul_by_cofactor_inv(), p);
+        }
+
+        p += GENERATOR_NUMS_EXTENDED;
+    }
+
+    assert_eq!(
+        JubJubExtended::identity().mul_by_cofactor_inv(),
+        JubJubExtended::identity()
+    );
+}
+
+#[test]
+fn test_mul_checked() {
+    let s = Fr::from(0xdead_beefu64);
+
+    assert_eq!(
+        GENERATOR_EXTENDED.mul_checked(&s),
+        Some(GENERATOR_EXTENDED * s)
+    );
+    assert_eq!(
+        GENERATOR_NUMS_EXTENDED.mul_checked(&Fr::zero()),
+        Some(JubJubExtended::identity())
+    );
+
+    assert_eq!(JubJubExtended::identity().mul_checked(&s), None);
+    for t in JubJubExtended::torsion_subgroup().iter() {
+        assert_eq!(t.mul_checked(&s), None);
+    }
+
+    // A point with a prime order component is accepted, even if not
+    // torsion free
+    let full = JubJubExtended::from(FULL_GENERATOR);
+    assert_eq!(full.mul_checked(&s), Some(full * s));
+}
+
+#[test]
+fn test_curve_info() {
+    let info = JubJubExtended::curve_info();
+    assert_eq!(info.cofactor, 8);
+
+    let order = info.subgroup_order_bytes;
+    assert!(bool::from(
+        GENERATOR_EXTENDED.multiply(&order).is_identity()
+    ));
+    assert!(bool::from(
+        GENERATOR_NUMS_EXTENDED.multiply(&order).is_identity()
+    ));
+
+    // The torsion points are sent to the identity by the cofactor only
+    for t in JubJubExtended::torsion_subgroup().iter() {
+        let cofactor = Fr::from(info.cofactor);
+        assert!(bool::from((t * cofactor).is_identity()));
+    }
+    let full = JubJubExtended::from(FULL_GENERATOR);
+    assert!(!bool::from(full.multiply(&order).is_identity()));
+    assert!(bool::from(
+        full.multiply(&order).mul_by_cofactor().is_identity()
+    ));
+
+    // The moduli are the smallest non-canonical encodings of their fields
+    assert_eq!(info.scalar_field_modulus, order);
+    let mut r_minus_one = info.scalar_field_modulus;
+    r_minus_one[0] -= 1;
+    assert_eq!(Fr::from_bytes(&r_minus_one), Ok(-Fr::one()));
+    assert!(Fr::from_bytes(&info.scalar_field_modulus).is_err());
+
+    let mut q_minus_one = info.base_field_modulus;
+    q_minus_one[0] -= 1;
+    assert_eq!(BlsScalar::from_bytes(&q_minus_one), Ok(-BlsScalar::one()));
+    assert!(BlsScalar::from_bytes(&info.base_field_modulus).is_err());
+}
+
+#[test]
+fn test_ct_select() {
+    let mut table = [JubJubExtended::identity(); 300];
+    let mut p = GENERATOR_NUMS_EXTENDED;
+    for entry in table.iter_mut() {
+        *entry = p;
+        p += GENERATOR_EXTENDED;
+    }
+
+    for (i, p) in table.iter().enumerate() {
+        assert_eq!(&JubJubExtended::ct_select(&table, i as u32), p);
+    }
+
+    for index in [300, 301, 1 << 16, u32::MAX].iter() {
+        assert_eq!(
+            JubJubExtended::ct_select(&table, *index),
+            JubJubExtended::identity()
+        );
+    }
+    assert_eq!(
+        JubJubExtended::ct_select(&[], 0),
+        JubJubExtended::identity()
+    );
+
+    assert_eq!(lookup(&table, 7), JubJubExtended::ct_select(&table, 7));
+}
+
+#[test]
+fn test_conditional_select() {
+    let id = JubJubExtended::identity();
+
+    let p =
+        JubJubExtended::conditional_select(&id, &GENERATOR_EXTENDED, 0.into());
+    assert_eq!(p, id);
+    let p =
+        JubJubExtended::conditional_select(&id, &GENERATOR_EXTENDED, 1.into());
+    assert_eq!(p, GENERATOR_EXTENDED);
+
+    let id = JubJubAffine::identity();
+
+    let p = JubJubAffine::conditional_select(&id, &GENERATOR, 0.into());
+    assert_eq!(p, id);
+    let p = JubJubAffine::conditional_select(&id, &GENERATOR, 1.into());
+    assert_eq!(p, GENERATOR);
+
+    let mut p = JubJubExtended::identity();
+    p.conditional_assign(&GENERATOR_EXTENDED, 0.into());
+    assert_eq!(p, JubJubExtended::identity());
+    p.conditional_assign(&GENERATOR_EXTENDED, 1.into());
+    assert_eq!(p, GENERATOR_EXTENDED);
+
+    let mut p = JubJubAffine::identity();
+    p.conditional_assign(&GENERATOR, 0.into());
+    assert_eq!(p, JubJubAffine::identity());
+    p.conditional_assign(&GENERATOR, 1.into());
+    assert_eq!(p, GENERATOR);
+}
+
 #[test]
 fn test_serialization_consistency() {
     let gen = FULL_GENERATOR.mul_by_cofactor();
@@ -0,0 +1,429 @@
+//! Montgomery form of Jubjub.
+//!
+//! Jubjub is birationally equivalent to the Montgomery curve
+//! `B · v^2 = u^3 + A · u^2 + u`, being `A` = [`MONTGOMERY_A`] and `B` =
+//! [`MONTGOMERY_B`]. The Montgomery form allows scalar multiplication with
+//! the Montgomery ladder, which only needs the `u`-coordinate and is
+//! naturally constant time.
+
+use crate::{
+    BlsScalar, JubJubExtended, JubJubScalar, MONTGOMERY_A, MONTGOMERY_B,
+};
+
+use dusk_bytes::Serializable;
+use subtle::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption};
+
+/// `(A - 2) / 4`, the constant of the ladder doubling
+const A24: BlsScalar = BlsScalar::from_raw([10240, 0, 0, 0]);
+
+/// A point of the Montgomery form of Jubjub, in projective coordinates
+/// `(U : V : W)` with `u = U / W` and `v = V / W`.
+///
+/// The point at infinity, image of the identity, is `(0 : 1 : 0)`.
+#[derive(Debug, Copy, Clone)]
+pub struct MontgomeryPoint {
+    u: BlsScalar,
+    v: BlsScalar,
+    w: BlsScalar,
+}
+
+impl ConstantTimeEq for MontgomeryPoint {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        (self.u * other.w).ct_eq(&(other.u * self.w))
+            & (self.v * other.w).ct_eq(&(other.v * self.w))
+            & (self.u * other.v).ct_eq(&(other.u * self.v))
+    }
+}
+
+impl PartialEq for MontgomeryPoint {
+    fn eq(&self, other: &Self) -> bool {
+        self.ct_eq(other).into()
+    }
+}
+
+impl Eq for MontgomeryPoint {}
+
+impl ConditionallySelectable for MontgomeryPoint {
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        MontgomeryPoint {
+            u: BlsScalar::conditional_select(&a.u, &b.u, choice),
+            v: BlsScalar::conditional_select(&a.v, &b.v, choice),
+            w: BlsScalar::conditional_select(&a.w, &b.w, choice),
+        }
+    }
+}
+
+impl MontgomeryPoint {
+    /// The point at infinity, image of the identity.
+    pub const fn infinity() -> Self {
+        MontgomeryPoint {
+            u: BlsScalar::zero(),
+            v: BlsScalar::one(),
+            w: BlsScalar::zero(),
+        }
+    }
+
+    /// Map an Edwards point to the Montgomery form, with
+    /// `(u, v) = ((1 + y) / (1 - y), u / x)`.
+    ///
+    /// The exceptional points of the map are sent to the point at infinity,
+    /// for the identity, and to `(0, 0)`, for the point `(0, -1)` of order
+    /// `2`.
+    pub fn from_edwards(point: &JubJubExtended) -> Self {
+        let x = point.get_x();
+        let y = point.get_y();
+        let z = point.get_z();
+
+        // The identity is mapped to (0 : 2z^2 : 0), which is the point at
+        // infinity, so only (0, -1) needs to be handled
+        let p = MontgomeryPoint {
+            u: (z + y) * x,
+            v: (z + y) * z,
+            w: (z - y) * x,
+        };
+        let order_two = MontgomeryPoint {
+            u: BlsScalar::zero(),
+            v: BlsScalar::zero(),
+            w: BlsScalar::one(),
+        };
+        let is_order_two = x.ct_eq(&BlsScalar::zero()) & y.ct_eq(&-z);
+
+        MontgomeryPoint::conditional_select(&p, &order_two, is_order_two)
+    }
+
+    /// Map the point back to the Edwards form, with
+    /// `(x, y) = (u / v, (u - 1) / (u + 1))`, sending the point at infinity
+    /// to the identity and `(0, 0)` to `(0, -1)`.
+    ///
+    /// The result is `none` only for points with `u = -1`, which are not on
+    /// the curve.
+    pub fn to_edwards(&self) -> CtOption<JubJubExtended> {
+        let p = JubJubExtended::from_raw_unchecked(
+            self.u * (self.u + self.w),
+            (self.u - self.w) * self.v,
+            self.v * (self.u + self.w),
+            self.u,
+            self.u - self.w,
+        );
+
+        let order_two = JubJubExtended::from_raw_unchecked(
+            BlsScalar::zero(),
+            -BlsScalar::one(),
+            BlsScalar::one(),
+            BlsScalar::zero(),
+            BlsScalar::zero(),
+        );
+
+        let is_infinity = self.w.ct_eq(&BlsScalar::zero());
+        let is_order_two = self.v.ct_eq(&BlsScalar::zero());
+
+        let p =
+            JubJubExtended::conditional_select(&p, &order_two, is_order_two);
+        let p = JubJubExtended::conditional_select(
+            &p,
+            &JubJubExtended::identity(),
+            is_infinity,
+        );
+
+        let is_some = !p.get_z().ct_eq(&BlsScalar::zero());
+
+        CtOption::new(p, is_some)
+    }
+
+    /// Returns the `u`-coordinate of this point, or `0` for the point at
+    /// infinity.
+    pub fn get_u(&self) -> BlsScalar {
+        self.u * self.w.invert().unwrap_or(BlsScalar::zero())
+    }
+
+    /// Returns the `v`-coordinate of this point, or `0` for the point at
+    /// infinity.
+    pub fn get_v(&self) -> BlsScalar {
+        self.v * self.w.invert().unwrap_or(BlsScalar::zero())
+    }
+
+    /// Multiply this point by `scalar` with the Montgomery ladder.
+    ///
+    /// The ladder computes the `u`-coordinates of `scalar · P` and
+    /// `(scalar + 1) · P`, and the `v`-coordinate is recovered from them
+    /// with the method of Okeya and Sakurai. The multiplication is constant
+    /// time.
+    pub fn mul(&self, scalar: &JubJubScalar) -> MontgomeryPoint {
+        let winv = self.w.invert().unwrap_or(BlsScalar::zero());
+        let x = self.u * winv;
+        let y = self.v * winv;
+
+        let (x1, z1, x2, z2) = ladder(&x, scalar);
+
+        // Okeya-Sakurai recovery of `Q = (x1 : y : z1)`, given `P = (x, y)`
+        // and `Q + P = (x2 : z2)`
+        let t1 = x * z1;
+        let t2 = x1 + t1;
+        let t3 = (x1 - t1).square() * x2;
+        let t1 = MONTGOMERY_A.double() * z1;
+        let t2 = t2 + t1;
+        let t4 = x * x1 + z1;
+        let t2 = (t2 * t4 - t1 * z1) * z2;
+        let y1 = t2 - t3;
+        let t1 = MONTGOMERY_B.double() * y * z1 * z2;
+
+        let q = MontgomeryPoint {
+            u: t1 * x1,
+            v: y1,
+            w: t1 * z1,
+        };
+
+        // The recovery doesn't apply when `Q` or `Q + P` are at infinity,
+        // or when `P` has order at most 2
+        let neg = MontgomeryPoint {
+            u: self.u,
+            v: -self.v,
+            w: self.w,
+        };
+        let q = MontgomeryPoint::conditional_select(
+            &q,
+            &neg,
+            z2.ct_eq(&BlsScalar::zero()),
+        );
+        let q = MontgomeryPoint::conditional_select(
+            &q,
+            &MontgomeryPoint::infinity(),
+            z1.ct_eq(&BlsScalar::zero()),
+        );
+
+        let is_odd = Choice::from(scalar.to_bytes()[0] & 1);
+        let small = MontgomeryPoint::conditional_select(
+            &MontgomeryPoint::infinity(),
+            self,
+            is_odd,
+        );
+        let is_small =
+            self.v.ct_eq(&BlsScalar::zero()) | self.w.ct_eq(&BlsScalar::zero());
+
+        MontgomeryPoint::conditional_select(&q, &small, is_small)
+    }
+}
+
+/// Multiply the point with `u`-coordinate `u` by `scalar`, returning the
+/// `u`-coordinate of the result, in the fashion of X25519.
+///
+/// Both points `P` and `-P` share the same `u`-coordinate, and so do their
+/// multiples, so the sign of the point is irrelevant. The point at infinity
+/// is represented, as in X25519, with `u = 0`, which is also the
+/// `u`-coordinate of the point of order `2`.
+///
+/// Unlike X25519, the scalar is not clamped: it is already an element of
+/// [`JubJubScalar`], and setting high bits wouldn't fix the `8`-torsion
+/// component of a malicious point. Callers receiving untrusted coordinates
+/// must either check that the point is torsion free, or multiply the scalar
+/// by the cofactor.
+///
+/// The multiplication is constant time. No check is performed that `u` is
+/// the coordinate of a point of Jubjub rather than of its quadratic twist.
+pub fn x25519_style_mul(u: &BlsScalar, scalar: &JubJubScalar) -> BlsScalar {
+    let (x, z, _, _) = ladder(u, scalar);
+
+    x * z.invert().unwrap_or(BlsScalar::zero())
+}
+
+/// Compute the `u`-coordinate of the Diffie-Hellman point shared with the
+/// owner of the public key with `u`-coordinate `their_u`.
+///
+/// This is the `u`-coordinate of the point returned by [`crate::dhke`],
+/// computed with [`x25519_style_mul`] so the public key doesn't need to be
+/// decompressed. See its documentation for the handling of the cofactor.
+pub fn montgomery_dh(secret: &JubJubScalar, their_u: &BlsScalar) -> BlsScalar {
+    x25519_style_mul(their_u, secret)
+}
+
+/// Montgomery ladder over the `u`-coordinate `u` of a point `P`, returning
+/// the projective coordinates `(X : Z)` of `scalar · P` and
+/// `(scalar + 1) · P`.
+fn ladder(
+    u: &BlsScalar,
+    scalar: &JubJubScalar,
+) -> (BlsScalar, BlsScalar, BlsScalar, BlsScalar) {
+    let bytes = scalar.to_bytes();
+
+    let mut x2 = BlsScalar::one();
+    let mut z2 = BlsScalar::zero();
+    let mut x3 = *u;
+    let mut z3 = BlsScalar::one();
+    let mut swap = Choice::from(0);
+
+    for i in (0..256).rev() {
+        let bit = Choice::from((bytes[i / 8] >> (i % 8)) & 1);
+
+        swap ^= bit;
+        BlsScalar::conditional_swap(&mut x2, &mut x3, swap);
+        BlsScalar::conditional_swap(&mut z2, &mut z3, swap);
+        swap = bit;
+
+        let a = x2 + z2;
+        let aa = a.square();
+        let b = x2 - z2;
+        let bb = b.square();
+        let e = aa - bb;
+        let c = x3 + z3;
+        let d = x3 - z3;
+        let da = d * a;
+        let cb = c * b;
+
+        x3 = (da + cb).square();
+        z3 = u * (da - cb).square();
+        x2 = aa * bb;
+        z2 = e * (aa + A24 * e);
+    }
+
+    BlsScalar::conditional_swap(&mut x2, &mut x3, swap);
+    BlsScalar::conditional_swap(&mut z2, &mut z3, swap);
+
+    (x2, z2, x3, z3)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{montgomery_dh, x25519_style_mul, MontgomeryPoint};
+    use crate::{
+        dhke, BlsScalar, JubJubExtended, JubJubScalar, GENERATOR_EXTENDED,
+        GENERATOR_NUMS_EXTENDED, MONTGOMERY_A, MONTGOMERY_B,
+    };
+    use dusk_bytes::Serializable;
+
+    fn is_on_curve(p: &MontgomeryPoint) -> bool {
+        let (u, v) = (p.get_u(), p.get_v());
+        MONTGOMERY_B * v.square()
+            == u.square() * u + MONTGOMERY_A * u.square() + u
+    }
+
+    #[test]
+    fn round_trip() {
+        let points = [
+            GENERATOR_EXTENDED,
+            GENERATOR_NUMS_EXTENDED,
+            -GENERATOR_EXTENDED,
+            GENERATOR_EXTENDED.double() * JubJubScalar::from(3u64),
+        ];
+
+        for p in points.iter() {
+            let m = MontgomeryPoint::from_edwards(p);
+            assert!(is_on_curve(&m));
+            assert_eq!(m.to_edwards().unwrap(), *p);
+        }
+
+        for p in JubJubExtended::torsion_subgroup().iter() {
+            let m = MontgomeryPoint::from_edwards(p);
+            assert_eq!(m.to_edwards().unwrap(), *p);
+        }
+
+        let identity =
+            MontgomeryPoint::from_edwards(&JubJubExtended::identity());
+        assert_eq!(identity, MontgomeryPoint::infinity());
+        assert_eq!(identity.get_u(), BlsScalar::zero());
+    }
+
+    #[test]
+    fn map_is_birational() {
+        let g = GENERATOR_EXTENDED;
+        let m = MontgomeryPoint::from_edwards(&g);
+
+        let x = g.get_x() * g.get_z().invert().unwrap();
+        let y = g.get_y() * g.get_z().invert().unwrap();
+
+        let u =
+            (BlsScalar::one() + y) * (BlsScalar::one() - y).invert().unwrap();
+        assert_eq!(m.get_u(), u);
+        assert_eq!(m.get_v(), u * x.invert().unwrap());
+    }
+
+    #[test]
+    fn x25519_style() {
+        let a =
+            JubJubScalar::from(0x1234_5678u64) * JubJubScalar::from(0x42u64);
+        let b = -JubJubScalar::from(0xcafe_babeu64);
+
+        let a_u =
+            MontgomeryPoint::from_edwards(&(GENERATOR_EXTENDED * a)).get_u();
+        let b_u =
+            MontgomeryPoint::from_edwards(&(GENERATOR_EXTENDED * b)).get_u();
+
+        let shared_a = x25519_style_mul(&b_u, &a);
+        let shared_b = x25519_style_mul(&a_u, &b);
+        assert_eq!(shared_a, shared_b);
+
+        // The shared secret is the projection of the Edwards one
+        let edwards = dhke(&a, &(GENERATOR_EXTENDED * b));
+        let expected = MontgomeryPoint::from_edwards(&edwards.into()).get_u();
+        assert_eq!(shared_a, expected);
+
+        // The point at infinity and the point of order 2
+        assert_eq!(x25519_style_mul(&BlsScalar::zero(), &a), BlsScalar::zero());
+        assert_eq!(
+            x25519_style_mul(&a_u, &JubJubScalar::zero()),
+            BlsScalar::zero()
+        );
+    }
+
+    #[test]
+    fn dh() {
+        let a = JubJubScalar::from(0xa11c_e5e1u64).pow2k(5);
+        let b = JubJubScalar::from(0xb0bu64).pow2k(7);
+
+        let a_public = GENERATOR_EXTENDED * a;
+        let b_public = GENERATOR_EXTENDED * b;
+
+        let a_u = MontgomeryPoint::from_edwards(&a_public).get_u();
+        let b_u = MontgomeryPoint::from_edwards(&b_public).get_u();
+
+        let shared = montgomery_dh(&a, &b_u);
+        assert_eq!(shared, montgomery_dh(&b, &a_u));
+
+        let edwards = dhke(&a, &b_public);
+        assert_eq!(
+            shared,
+            MontgomeryPoint::from_edwards(&edwards.into()).get_u()
+        );
+        assert_eq!(
+            shared,
+            MontgomeryPoint::from_edwards(&dhke(&b, &a_public).into()).get_u()
+        );
+
+        // Computed independently with the affine Edwards addition law
+        let expected = BlsScalar::from_bytes(&[
+            216, 20, 132, 219, 137, 157, 10, 250, 191, 116, 147, 38, 228, 227,
+            195, 253, 129, 17, 240, 8, 81, 88, 118, 56, 188, 21, 172, 96, 90,
+            38, 195, 109,
+        ])
+        .unwrap();
+        assert_eq!(shared, expected);
+    }
+
+    #[test]
+    fn ladder_mul() {
+        let scalars = [
+            JubJubScalar::zero(),
+            JubJubScalar::one(),
+            JubJubScalar::from(2u64),
+            JubJubScalar::from(0xdead_beefu64),
+            -JubJubScalar::one(),
+            -JubJubScalar::from(2u64),
+        ];
+
+        let mut points = vec![GENERATOR_EXTENDED, GENERATOR_NUMS_EXTENDED];
+        points.extend_from_slice(&JubJubExtended::torsion_subgroup());
+        points.push(GENERATOR_EXTENDED + JubJubExtended::torsion_subgroup()[2]);
+
+        for p in points.iter() {
+            let m = MontgomeryPoint::from_edwards(p);
+
+            for s in scalars.iter() {
+                let expected = MontgomeryPoint::from_edwards(&(p * s));
+                let product = m.mul(s);
+
+                assert_eq!(product, expected);
+                assert_eq!(product.get_u(), expected.get_u());
+                assert_eq!(product.to_edwards().unwrap(), p * s);
+            }
+        }
+    }
+}
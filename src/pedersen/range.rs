@@ -0,0 +1,279 @@
+//! Range proofs over Pedersen commitments, by bit decomposition.
+//!
+//! To prove that `C = v · G + r · H` commits to `v ∈ [0, 2^n)`, the prover
+//! commits to every bit `b_i` of `v` as `C_i = b_i · G + r_i · H`, choosing
+//! the blinding factors such that `Σ 2^i · r_i = r`. Then, for every `C_i`,
+//! it proves knowledge of the discrete logarithm in base `H` of either
+//! `C_i` or `C_i - G`, without revealing which one.
+//!
+//! The verifier checks every bit proof and that `Σ 2^i · C_i = C`.
+//!
+//! The proof grows linearly with `n`, and is meant for small ranges or as a
+//! building block; it is not a replacement for succinct range proofs.
+
+use super::PedersenGens;
+use crate::transcript::Transcript;
+use crate::{JubJubExtended, JubJubScalar};
+
+use alloc::vec::Vec;
+use rand_core::{CryptoRng, RngCore};
+use subtle::{Choice, ConditionallySelectable};
+
+const LABEL: &[u8] = b"dusk-jubjub-range-proof";
+
+/// Proof that a bit commitment `C` opens to either `0` or `1`.
+///
+/// It's a disjunction of two Schnorr proofs of knowledge of the discrete
+/// logarithm in base `H`, one of `C` and one of `C - G`, where the branch
+/// that isn't known is simulated.
+#[derive(Debug, Copy, Clone, PartialEq)]
+struct BitProof {
+    commitment: JubJubExtended,
+    e0: JubJubScalar,
+    e1: JubJubScalar,
+    z0: JubJubScalar,
+    z1: JubJubScalar,
+}
+
+impl BitProof {
+    fn prove<R: RngCore + CryptoRng>(
+        gens: &PedersenGens,
+        transcript: &mut Transcript,
+        bit: Choice,
+        blinding: &JubJubScalar,
+        rng: &mut R,
+    ) -> Self {
+        let g = JubJubScalar::conditional_select(
+            &JubJubScalar::zero(),
+            &JubJubScalar::one(),
+            bit,
+        );
+        let commitment = gens.commit(&g, blinding);
+
+        // Statements of both branches, proven against `H`
+        let p0 = commitment;
+        let p1 = commitment - gens.g();
+        let p_fake = JubJubExtended::conditional_select(&p1, &p0, bit);
+
+        let k = JubJubScalar::random(rng);
+        let e_fake = JubJubScalar::random(rng);
+        let z_fake = JubJubScalar::random(rng);
+
+        let a_real = gens.h() * k;
+        let a_fake = gens.h() * z_fake - p_fake * e_fake;
+
+        let a0 = JubJubExtended::conditional_select(&a_real, &a_fake, bit);
+        let a1 = JubJubExtended::conditional_select(&a_fake, &a_real, bit);
+
+        let e = Self::challenge(transcript, &commitment, &a0, &a1);
+
+        let e_real = e - e_fake;
+        let z_real = k + e_real * blinding;
+
+        Self {
+            commitment,
+            e0: JubJubScalar::conditional_select(&e_real, &e_fake, bit),
+            e1: JubJubScalar::conditional_select(&e_fake, &e_real, bit),
+            z0: JubJubScalar::conditional_select(&z_real, &z_fake, bit),
+            z1: JubJubScalar::conditional_select(&z_fake, &z_real, bit),
+        }
+    }
+
+    fn verify(&self, gens: &PedersenGens, transcript: &mut Transcript) -> bool {
+        let p0 = self.commitment;
+        let p1 = self.commitment - gens.g();
+
+        let a0 = gens.h() * self.z0 - p0 * self.e0;
+        let a1 = gens.h() * self.z1 - p1 * self.e1;
+
+        let e = Self::challenge(transcript, &self.commitment, &a0, &a1);
+
+        e == self.e0 + self.e1
+    }
+
+    fn challenge(
+        transcript: &mut Transcript,
+        commitment: &JubJubExtended,
+        a0: &JubJubExtended,
+        a1: &JubJubExtended,
+    ) -> JubJubScalar {
+        transcript.append_point(b"bit-commitment", commitment);
+        transcript.append_point(b"a0", a0);
+        transcript.append_point(b"a1", a1);
+
+        transcript.challenge_scalar(b"e")
+    }
+}
+
+/// Proof that a Pedersen commitment opens to a value in `[0, 2^n)`.
+///
+/// See the [module documentation](self) for the construction.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RangeProof {
+    bits: Vec<BitProof>,
+}
+
+/// Prove that `value · G + blinding · H` commits to a value in `[0, 2^n)`.
+///
+/// Only the `n` least significant bits of `value` are proven, so if `value`
+/// is out of range the proof won't verify against its commitment.
+///
+/// # Panics
+///
+/// If `n` is zero or greater than `64`.
+pub fn prove<R: RngCore + CryptoRng>(
+    gens: &PedersenGens,
+    value: u64,
+    blinding: &JubJubScalar,
+    n: usize,
+    rng: &mut R,
+) -> RangeProof {
+    assert!(n > 0 && n <= 64, "Invalid number of bits");
+
+    let mut transcript = RangeProof::transcript(gens, n);
+    transcript.append_point(
+        b"commitment",
+        &gens.commit(&JubJubScalar::from(value), blinding),
+    );
+
+    // Random blinding factors for every bit but the first, which is chosen
+    // so that `Σ 2^i · r_i = blinding`
+    let mut blindings = Vec::with_capacity(n);
+    blindings.push(JubJubScalar::zero());
+
+    let mut weighted = JubJubScalar::zero();
+    let mut power = JubJubScalar::one();
+    for _ in 1..n {
+        power = power.double();
+
+        let r = JubJubScalar::random(rng);
+        weighted += power * r;
+        blindings.push(r);
+    }
+    blindings[0] = blinding - weighted;
+
+    let bits = blindings
+        .iter()
+        .enumerate()
+        .map(|(i, r)| {
+            let bit = Choice::from(((value >> i) & 1) as u8);
+            BitProof::prove(gens, &mut transcript, bit, r, rng)
+        })
+        .collect();
+
+    RangeProof { bits }
+}
+
+impl RangeProof {
+    fn transcript(gens: &PedersenGens, n: usize) -> Transcript {
+        let mut transcript = Transcript::new(LABEL);
+        transcript.append_point(b"g", gens.g());
+        transcript.append_point(b"h", gens.h());
+        transcript.append_message(b"n", &(n as u64).to_le_bytes());
+
+        transcript
+    }
+
+    /// Verify that `commitment` opens to a value in `[0, 2^n)`.
+    pub fn verify(
+        &self,
+        gens: &PedersenGens,
+        commitment: &JubJubExtended,
+        n: usize,
+    ) -> bool {
+        if n == 0 || n > 64 || self.bits.len() != n {
+            return false;
+        }
+
+        let mut transcript = Self::transcript(gens, n);
+        transcript.append_point(b"commitment", commitment);
+
+        if !self.bits.iter().all(|b| b.verify(gens, &mut transcript)) {
+            return false;
+        }
+
+        let sum = self
+            .bits
+            .iter()
+            .rev()
+            .fold(JubJubExtended::identity(), |acc, b| {
+                acc.double() + b.commitment
+            });
+
+        sum == *commitment
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg(test)]
+mod tests {
+    use super::prove;
+    use crate::pedersen::PedersenGens;
+    use crate::JubJubScalar;
+    use rand_core::OsRng;
+
+    #[test]
+    fn in_range() {
+        let gens = PedersenGens::default();
+
+        for (value, n) in
+            [(0u64, 1), (1, 1), (5, 3), (200, 8), (u64::MAX, 64)].iter()
+        {
+            let blinding = JubJubScalar::random(&mut OsRng);
+            let commitment =
+                gens.commit(&JubJubScalar::from(*value), &blinding);
+
+            let proof = prove(&gens, *value, &blinding, *n, &mut OsRng);
+            assert!(proof.verify(&gens, &commitment, *n));
+
+            // The proof is bound to the number of bits and the commitment
+            if *n < 64 {
+                assert!(!proof.verify(&gens, &commitment, *n + 1));
+            }
+            assert!(!proof.verify(&gens, &(commitment + gens.g()), *n));
+        }
+    }
+
+    #[test]
+    fn out_of_range() {
+        let gens = PedersenGens::default();
+        let blinding = JubJubScalar::random(&mut OsRng);
+
+        let value = 256u64;
+        let commitment = gens.commit(&JubJubScalar::from(value), &blinding);
+
+        let proof = prove(&gens, value, &blinding, 8, &mut OsRng);
+        assert!(!proof.verify(&gens, &commitment, 8));
+
+        // A negative value wraps around the scalar field
+        let commitment = gens.commit(&-JubJubScalar::one(), &blinding);
+        let proof = prove(&gens, u64::MAX, &blinding, 8, &mut OsRng);
+        assert!(!proof.verify(&gens, &commitment, 8));
+    }
+
+    #[test]
+    fn tampered() {
+        let gens = PedersenGens::default();
+        let blinding = JubJubScalar::random(&mut OsRng);
+
+        let commitment = gens.commit(&JubJubScalar::from(6u64), &blinding);
+        let proof = prove(&gens, 6, &blinding, 4, &mut OsRng);
+        assert!(proof.verify(&gens, &commitment, 4));
+
+        let mut tampered = proof.clone();
+        tampered.bits[1].e0 += JubJubScalar::one();
+        tampered.bits[1].e1 -= JubJubScalar::one();
+        assert!(!tampered.verify(&gens, &commitment, 4));
+
+        let mut tampered = proof;
+        tampered.bits.swap(1, 2);
+        assert!(!tampered.verify(&gens, &commitment, 4));
+    }
+
+    #[test]
+    #[should_panic]
+    fn too_many_bits() {
+        let gens = PedersenGens::default();
+        prove(&gens, 0, &JubJubScalar::zero(), 65, &mut OsRng);
+    }
+}
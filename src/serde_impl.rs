@@ -0,0 +1,167 @@
+//! Implementations of the [`serde`] traits for the curve points.
+//!
+//! Points are serialized from their compressed 32-byte encoding. Formats that
+//! are human readable, such as JSON, get the same lowercase hexadecimal string
+//! as `JubJubAffine::to_hex`; binary formats, such as bincode, get the bytes
+//! as a fixed size tuple, with no length prefix.
+
+use crate::{JubJubAffine, JubJubExtended};
+
+use core::fmt;
+
+use dusk_bytes::Serializable;
+use serde::de::{self, Deserializer, SeqAccess, Visitor};
+use serde::ser::{SerializeTuple, Serializer};
+use serde::{Deserialize, Serialize};
+
+const HEX_DIGITS: &[u8; 16] = b"0123456789abcdef";
+
+impl Serialize for JubJubAffine {
+    fn serialize<S: Serializer>(
+        &self,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        let bytes = self.to_bytes();
+
+        if serializer.is_human_readable() {
+            let mut hex = [0u8; 64];
+            for (i, b) in bytes.iter().enumerate() {
+                hex[2 * i] = HEX_DIGITS[(b >> 4) as usize];
+                hex[2 * i + 1] = HEX_DIGITS[(b & 0x0f) as usize];
+            }
+            let hex = core::str::from_utf8(&hex)
+                .expect("Hexadecimal digits are valid UTF-8");
+
+            serializer.serialize_str(hex)
+        } else {
+            let mut tuple = serializer.serialize_tuple(bytes.len())?;
+            for b in bytes.iter() {
+                tuple.serialize_element(b)?;
+            }
+            tuple.end()
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for JubJubAffine {
+    fn deserialize<D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Self, D::Error> {
+        struct HexVisitor;
+
+        impl<'de> Visitor<'de> for HexVisitor {
+            type Value = JubJubAffine;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("a hex encoded compressed Jubjub point")
+            }
+
+            fn visit_str<E: de::Error>(
+                self,
+                v: &str,
+            ) -> Result<Self::Value, E> {
+                JubJubAffine::from_hex(v)
+                    .map_err(|e| E::custom(format_args!("{:?}", e)))
+            }
+        }
+
+        struct BytesVisitor;
+
+        impl<'de> Visitor<'de> for BytesVisitor {
+            type Value = JubJubAffine;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("32 bytes of a compressed Jubjub point")
+            }
+
+            fn visit_seq<A: SeqAccess<'de>>(
+                self,
+                mut seq: A,
+            ) -> Result<Self::Value, A::Error> {
+                let mut bytes = [0u8; JubJubAffine::SIZE];
+                for (i, b) in bytes.iter_mut().enumerate() {
+                    *b = seq
+                        .next_element()?
+                        .ok_or_else(|| de::Error::invalid_length(i, &self))?;
+                }
+
+                JubJubAffine::from_bytes(&bytes)
+                    .map_err(|e| de::Error::custom(format_args!("{:?}", e)))
+            }
+        }
+
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_str(HexVisitor)
+        } else {
+            deserializer.deserialize_tuple(JubJubAffine::SIZE, BytesVisitor)
+        }
+    }
+}
+
+impl Serialize for JubJubExtended {
+    fn serialize<S: Serializer>(
+        &self,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        JubJubAffine::from(self).serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for JubJubExtended {
+    fn deserialize<D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Self, D::Error> {
+        JubJubAffine::deserialize(deserializer).map(JubJubExtended::from)
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg(test)]
+mod tests {
+    use crate::{
+        JubJubAffine, JubJubExtended, GENERATOR, GENERATOR_EXTENDED,
+        GENERATOR_NUMS,
+    };
+    use dusk_bytes::Serializable;
+
+    #[test]
+    fn json_is_hex() {
+        let json = serde_json::to_string(&GENERATOR).unwrap();
+        assert_eq!(json, format!("\"{}\"", GENERATOR.to_hex()));
+
+        let points = [GENERATOR, GENERATOR_NUMS, -GENERATOR];
+        for point in points.iter() {
+            let json = serde_json::to_string(point).unwrap();
+            let decoded: JubJubAffine = serde_json::from_str(&json).unwrap();
+            assert_eq!(&decoded, point);
+        }
+
+        let json = serde_json::to_string(&GENERATOR_EXTENDED).unwrap();
+        let decoded: JubJubExtended = serde_json::from_str(&json).unwrap();
+        assert_eq!(decoded, GENERATOR_EXTENDED);
+
+        // y = 2 has no corresponding x-coordinate on the curve
+        let invalid = format!("\"02{}\"", &GENERATOR.to_hex()[2..]);
+        assert!(serde_json::from_str::<JubJubAffine>(&invalid).is_err());
+    }
+
+    #[test]
+    fn bincode_is_bytes() {
+        let points = [GENERATOR, GENERATOR_NUMS, -GENERATOR];
+        for point in points.iter() {
+            let encoded = bincode::serialize(point).unwrap();
+            assert_eq!(encoded, point.to_bytes());
+
+            let decoded: JubJubAffine = bincode::deserialize(&encoded).unwrap();
+            assert_eq!(&decoded, point);
+        }
+
+        let encoded = bincode::serialize(&GENERATOR_EXTENDED).unwrap();
+        assert_eq!(encoded, GENERATOR.to_bytes());
+        let decoded: JubJubExtended = bincode::deserialize(&encoded).unwrap();
+        assert_eq!(decoded, GENERATOR_EXTENDED);
+
+        let encoded = GENERATOR.to_bytes();
+        assert!(bincode::deserialize::<JubJubAffine>(&encoded[..31]).is_err());
+    }
+}
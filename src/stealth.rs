@@ -0,0 +1,64 @@
+//! One-time keys for stealth addresses.
+//!
+//! Being `A = G · a` the public key of the receiver, the sender picks a
+//! random `r`, publishes `R = G · r` and derives the one-time public key
+//! `P = A + G · H(r · A)`. The receiver derives the corresponding secret
+//! `p = a + H(a · R)`, since `r · A = a · R`.
+
+use crate::{mul_by_generator, JubJubAffine, JubJubExtended, JubJubScalar};
+
+use dusk_bytes::Serializable;
+
+const DOMAIN: &[u8] = b"dusk-jubjub-stealth";
+
+/// Hash the shared point to the scalar offset of the one-time keys
+fn offset(shared: &JubJubExtended) -> JubJubScalar {
+    let shared = JubJubAffine::from(shared).to_bytes();
+    JubJubScalar::hash_to_scalar(DOMAIN, &[&shared])
+}
+
+/// Derive the one-time public key `base_public + G · H(shared)`.
+pub fn derive_onetime_public(
+    base_public: &JubJubExtended,
+    shared: &JubJubExtended,
+) -> JubJubExtended {
+    base_public + mul_by_generator(&offset(shared))
+}
+
+/// Derive the one-time secret `base_secret + H(shared)`, corresponding to
+/// the public key of [`derive_onetime_public`].
+pub fn derive_onetime_secret(
+    base_secret: &JubJubScalar,
+    shared: &JubJubExtended,
+) -> JubJubScalar {
+    base_secret + offset(shared)
+}
+
+#[cfg(feature = "std")]
+#[cfg(test)]
+mod tests {
+    use super::{derive_onetime_public, derive_onetime_secret};
+    use crate::{JubJubScalar, GENERATOR_EXTENDED};
+    use rand_core::OsRng;
+
+    #[test]
+    fn onetime_keys() {
+        let a = JubJubScalar::random(&mut OsRng);
+        let a_g = GENERATOR_EXTENDED * a;
+
+        // Sender
+        let r = JubJubScalar::random(&mut OsRng);
+        let r_g = GENERATOR_EXTENDED * r;
+        let public = derive_onetime_public(&a_g, &(a_g * r));
+
+        // Receiver
+        let secret = derive_onetime_secret(&a, &(r_g * a));
+
+        assert_eq!(GENERATOR_EXTENDED * secret, public);
+        assert_ne!(public, a_g);
+
+        // Different ephemeral keys produce unlinkable one-time keys
+        let r = JubJubScalar::random(&mut OsRng);
+        assert_ne!(derive_onetime_public(&a_g, &(a_g * r)), public);
+    }
+}
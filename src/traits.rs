@@ -0,0 +1,229 @@
+//! Implementations of the [`ff`] and [`group`] traits, so [`JubJubScalar`]
+//! and [`JubJubExtended`] can be used by crates generic over them.
+
+use crate::{JubJubAffine, JubJubExtended, JubJubScalar, GENERATOR_EXTENDED};
+
+use dusk_bytes::Serializable;
+use rand_core::RngCore;
+use subtle::{Choice, CtOption};
+
+impl ff::Field for JubJubScalar {
+    const ZERO: Self = JubJubScalar::zero();
+    const ONE: Self = JubJubScalar::one();
+
+    fn random(mut rng: impl RngCore) -> Self {
+        let mut bytes = [0u8; 64];
+        rng.fill_bytes(&mut bytes);
+
+        JubJubScalar::from_bytes_wide(&bytes)
+    }
+
+    fn square(&self) -> Self {
+        JubJubScalar::square(self)
+    }
+
+    fn double(&self) -> Self {
+        JubJubScalar::double(self)
+    }
+
+    fn invert(&self) -> CtOption<Self> {
+        JubJubScalar::invert(self)
+    }
+
+    fn sqrt(&self) -> CtOption<Self> {
+        JubJubScalar::sqrt(self)
+    }
+
+    fn sqrt_ratio(num: &Self, div: &Self) -> (Choice, Self) {
+        ff::helpers::sqrt_ratio_generic(num, div)
+    }
+}
+
+impl ff::PrimeField for JubJubScalar {
+    type Repr = [u8; 32];
+
+    const MODULUS: &'static str =
+        "0x0e7db4ea6533afa906673b0101343b00a6682093ccc81082d0970e5ed6f72cb7";
+    const NUM_BITS: u32 = JubJubScalar::NUM_BITS;
+    const CAPACITY: u32 = JubJubScalar::CAPACITY;
+    const TWO_INV: Self = JubJubScalar::from_raw([
+        0x684b_872f_6b7b_965c,
+        0x5334_1049_e664_0841,
+        0x8333_9d80_809a_1d80,
+        0x073e_da75_3299_d7d4,
+    ]);
+    const MULTIPLICATIVE_GENERATOR: Self = JubJubScalar::from_raw([6, 0, 0, 0]);
+    const S: u32 = JubJubScalar::TWO_ADICITY;
+    const ROOT_OF_UNITY: Self = JubJubScalar::ROOT_OF_UNITY;
+    // -1 is its own inverse
+    const ROOT_OF_UNITY_INV: Self = JubJubScalar::ROOT_OF_UNITY;
+    const DELTA: Self = JubJubScalar::from_raw([36, 0, 0, 0]);
+
+    fn from_repr(repr: Self::Repr) -> CtOption<Self> {
+        let scalar = JubJubScalar::from_bytes(&repr);
+        let is_some = Choice::from(scalar.is_ok() as u8);
+
+        CtOption::new(scalar.unwrap_or_default(), is_some)
+    }
+
+    fn to_repr(&self) -> Self::Repr {
+        self.to_bytes()
+    }
+
+    fn is_odd(&self) -> Choice {
+        Choice::from(self.to_bytes()[0] & 1)
+    }
+}
+
+impl group::Group for JubJubExtended {
+    type Scalar = JubJubScalar;
+
+    /// Returns a random point of the full group, sampling encodings until
+    /// one is valid.
+    fn random(mut rng: impl RngCore) -> Self {
+        loop {
+            let mut bytes = [0u8; 32];
+            rng.fill_bytes(&mut bytes);
+
+            if let Ok(p) = JubJubAffine::from_bytes(&bytes) {
+                let p = JubJubExtended::from(p);
+                if !bool::from(p.is_identity()) {
+                    return p;
+                }
+            }
+        }
+    }
+
+    fn identity() -> Self {
+        JubJubExtended::identity()
+    }
+
+    fn generator() -> Self {
+        GENERATOR_EXTENDED
+    }
+
+    fn is_identity(&self) -> Choice {
+        JubJubExtended::is_identity(self)
+    }
+
+    fn double(&self) -> Self {
+        JubJubExtended::double(self)
+    }
+}
+
+impl group::GroupEncoding for JubJubExtended {
+    type Repr = [u8; 32];
+
+    fn from_bytes(bytes: &Self::Repr) -> CtOption<Self> {
+        let point = JubJubAffine::from_bytes(bytes);
+        let is_some = Choice::from(point.is_ok() as u8);
+
+        CtOption::new(point.unwrap_or_default().into(), is_some)
+    }
+
+    fn from_bytes_unchecked(bytes: &Self::Repr) -> CtOption<Self> {
+        <Self as group::GroupEncoding>::from_bytes(bytes)
+    }
+
+    fn to_bytes(&self) -> Self::Repr {
+        JubJubAffine::from(self).to_bytes()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{JubJubExtended, JubJubScalar, GENERATOR_EXTENDED};
+    use ff::{Field, PrimeField};
+    use group::{Group, GroupEncoding};
+    use rand_core::SeedableRng;
+    use rand_xorshift::XorShiftRng;
+
+    const SEED: [u8; 16] = [
+        0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32,
+        0x54, 0x06, 0xbc, 0xe5,
+    ];
+
+    fn horner<F: Field>(coefficients: &[F], x: &F) -> F {
+        coefficients
+            .iter()
+            .rev()
+            .fold(F::ZERO, |acc, c| acc * x + c)
+    }
+
+    #[test]
+    fn generic_field() {
+        let coefficients = [
+            JubJubScalar::from(3u64),
+            JubJubScalar::from(2u64),
+            JubJubScalar::one(),
+        ];
+        let x = JubJubScalar::from(5u64);
+
+        assert_eq!(horner(&coefficients, &x), JubJubScalar::from(38u64));
+
+        let mut rng = XorShiftRng::from_seed(SEED);
+        for _ in 0..10 {
+            let a = <JubJubScalar as Field>::random(&mut rng);
+            let b = <JubJubScalar as Field>::random(&mut rng);
+
+            assert_eq!(horner(&[a, b], &x), a + b * JubJubScalar::from(5u64));
+
+            let (is_square, root) = JubJubScalar::sqrt_ratio(&a.square(), &b);
+            if bool::from(is_square) {
+                assert_eq!(root.square() * b, a.square());
+            }
+        }
+    }
+
+    #[test]
+    fn prime_field_constants() {
+        assert_eq!(JubJubScalar::TWO_INV.double(), JubJubScalar::one());
+        assert_eq!(
+            JubJubScalar::ROOT_OF_UNITY * JubJubScalar::ROOT_OF_UNITY_INV,
+            JubJubScalar::one()
+        );
+        assert_eq!(
+            JubJubScalar::MULTIPLICATIVE_GENERATOR.pow2k(JubJubScalar::S),
+            JubJubScalar::DELTA
+        );
+        assert_eq!(
+            JubJubScalar::MULTIPLICATIVE_GENERATOR.pow_vartime(&[
+                0x684b_872f_6b7b_965b,
+                0x5334_1049_e664_0841,
+                0x8333_9d80_809a_1d80,
+                0x073e_da75_3299_d7d4,
+            ]),
+            -JubJubScalar::one()
+        );
+        assert_eq!(JubJubScalar::from_u128(1u128 << 64).to_repr()[8], 1);
+
+        let x = JubJubScalar::from(42u64);
+        assert_eq!(JubJubScalar::from_repr(x.to_repr()).unwrap(), x);
+        assert!(bool::from(JubJubScalar::from_repr([0xff; 32]).is_none()));
+        assert!(!bool::from(x.is_odd()));
+        assert!(bool::from(JubJubScalar::one().is_odd()));
+    }
+
+    #[test]
+    fn group_encoding() {
+        let mut rng = XorShiftRng::from_seed(SEED);
+        for _ in 0..10 {
+            let p = <JubJubExtended as Group>::random(&mut rng);
+            let bytes = GroupEncoding::to_bytes(&p);
+
+            let q = JubJubExtended::from_bytes(&bytes).unwrap();
+            assert_eq!(p, q);
+        }
+
+        let g = <JubJubExtended as Group>::generator();
+        assert_eq!(g, GENERATOR_EXTENDED);
+        assert_eq!(
+            JubJubExtended::from_bytes_unchecked(&GroupEncoding::to_bytes(&g))
+                .unwrap(),
+            g
+        );
+        assert!(bool::from(
+            JubJubExtended::from_bytes(&[0xff; 32]).is_none()
+        ));
+    }
+}
@@ -0,0 +1,245 @@
+//! Fiat-Shamir transcripts over Jubjub.
+//!
+//! A [`Transcript`] absorbs labelled points and scalars into a SHAKE256
+//! state, and squeezes challenges out of it. Every input is framed with its
+//! label and length, so different sequences of inputs never produce the same
+//! state.
+//!
+//! A [`PointHasher`] binds a sequence of points to a 32-byte SHA-256 digest,
+//! such as a commitment to a vector of points.
+
+use crate::{JubJubAffine, JubJubExtended, JubJubScalar};
+
+use core::fmt;
+use dusk_bytes::Serializable;
+use sha2::{Digest, Sha256};
+use sha3::digest::{ExtendableOutput, Update, XofReader};
+use sha3::Shake256;
+
+/// Transcript of a public-coin protocol, used to derive Fiat-Shamir
+/// challenges from the messages exchanged.
+///
+/// ## Example
+///
+/// ```
+/// use dusk_jubjub::transcript::Transcript;
+/// use dusk_jubjub::{JubJubScalar, GENERATOR_EXTENDED};
+///
+/// let mut transcript = Transcript::new(b"my-protocol");
+/// transcript.append_point(b"commitment", &GENERATOR_EXTENDED);
+/// transcript.append_scalar(b"response", &JubJubScalar::one());
+///
+/// let challenge = transcript.challenge_scalar(b"challenge");
+/// ```
+#[derive(Clone)]
+pub struct Transcript {
+    state: Shake256,
+}
+
+impl fmt::Debug for Transcript {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Transcript").finish_non_exhaustive()
+    }
+}
+
+impl Transcript {
+    const DOMAIN: &'static [u8] = b"dusk-jubjub-transcript";
+
+    /// Create a new transcript, separated from others by the domain `label`.
+    pub fn new(label: &[u8]) -> Self {
+        let mut transcript = Self {
+            state: Shake256::default(),
+        };
+
+        transcript.append_message(Self::DOMAIN, label);
+        transcript
+    }
+
+    /// Append a labelled message of arbitrary bytes to the transcript.
+    pub fn append_message(&mut self, label: &[u8], message: &[u8]) {
+        self.state.update((label.len() as u64).to_le_bytes());
+        self.state.update(label);
+        self.state.update((message.len() as u64).to_le_bytes());
+        self.state.update(message);
+    }
+
+    /// Append a labelled point to the transcript, in its compressed form.
+    pub fn append_point(&mut self, label: &[u8], point: &JubJubExtended) {
+        self.append_message(label, &JubJubAffine::from(point).to_bytes());
+    }
+
+    /// Append a labelled scalar to the transcript, in its canonical form.
+    pub fn append_scalar(&mut self, label: &[u8], scalar: &JubJubScalar) {
+        self.append_message(label, &scalar.to_bytes());
+    }
+
+    /// Derive a challenge scalar from everything appended so far.
+    ///
+    /// The challenge is appended back to the transcript under `label`, so
+    /// subsequent challenges depend on it.
+    pub fn challenge_scalar(&mut self, label: &[u8]) -> JubJubScalar {
+        let mut state = self.state.clone();
+        state.update((label.len() as u64).to_le_bytes());
+        state.update(label);
+
+        let mut bytes = [0u8; 64];
+        state.finalize_xof().read(&mut bytes);
+
+        let challenge = JubJubScalar::from_bytes_wide(&bytes);
+        self.append_scalar(label, &challenge);
+
+        challenge
+    }
+}
+
+/// Incremental SHA-256 hash of a sequence of points.
+///
+/// Every point is fed as its compressed encoding, so the digest only depends
+/// on the points and not on their projective representation. Since the
+/// encodings have a fixed size, the digest commits to the order and number
+/// of points as well.
+///
+/// ## Example
+///
+/// ```
+/// use dusk_jubjub::transcript::PointHasher;
+/// use dusk_jubjub::{GENERATOR_EXTENDED, GENERATOR_NUMS_EXTENDED};
+///
+/// let mut hasher = PointHasher::new(b"my-commitment");
+/// hasher.update(&GENERATOR_EXTENDED);
+/// hasher.update(&GENERATOR_NUMS_EXTENDED);
+///
+/// let digest: [u8; 32] = hasher.finalize();
+/// ```
+#[derive(Clone)]
+pub struct PointHasher {
+    state: Sha256,
+}
+
+impl fmt::Debug for PointHasher {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PointHasher").finish_non_exhaustive()
+    }
+}
+
+impl PointHasher {
+    /// Create a new hasher, separated from others by `domain`.
+    pub fn new(domain: &[u8]) -> Self {
+        let mut state = Sha256::new();
+        Digest::update(&mut state, (domain.len() as u64).to_le_bytes());
+        Digest::update(&mut state, domain);
+
+        Self { state }
+    }
+
+    /// Feed the next point of the sequence to the hasher.
+    pub fn update(&mut self, point: &JubJubExtended) {
+        Digest::update(&mut self.state, JubJubAffine::from(point).to_bytes());
+    }
+
+    /// Consume the hasher, returning the digest of the points fed so far.
+    pub fn finalize(self) -> [u8; 32] {
+        let mut digest = [0u8; 32];
+        digest.copy_from_slice(&self.state.finalize());
+
+        digest
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{PointHasher, Transcript};
+    use crate::{
+        JubJubExtended, JubJubScalar, GENERATOR_EXTENDED,
+        GENERATOR_NUMS_EXTENDED,
+    };
+
+    fn transcript(p: usize, q: usize) -> Transcript {
+        let points = [GENERATOR_EXTENDED, GENERATOR_NUMS_EXTENDED];
+
+        let mut transcript = Transcript::new(b"test");
+        transcript.append_point(b"p", &points[p]);
+        transcript.append_point(b"q", &points[q]);
+        transcript.append_scalar(b"s", &JubJubScalar::from(42u64));
+
+        transcript
+    }
+
+    #[test]
+    fn same_inputs() {
+        let mut a = transcript(0, 1);
+        let mut b = transcript(0, 1);
+
+        assert_eq!(a.challenge_scalar(b"c"), b.challenge_scalar(b"c"));
+        assert_eq!(a.challenge_scalar(b"d"), b.challenge_scalar(b"d"));
+    }
+
+    #[test]
+    fn different_inputs() {
+        let mut a = transcript(0, 1);
+
+        assert_ne!(
+            a.clone().challenge_scalar(b"c"),
+            transcript(1, 0).challenge_scalar(b"c")
+        );
+        assert_ne!(
+            a.clone().challenge_scalar(b"c"),
+            transcript(0, 0).challenge_scalar(b"c")
+        );
+        assert_ne!(
+            a.clone().challenge_scalar(b"c"),
+            a.clone().challenge_scalar(b"d")
+        );
+
+        let mut b = Transcript::new(b"another");
+        b.append_point(b"p", &GENERATOR_EXTENDED);
+        b.append_point(b"q", &GENERATOR_NUMS_EXTENDED);
+        b.append_scalar(b"s", &JubJubScalar::from(42u64));
+        assert_ne!(a.clone().challenge_scalar(b"c"), b.challenge_scalar(b"c"));
+
+        // Successive challenges differ
+        let c = a.challenge_scalar(b"c");
+        assert_ne!(c, a.challenge_scalar(b"c"));
+    }
+
+    #[test]
+    fn framing() {
+        let mut a = Transcript::new(b"test");
+        a.append_message(b"ab", b"c");
+
+        let mut b = Transcript::new(b"test");
+        b.append_message(b"a", b"bc");
+
+        assert_ne!(a.challenge_scalar(b"c"), b.challenge_scalar(b"c"));
+    }
+
+    fn digest(domain: &[u8], points: &[JubJubExtended]) -> [u8; 32] {
+        let mut hasher = PointHasher::new(domain);
+        points.iter().for_each(|p| hasher.update(p));
+
+        hasher.finalize()
+    }
+
+    #[test]
+    fn point_hasher() {
+        let (g, h) = (GENERATOR_EXTENDED, GENERATOR_NUMS_EXTENDED);
+
+        assert_eq!(digest(b"test", &[g, h]), digest(b"test", &[g, h]));
+        assert_ne!(digest(b"test", &[g, h]), digest(b"test", &[h, g]));
+        assert_ne!(digest(b"test", &[g, h]), digest(b"test", &[g]));
+        assert_ne!(digest(b"test", &[g, h]), digest(b"other", &[g, h]));
+        assert_ne!(digest(b"test", &[]), digest(b"other", &[]));
+
+        // The digest is independent of the projective representation
+        let doubled = g.double() - g;
+        assert_ne!(doubled.get_z(), g.get_z());
+        assert_eq!(digest(b"test", &[doubled, h]), digest(b"test", &[g, h]));
+
+        let mut hasher = PointHasher::new(b"test");
+        hasher.update(&g);
+        let mut fork = hasher.clone();
+        hasher.update(&h);
+        fork.update(&h);
+        assert_eq!(hasher.finalize(), fork.finalize());
+    }
+}
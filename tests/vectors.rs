@@ -0,0 +1,137 @@
+//! Verifies the test vectors generated by `examples/gen_vectors.rs`.
+
+use dusk_bytes::Serializable;
+use dusk_jubjub::elgamal::ElgamalCipher;
+use dusk_jubjub::{
+    BlsScalar, JubJubAffine, JubJubExtended, JubJubScalar, GENERATOR_EXTENDED,
+};
+
+const VECTORS: &str = include_str!("vectors.json");
+
+fn unhex<const N: usize>(s: &str) -> [u8; N] {
+    assert_eq!(s.len(), N * 2, "bad hex length");
+
+    let mut bytes = [0u8; N];
+    bytes.iter_mut().enumerate().for_each(|(i, b)| {
+        *b = u8::from_str_radix(&s[i * 2..i * 2 + 2], 16).expect("bad hex")
+    });
+
+    bytes
+}
+
+/// Returns the records of a section, as lists of `(key, value)` pairs.
+fn section(name: &str) -> Vec<Vec<(&'static str, &'static str)>> {
+    let header = format!("\"{}\": [", name);
+
+    VECTORS
+        .lines()
+        .skip_while(|l| l.trim() != header)
+        .skip(1)
+        .take_while(|l| !l.trim().starts_with(']'))
+        .map(|l| {
+            l.trim()
+                .trim_start_matches('{')
+                .trim_end_matches(',')
+                .trim_end_matches('}')
+                .split(',')
+                .map(|kv| {
+                    let mut kv =
+                        kv.split(':').map(|s| s.trim().trim_matches('"'));
+                    (kv.next().unwrap(), kv.next().unwrap())
+                })
+                .collect()
+        })
+        .collect()
+}
+
+fn get<'a>(record: &[(&str, &'a str)], key: &str) -> &'a str {
+    record
+        .iter()
+        .find(|(k, _)| *k == key)
+        .expect("missing key")
+        .1
+}
+
+fn scalar(s: &str) -> JubJubScalar {
+    JubJubScalar::from_bytes(&unhex(s)).unwrap()
+}
+
+fn point(s: &str) -> JubJubExtended {
+    JubJubAffine::from_bytes(&unhex(s)).unwrap().into()
+}
+
+#[test]
+fn scalar_mul() {
+    let records = section("scalar_mul");
+    assert!(!records.is_empty());
+
+    for r in records {
+        let s = scalar(get(&r, "scalar"));
+        let p = point(get(&r, "point"));
+        assert_eq!(p * s, point(get(&r, "result")));
+    }
+}
+
+#[test]
+fn point_add() {
+    let records = section("point_add");
+    assert!(!records.is_empty());
+
+    for r in records {
+        let a = point(get(&r, "a"));
+        let b = point(get(&r, "b"));
+        assert_eq!(a + b, point(get(&r, "result")));
+    }
+}
+
+#[test]
+fn compression() {
+    let records = section("compression");
+    assert!(!records.is_empty());
+
+    for r in records {
+        let x = BlsScalar::from_bytes(&unhex(get(&r, "x"))).unwrap();
+        let y = BlsScalar::from_bytes(&unhex(get(&r, "y"))).unwrap();
+        let compressed = unhex(get(&r, "compressed"));
+
+        let p = JubJubAffine::from_bytes(&compressed).unwrap();
+        assert_eq!(p.get_x(), x);
+        assert_eq!(p.get_y(), y);
+        assert_eq!(p.to_bytes(), compressed);
+    }
+}
+
+#[test]
+fn elgamal() {
+    let records = section("elgamal");
+    assert!(!records.is_empty());
+
+    for r in records {
+        let secret = scalar(get(&r, "secret"));
+        let public = point(get(&r, "public"));
+        let ephemeral = scalar(get(&r, "ephemeral"));
+        let message = point(get(&r, "message"));
+        let cipher = unhex(get(&r, "cipher"));
+
+        assert_eq!(GENERATOR_EXTENDED * secret, public);
+
+        let encrypted = ElgamalCipher::encrypt_with_randomness(
+            &ephemeral,
+            &public,
+            &GENERATOR_EXTENDED,
+            &message,
+        );
+        assert_eq!(encrypted.to_bytes(), cipher);
+
+        let encrypted = ElgamalCipher::encrypt(
+            &ephemeral,
+            &public,
+            &GENERATOR_EXTENDED,
+            &message,
+        );
+        assert_eq!(encrypted.to_bytes(), cipher);
+
+        let cipher = ElgamalCipher::from_bytes(&cipher).unwrap();
+        assert_eq!(cipher.decrypt(&secret), message);
+    }
+}